@@ -1,6 +1,6 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	waste_management::WasteCategory, AccountId, AuraConfig, BalancesConfig, GenesisConfig,
+	GrandpaConfig, Signature, SudoConfig, SystemConfig, WasteManagementConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -152,5 +152,17 @@ fn testnet_genesis(
 			key: Some(root_key),
 		},
 		transaction_payment: Default::default(),
+		waste_management: WasteManagementConfig {
+			// Standard waste categories, available from block zero.
+			waste_types: vec![
+				(WasteCategory::Plastic, b"plastic".to_vec()),
+				(WasteCategory::Organic, b"organic".to_vec()),
+				(WasteCategory::Glass, b"glass".to_vec()),
+				(WasteCategory::Metal, b"metal".to_vec()),
+				(WasteCategory::Ewaste, b"e-waste".to_vec()),
+				(WasteCategory::Hazardous, b"hazardous".to_vec()),
+			],
+			..Default::default()
+		},
 	}
 }
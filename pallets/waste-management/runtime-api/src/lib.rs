@@ -0,0 +1,93 @@
+//! Runtime API definition for the waste-management pallet.
+//!
+//! The account, report and block number types are left generic so that the runtime can plug in
+//! its concrete `AccountId`, `waste_management::WasteData<Runtime>` and `BlockNumber` without
+//! this crate depending on the runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+pub use waste_management::{
+	DashboardSummary, GeoPoint, ReportId, WasteCategory, WasteStatus, WasteType,
+};
+
+sp_api::decl_runtime_apis! {
+	pub trait WasteManagementApi<AccountId, WasteData, BlockNumber> where
+		AccountId: Codec,
+		WasteData: Codec,
+		BlockNumber: Codec,
+	{
+		/// All reports currently in `status`.
+		fn reports_by_status(status: WasteStatus) -> Vec<WasteData>;
+
+		/// The number of reports in `status` with a component of `waste_type`, examining at most
+		/// `scan_limit` reports in that status.
+		fn count_by_type_and_status(
+			waste_type: WasteType,
+			status: WasteStatus,
+			scan_limit: Option<u32>,
+		) -> u64;
+
+		/// The number of reports whose `waste_type` component amounts to below 10, from 10 to
+		/// 100, from 100 to 1000 or 1000 or more whole units, examining at most `scan_limit`
+		/// reports.
+		fn amount_histogram(waste_type: WasteType, scan_limit: Option<u32>) -> [u64; 4];
+
+		/// The reports filed by `who` that are currently in `status`.
+		fn reports_of_by_status(who: AccountId, status: WasteStatus) -> Vec<WasteData>;
+
+		/// Up to `limit` reports that are not cancelled within the box spanned by `min` and
+		/// `max`.
+		fn reports_in_box(min: GeoPoint, max: GeoPoint, limit: u32) -> Vec<WasteData>;
+
+		/// The `k` reports closest to `point`, optionally only those in `status_filter`, with
+		/// their squared distances, closest first.
+		fn nearest_reports(
+			point: GeoPoint,
+			k: u32,
+			status_filter: Option<WasteStatus>,
+		) -> Vec<(ReportId, u64)>;
+
+		/// Every status a report can be in, in SCALE index order.
+		fn supported_statuses() -> Vec<WasteStatus>;
+
+		/// The report count and the amount in each status, for a stats page.
+		fn dashboard() -> DashboardSummary;
+
+		/// The report filed under the outside case number `external_ref`, if any.
+		fn report_by_ref(external_ref: [u8; 32]) -> Option<WasteData>;
+
+		/// Every registered waste type with its name, for a type picker. Only root registers
+		/// types, singly or in batches, so the list is as long as it chose to make it.
+		fn registered_waste_types() -> Vec<(WasteType, Vec<u8>)>;
+
+		/// The `blake2_256` commitment to the contents of a report, if it is on file, which
+		/// together with a storage proof shows the report exists.
+		fn report_commitment(report_id: ReportId) -> Option<[u8; 32]>;
+
+		/// The number of status updates and other operator calls `operator` has made.
+		fn operator_actions(operator: AccountId) -> u64;
+
+		/// How far through its lifecycle a report is in thousandths, or `u32::MAX` once it is
+		/// cancelled or rejected, if it is on file.
+		fn report_progress(report_id: ReportId) -> Option<u32>;
+
+		/// The current status of each of `ids`, `None` for those not on file, in the order
+		/// given. Ids past the runtime's `MaxQueryBatch` are left out.
+		fn statuses_of(ids: Vec<ReportId>) -> Vec<(ReportId, Option<WasteStatus>)>;
+
+		/// The latest events about any report, as the report id, the event's SCALE variant
+		/// index and the block it was deposited in, oldest first.
+		fn recent_activity() -> Vec<(ReportId, u8, BlockNumber)>;
+
+		/// Up to `limit` reports after `start_after` in id order, the cursor for the next page
+		/// and a hash chaining `prev_hash`, the one returned with the previous page, with this
+		/// page's reports, for verifying a chunked sync.
+		fn report_chunk(
+			start_after: Option<ReportId>,
+			limit: u32,
+			prev_hash: Option<[u8; 32]>,
+		) -> (Vec<WasteData>, Option<ReportId>, [u8; 32]);
+	}
+}
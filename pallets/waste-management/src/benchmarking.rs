@@ -0,0 +1,187 @@
+//! Benchmarking setup for waste-management
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as WasteManagement;
+use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	sp_runtime::traits::Bounded,
+	traits::{Currency, Get, ReservableCurrency},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+
+fn single_component<T: Config>() -> Components<T> {
+	Components::<T>::truncate_from(sp_std::vec![(WasteCategory::Plastic, 100)])
+}
+
+/// Registers `caller` along with as many other operators as `MinOperators` asks for.
+fn register_operators<T: Config>(caller: &T::AccountId) {
+	Operators::<T>::insert(caller, ());
+	for i in 1..T::MinOperators::get() {
+		Operators::<T>::insert(account::<T::AccountId>("operator", i, 0), ());
+	}
+}
+
+benchmarks! {
+	create_waste_data {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		WasteTypeRegistry::<T>::insert(WasteCategory::Plastic, BoundedVec::default());
+	}: _(RawOrigin::Signed(caller.clone()), single_component::<T>(), 0, GeoPoint { x: 10, y: 20 }, Severity::Low, None, None, None, None, None)
+	verify {
+		assert_eq!(NextReportId::<T>::get(), 2);
+		assert_eq!(ReportsByReporter::<T>::get(&caller).len(), 1);
+		assert_eq!(T::Currency::reserved_balance(&caller), T::ReportDeposit::get());
+	}
+
+	// The status always changes here, so this measures the remove + insert on the status index.
+	update_waste_status {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		WasteTypeRegistry::<T>::insert(WasteCategory::Plastic, BoundedVec::default());
+		WasteManagement::<T>::create_waste_data(
+			RawOrigin::Signed(caller.clone()).into(), single_component::<T>(), 0, GeoPoint { x: 10, y: 20 }, Severity::Low, None, None, None, None, None
+		)?;
+		register_operators::<T>(&caller);
+		CooldownExempt::<T>::insert(&caller, ());
+		WasteManagement::<T>::assign_collector(RawOrigin::Signed(caller.clone()).into(), 1, caller.clone())?;
+	}: _(RawOrigin::Signed(caller), 1, WasteStatus::Collected, None)
+	verify {
+		assert!(WasteDataByStatus::<T>::contains_key(WasteStatus::Collected, 1));
+		assert!(!WasteDataByStatus::<T>::contains_key(WasteStatus::Reported, 1));
+	}
+
+	update_waste_status_to_collected {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		WasteTypeRegistry::<T>::insert(WasteCategory::Plastic, BoundedVec::default());
+		WasteManagement::<T>::create_waste_data(
+			RawOrigin::Signed(caller.clone()).into(), single_component::<T>(), 0, GeoPoint { x: 10, y: 20 }, Severity::Low, None, None, None, None, None
+		)?;
+		register_operators::<T>(&caller);
+		CooldownExempt::<T>::insert(&caller, ());
+		WasteManagement::<T>::assign_collector(RawOrigin::Signed(caller.clone()).into(), 1, caller.clone())?;
+	}: update_waste_status(RawOrigin::Signed(caller), 1, WasteStatus::Collected, None)
+	verify {
+		assert!(WasteDataByStatus::<T>::contains_key(WasteStatus::Collected, 1));
+	}
+
+	update_waste_status_to_transported {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		WasteTypeRegistry::<T>::insert(WasteCategory::Plastic, BoundedVec::default());
+		WasteManagement::<T>::create_waste_data(
+			RawOrigin::Signed(caller.clone()).into(), single_component::<T>(), 0, GeoPoint { x: 10, y: 20 }, Severity::Low, None, None, None, None, None
+		)?;
+		register_operators::<T>(&caller);
+		CooldownExempt::<T>::insert(&caller, ());
+		WasteManagement::<T>::assign_collector(RawOrigin::Signed(caller.clone()).into(), 1, caller.clone())?;
+		WasteManagement::<T>::update_waste_status(RawOrigin::Signed(caller.clone()).into(), 1, WasteStatus::Collected, None)?;
+	}: update_waste_status(RawOrigin::Signed(caller), 1, WasteStatus::Transported, None)
+	verify {
+		assert!(WasteDataByStatus::<T>::contains_key(WasteStatus::Transported, 1));
+	}
+
+	// The caller gives the last of the required approvals, so the report is utilized and the
+	// deposit and reward are settled.
+	update_waste_status_to_utilized {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		WasteTypeRegistry::<T>::insert(WasteCategory::Plastic, BoundedVec::default());
+		WasteManagement::<T>::create_waste_data(
+			RawOrigin::Signed(caller.clone()).into(), single_component::<T>(), 0, GeoPoint { x: 10, y: 20 }, Severity::Low, None, None, None, None, None
+		)?;
+		register_operators::<T>(&caller);
+		CooldownExempt::<T>::insert(&caller, ());
+		WasteManagement::<T>::assign_collector(RawOrigin::Signed(caller.clone()).into(), 1, caller.clone())?;
+		WasteManagement::<T>::update_waste_status(RawOrigin::Signed(caller.clone()).into(), 1, WasteStatus::Collected, None)?;
+		WasteManagement::<T>::update_waste_status(RawOrigin::Signed(caller.clone()).into(), 1, WasteStatus::Transported, None)?;
+		let required = match T::RequiredApprovals::get() {
+			(WasteStatus::Utilized, required) => required,
+			_ => 1,
+		};
+		let approvers = (1..required).map(|i| account("approver", i, 0)).collect::<sp_std::vec::Vec<_>>();
+		PendingApprovals::<T>::insert((1, WasteStatus::Utilized), BoundedVec::truncate_from(approvers));
+	}: update_waste_status(RawOrigin::Signed(caller), 1, WasteStatus::Utilized, None)
+	verify {
+		assert!(WasteDataByStatus::<T>::contains_key(WasteStatus::Utilized, 1));
+	}
+
+	// The report is already in the requested status, so the call stops before any write.
+	update_waste_status_noop {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		WasteTypeRegistry::<T>::insert(WasteCategory::Plastic, BoundedVec::default());
+		WasteManagement::<T>::create_waste_data(
+			RawOrigin::Signed(caller.clone()).into(), single_component::<T>(), 0, GeoPoint { x: 10, y: 20 }, Severity::Low, None, None, None, None, None
+		)?;
+		register_operators::<T>(&caller);
+	}: {
+		assert!(WasteManagement::<T>::update_waste_status(
+			RawOrigin::Signed(caller).into(), 1, WasteStatus::Reported, None
+		).is_err());
+	}
+	verify {
+		assert!(WasteDataByStatus::<T>::contains_key(WasteStatus::Reported, 1));
+	}
+
+	create_waste_data_batch {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		WasteTypeRegistry::<T>::insert(WasteCategory::Plastic, BoundedVec::default());
+		// Distinct locations, so that no report is taken for a duplicate of another.
+		let reports: BoundedVec<(WasteType, WasteAmount, u64, GeoPoint, Severity), T::MaxBatchSize> = (0..n)
+			.map(|i| (WasteCategory::Plastic, 100, 0, GeoPoint { x: i, y: 20 }, Severity::Low))
+			.collect::<sp_std::vec::Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), reports)
+	verify {
+		assert_eq!(NextReportId::<T>::get(), n as u64 + 1);
+		assert_eq!(ReportsByReporter::<T>::get(&caller).len(), n as usize);
+	}
+
+	bulk_update_status {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		WasteTypeRegistry::<T>::insert(WasteCategory::Plastic, BoundedVec::default());
+		register_operators::<T>(&caller);
+		for i in 0..n {
+			WasteManagement::<T>::create_waste_data(
+				RawOrigin::Signed(caller.clone()).into(), single_component::<T>(), 0, GeoPoint { x: i, y: 20 }, Severity::Low, None, None, None, None, None
+			)?;
+			WasteManagement::<T>::assign_collector(RawOrigin::Signed(caller.clone()).into(), i as u64 + 1, caller.clone())?;
+		}
+	}: _(RawOrigin::Signed(caller), WasteStatus::Reported, WasteStatus::Collected, n)
+	verify {
+		assert_eq!(WasteDataByStatus::<T>::iter_key_prefix(WasteStatus::Collected).count(), n as usize);
+	}
+
+	// The report sits in every index, and removing it from a reporter list of `n` ids is the
+	// part that grows.
+	delete_waste_data {
+		let n in 1 .. T::MaxReportsPerAccount::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		WasteTypeRegistry::<T>::insert(WasteCategory::Plastic, BoundedVec::default());
+		for i in 0..n {
+			// One report per block, so that the per-block rate limit is never hit.
+			frame_system::Pallet::<T>::set_block_number((i + 1).into());
+			WasteManagement::<T>::create_waste_data(
+				RawOrigin::Signed(caller.clone()).into(), single_component::<T>(), 0, GeoPoint { x: i, y: 20 }, Severity::Low, None, None, None, None, None
+			)?;
+		}
+		let report_id = n as u64;
+		EvidenceHash::<T>::insert(report_id, [0u8; 32]);
+	}: _(RawOrigin::Signed(caller.clone()), report_id)
+	verify {
+		assert!(!WasteDataMap::<T>::contains_key(report_id));
+		assert_eq!(ReportsByReporter::<T>::get(&caller).len(), n as usize - 1);
+	}
+
+	impl_benchmark_test_suite!(WasteManagement, crate::mock::new_test_ext(), crate::mock::Test);
+}
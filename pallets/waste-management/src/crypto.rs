@@ -0,0 +1,28 @@
+//! Keys used by the off-chain worker to sign `flag_stale` transactions.
+
+use crate::KEY_TYPE;
+use sp_core::sr25519::Signature as Sr25519Signature;
+use sp_runtime::{
+	app_crypto::{app_crypto, sr25519},
+	traits::Verify,
+	MultiSignature, MultiSigner,
+};
+
+app_crypto!(sr25519, KEY_TYPE);
+
+/// The identifier the runtime sets as `Config::AuthorityId`.
+pub struct AuthId;
+
+impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthId {
+	type RuntimeAppPublic = Public;
+	type GenericSignature = sp_core::sr25519::Signature;
+	type GenericPublic = sp_core::sr25519::Public;
+}
+
+impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+	for AuthId
+{
+	type RuntimeAppPublic = Public;
+	type GenericSignature = sp_core::sr25519::Signature;
+	type GenericPublic = sp_core::sr25519::Public;
+}
@@ -2,138 +2,6096 @@
 
 pub use pallet::*;
 
+use sp_core::crypto::KeyTypeId;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod crypto;
+pub mod migrations;
+pub mod weights;
+pub use weights::*;
+
+/// Key type of the account the off-chain worker signs `flag_stale` transactions with.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"wste");
+
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
+	use frame_support::{
+		dispatch::WithPostDispatchInfo,
+		pallet_prelude::*,
+		sp_runtime::{
+			traits::{Hash, One, TrailingZeroInput, Zero},
+			Perbill, Permill,
+		},
+		traits::{Currency, Imbalance, OnRuntimeUpgrade, OnUnbalanced, ReservableCurrency},
+	};
+	use frame_system::{
+		offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer},
+		pallet_prelude::*,
+	};
+	use sp_std::vec::Vec;
+
+	use crate::weights::WeightInfo;
+
+	/// The in-code storage version. Bump it, and add a migration to `crate::migrations`,
+	/// whenever the layout of a stored type changes.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
+	/// The share of the `create_waste_data` weight refunded per point of completeness.
+	pub const METADATA_DISCOUNT_PERCENT: u32 = 10;
+
+	// All storage is bounded so that PoV size can be accounted for. Any variable-length
+	// field added to a stored type must be a bounded collection whose limit is documented on
+	// the storage item that holds it.
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[scale_info(skip_type_params(T))]
-	#[derive(Encode, Decode, Clone, PartialEq, Debug, TypeInfo)]
+	#[derive(Encode, Decode, Clone, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
 	pub enum WasteStatus {
 		Reported,
 		Collected,
 		Transported,
 		Utilized,
+		/// Voided, but kept on chain for audit.
+		Cancelled,
+		/// Refused by the recycling plant, e.g. as contaminated.
+		Rejected,
+	}
+
+	impl WasteStatus {
+		/// The SCALE index of the status, i.e. the byte it encodes to.
+		pub fn as_index(&self) -> u8 {
+			match self {
+				WasteStatus::Reported => 0,
+				WasteStatus::Collected => 1,
+				WasteStatus::Transported => 2,
+				WasteStatus::Utilized => 3,
+				WasteStatus::Cancelled => 4,
+				WasteStatus::Rejected => 5,
+			}
+		}
+
+		/// The status with SCALE index `index`, if there is one.
+		pub fn from_index(index: u8) -> Option<Self> {
+			Self::all().get(index as usize).cloned()
+		}
+
+		/// The number of statuses.
+		pub const COUNT: usize = 6;
+
+		/// Every status, in index order.
+		pub const ALL: [WasteStatus; Self::COUNT] = [
+			WasteStatus::Reported,
+			WasteStatus::Collected,
+			WasteStatus::Transported,
+			WasteStatus::Utilized,
+			WasteStatus::Cancelled,
+			WasteStatus::Rejected,
+		];
+
+		/// `ALL` as a slice.
+		pub fn all() -> &'static [WasteStatus] {
+			&Self::ALL
+		}
+
+		/// The bit standing for the status in a `StatusBitmap` entry.
+		pub fn bit(&self) -> u8 {
+			1 << self.as_index()
+		}
+
+		/// The statuses whose bits are set in `bitmap`, in index order.
+		pub fn from_bitmap(bitmap: u8) -> Vec<WasteStatus> {
+			Self::ALL.iter().filter(|status| bitmap & status.bit() != 0).cloned().collect()
+		}
+
+		/// What `progress_permill` gives for a report taken out of the lifecycle, which is
+		/// outside the 0 to 1000 of the others so that it can't be shown as progress by mistake.
+		pub const NO_PROGRESS: u32 = u32::MAX;
+
+		/// How far through the lifecycle a report in this status is, in thousandths, e.g. for
+		/// a progress bar. `Cancelled` and `Rejected` reports will make no more progress and
+		/// give `NO_PROGRESS`.
+		pub fn progress_permill(&self) -> u32 {
+			match self {
+				WasteStatus::Reported => 0,
+				WasteStatus::Collected => 333,
+				WasteStatus::Transported => 666,
+				WasteStatus::Utilized => 1000,
+				WasteStatus::Cancelled | WasteStatus::Rejected => Self::NO_PROGRESS,
+			}
+		}
+
+		/// Whether a report in this status is done with, either completed or taken out of the
+		/// lifecycle, so that it no longer takes up one of the `MaxActiveReports` slots.
+		pub fn is_closed(&self) -> bool {
+			matches!(self, WasteStatus::Utilized | WasteStatus::Cancelled | WasteStatus::Rejected)
+		}
+	}
+
+	/// How urgently a report needs a response.
+	#[derive(Encode, Decode, Clone, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+	pub enum Severity {
+		Low,
+		Medium,
+		High,
+		/// Hazardous waste needing an immediate response.
+		Critical,
+	}
+
+	/// A unit partners state amounts in, which `create_waste_data` converts to `WasteAmount`.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+	pub enum WasteUnit {
+		/// Already a `WasteAmount`.
+		Canonical,
+		Kilograms,
+		/// Metric tonnes, the whole unit of a `WasteAmount`.
+		Tonnes,
+		/// International avoirdupois pounds of exactly 0.45359237 kg.
+		Pounds,
 	}
 
-	pub type WasteType = u32;
+	/// A job that works through the reports over several calls, keeping its place in
+	/// `BatchCursors` in between.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+	pub enum BatchKind {
+		/// `recompute_aggregates`.
+		RecomputeAggregates,
+		/// `prune_orphans`.
+		PruneOrphans,
+	}
+
+	/// What `update_location` does once a report's route holds `MaxLocationHistory` locations.
+	#[derive(Encode, Decode, Clone, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+	pub enum RouteHistoryMode {
+		/// Refuse the move with `LocationHistoryFull`, so the whole route is kept.
+		Reject,
+		/// Drop the oldest location to make room, so the route keeps only the latest legs.
+		DropOldest,
+	}
+
+	/// What filing a report does when its `external_ref` is already on file.
+	#[derive(Encode, Decode, Clone, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+	pub enum ExternalRefMode {
+		/// Refuse the report with `DuplicateExternalRef`.
+		Reject,
+		/// Take the report for an update of the one on file, as legacy systems send when the
+		/// weighed amount comes in, and replace that report's amount with the new one.
+		UpsertAmount,
+	}
+
+	/// A location in the service area. It encodes exactly like the bare `x`, `y` pair it
+	/// replaced, so stored reports decode unchanged.
+	#[derive(
+		Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, Default, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct GeoPoint {
+		pub x: u32,
+		pub y: u32,
+	}
+
+	impl GeoPoint {
+		/// The squared euclidean distance to `other`, saturating at `u64::MAX` for points at
+		/// opposite corners of the full `u32` range.
+		pub fn distance_sq(&self, other: &Self) -> u64 {
+			let dx = self.x.abs_diff(other.x) as u64;
+			let dy = self.y.abs_diff(other.y) as u64;
+			(dx * dx).saturating_add(dy * dy)
+		}
+	}
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	/// The kind of waste a report or component holds.
+	#[derive(
+		Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, TypeInfo, MaxEncodedLen,
+	)]
+	pub enum WasteCategory {
+		Plastic,
+		Organic,
+		Glass,
+		Metal,
+		/// Discarded electrical and electronic equipment.
+		Ewaste,
+		Hazardous,
+		/// A category without a variant of its own, under a locally agreed code.
+		Other(u32),
+	}
+
+	impl WasteCategory {
+		/// The category a version 0 numeric waste type stands for. Codes 0 to 5 follow the
+		/// standard categories registered at genesis; any other code becomes `Other`.
+		pub fn from_legacy(code: u32) -> Self {
+			match code {
+				0 => Self::Plastic,
+				1 => Self::Organic,
+				2 => Self::Glass,
+				3 => Self::Metal,
+				4 => Self::Ewaste,
+				5 => Self::Hazardous,
+				code => Self::Other(code),
+			}
+		}
+	}
+
+	pub type WasteType = WasteCategory;
+	/// The statuses a report may move to from a given status. There are only six statuses.
+	pub type TransitionTargets = BoundedVec<WasteStatus, ConstU32<6>>;
+
+	/// A fixed-point amount of waste in tonnes with `AmountDecimals` implied decimal places.
 	pub type WasteAmount = u64;
 	pub type ReportId = u64;
 
+	/// The waste types making up a single pile, with the amount of each.
+	pub type Components<T> = BoundedVec<(WasteType, WasteAmount), <T as Config>::MaxComponents>;
+
+	/// An opaque way to reach a reporter, such as a phone number, email or handle. The chain
+	/// doesn't interpret it.
+	pub type Contact<T> = BoundedVec<u8, <T as Config>::MaxContactLen>;
+
+	/// A weighbridge's signature over the mass it measured. The chain keeps it as opaque proof.
+	pub type WeighbridgeSignature = BoundedVec<u8, ConstU32<64>>;
+
+	/// Where a report filed on someone else's behalf came from, such as a phoned in tip.
+	pub type OriginTag<T> = BoundedVec<u8, <T as Config>::MaxTagLen>;
+
+	/// Identifies a service zone in `ServiceZones`.
+	pub type ZoneId = u32;
+
+	/// A client chosen tag that makes retrying `create_waste_data` safe.
+	pub type IdempotencyKey = [u8; 16];
+
+	/// A case number from an outside system, e.g. a municipality's, that a report can be looked
+	/// up by.
+	pub type ExternalRef = [u8; 32];
+
 	#[scale_info(skip_type_params(T))]
-	#[derive(Encode, Decode, Clone, PartialEq, Debug, TypeInfo)]
+	#[derive(Encode, Decode, Clone, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
 	pub struct WasteData<T: Config> {
 		pub report_id: ReportId,
+		/// The type of the first component.
 		pub waste_type: WasteType,
+		/// The total amount over all components.
 		pub waste_amount: WasteAmount,
+		pub components: Components<T>,
+		/// The space the waste takes up, which is what fills a truck first.
+		pub volume: u64,
 		pub status: WasteStatus,
-		pub location_x: u32,
-		pub location_y: u32,
+		/// Where the waste is, or `None` for waste with no meaningful map position, such as
+		/// office e-waste inside a facility. Such reports are in no cell, zone or route.
+		pub location: Option<GeoPoint>,
+		/// The service zone the location falls in, or `None` while no zone is defined.
+		pub zone_id: Option<ZoneId>,
+		pub reporter: T::AccountId,
+		pub severity: Severity,
+		/// How operators can reach the reporter, if they left a contact.
+		pub contact: Option<Contact<T>>,
+		/// The real-world source of the report when the reporter files it for someone without
+		/// an account, e.g. a clerk logging a citizen's tip. The reporter stays accountable.
+		pub origin_tag: Option<OriginTag<T>>,
+		/// The case number the report has in an outside system, unique among the reports on
+		/// file.
+		pub external_ref: Option<ExternalRef>,
+		/// The report this one was split off from, if any.
+		pub parent: Option<ReportId>,
+		/// Block at which the report was filed.
+		pub created_at: BlockNumberFor<T>,
+		/// Block at which the report was last modified.
+		pub updated_at: BlockNumberFor<T>,
+		/// Block by which the report should be collected, from the `ZoneSla` of the zone it
+		/// was filed in. `None` outside any zone or SLA.
+		pub deadline: Option<BlockNumberFor<T>>,
+		/// The collection crew the report has been dispatched to. A report can only be
+		/// collected once it has one.
+		pub collector: Option<T::AccountId>,
+		/// Handling attributes that hold whatever the status, as the `FLAG_*` bits.
+		pub flags: u16,
+	}
+
+	impl<T: Config> WasteData<T> {
+		/// A new report in `Reported`, filed now, with every field checked against the
+		/// configured bounds and the waste type registry. This is the one place reports are
+		/// built, so that every call filing one accepts the same reports.
+		#[allow(clippy::too_many_arguments)]
+		pub fn new_validated(
+			report_id: ReportId,
+			reporter: T::AccountId,
+			components: Components<T>,
+			volume: u64,
+			location: Option<GeoPoint>,
+			severity: Severity,
+			contact: Option<Contact<T>>,
+			origin_tag: Option<OriginTag<T>>,
+		) -> Result<Self, Error<T>> {
+			let zone_id = match &location {
+				Some(location) => {
+					ensure!(
+						location.x <= T::MaxLocationX::get() &&
+							location.y <= T::MaxLocationY::get(),
+						Error::<T>::LocationOutOfBounds
+					);
+					Pallet::<T>::zone_for(location)?
+				},
+				None => None,
+			};
+			let (waste_type, _) = *components.first().ok_or(Error::<T>::EmptyComponents)?;
+			let mut waste_amount: WasteAmount = 0;
+			for (component_type, component_amount) in components.iter() {
+				ensure!(
+					WasteTypeRegistry::<T>::contains_key(component_type),
+					Error::<T>::UnknownWasteType
+				);
+				ensure!(
+					*component_amount >= MinAmountByType::<T>::get(component_type),
+					Error::<T>::AmountBelowMinimum
+				);
+				waste_amount = waste_amount
+					.checked_add(*component_amount)
+					.ok_or(Error::<T>::AmountTooLarge)?;
+			}
+			ensure!(waste_amount <= T::MaxWasteAmount::get(), Error::<T>::AmountTooLarge);
+			ensure!(volume <= T::MaxVolume::get(), Error::<T>::VolumeTooLarge);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			Ok(WasteData {
+				report_id,
+				waste_type,
+				waste_amount,
+				components,
+				volume,
+				status: WasteStatus::Reported,
+				location,
+				zone_id,
+				reporter,
+				severity,
+				contact,
+				origin_tag,
+				external_ref: None,
+				parent: None,
+				created_at: now,
+				updated_at: now,
+				deadline: zone_id
+					.and_then(ZoneSla::<T>::get)
+					.map(|sla| now.saturating_add(sla.max(One::one()))),
+				collector: None,
+				flags: 0,
+			})
+		}
+	}
+
+	/// The headline figures of the pallet, read in one call from the report count and the
+	/// per-status and per-zone aggregates.
+	#[derive(Encode, Decode, Clone, PartialEq, Debug, TypeInfo)]
+	pub struct DashboardSummary {
+		/// Reports on file that are not cancelled.
+		pub total: u64,
+		/// The amount of waste in each status, in index order.
+		pub by_status: [(WasteStatus, WasteAmount); WasteStatus::COUNT],
+		/// The amount over all statuses.
+		pub total_amount: WasteAmount,
+		/// The amount on file in each service zone, in zone id order.
+		pub by_zone: Vec<(ZoneId, WasteAmount)>,
+	}
+
+	/// What is kept of a `Utilized` report once `archive_completed` moves it out of the active
+	/// maps.
+	#[scale_info(skip_type_params(T))]
+	#[derive(Encode, Decode, Clone, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+	pub struct ArchivedReport<T: Config> {
+		pub waste_type: WasteType,
+		pub waste_amount: WasteAmount,
+		pub volume: u64,
+		pub location: Option<GeoPoint>,
 		pub reporter: T::AccountId,
+		pub created_at: BlockNumberFor<T>,
+		/// Block at which the report was utilized.
+		pub completed_at: BlockNumberFor<T>,
+	}
+
+	/// A report held in `Settling` by `Pallet::prepare_settlement`, to be handed back to
+	/// `Pallet::commit_settlement`.
+	#[derive(Encode, Decode, Clone, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+	pub struct SettlementTicket<BlockNumber> {
+		pub report_id: ReportId,
+		/// The status the report keeps if the settlement times out.
+		pub prior_status: WasteStatus,
+		/// The block at which the settlement times out unless committed.
+		pub deadline: BlockNumber,
+	}
+
+	/// Extension point for a parent pallet to inspect every new report before it is stored.
+	pub trait OnReportCreated<T: Config> {
+		/// Called with each new report before it is written. Returning an error rejects the
+		/// report and rolls back the call that filed it.
+		fn on_created(report: &WasteData<T>) -> DispatchResult;
+	}
+
+	impl<T: Config> OnReportCreated<T> for () {
+		fn on_created(_report: &WasteData<T>) -> DispatchResult {
+			Ok(())
+		}
+	}
+
+	/// Extension point for another pallet to be told whenever a report moves to a new status,
+	/// e.g. an incineration scheduler waiting for `Transported`.
+	pub trait OnStatusReached<T: Config> {
+		/// Called once `update_waste_status` has moved report `report_id` to `status`, with the
+		/// report as it is now stored. The transition has happened and can't be refused here.
+		fn on_reached(report_id: ReportId, status: &WasteStatus, report: &WasteData<T>);
 	}
 
+	impl<T: Config> OnStatusReached<T> for () {
+		fn on_reached(_report_id: ReportId, _status: &WasteStatus, _report: &WasteData<T>) {}
+	}
+
+	#[pallet::type_value]
+	pub(super) fn FirstReportId() -> ReportId {
+		1
+	}
+
+	/// The id the next report will be given. Ids are never reused, even after a report is
+	/// deleted, so a stale key can't collide with a new report.
+	#[pallet::storage]
+	#[pallet::getter(fn next_report_id)]
+	pub(super) type NextReportId<T: Config> = StorageValue<_, ReportId, ValueQuery, FirstReportId>;
+
+	/// The `seq` of the latest event the pallet emitted.
+	#[pallet::storage]
+	#[pallet::getter(fn event_seq)]
+	pub(super) type EventSeq<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The number of reports on file that have not been deleted or cancelled.
 	#[pallet::storage]
-	pub(super) type WasteDataCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+	#[pallet::getter(fn waste_data_count)]
+	pub(super) type ActiveReportCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The number of reports on file that are not utilized, cancelled or rejected, which is
+	/// what `MaxActiveReports` caps.
+	#[pallet::storage]
+	#[pallet::getter(fn open_report_count)]
+	pub(super) type OpenReportCount<T: Config> = StorageValue<_, u64, ValueQuery>;
 
 	/// Maps the WasteData struct to the report_id.
+	///
+	/// Report ids are assigned sequentially by the pallet, so callers can't choose keys that
+	/// unbalance the trie and the cheaper `Twox64Concat` is safe. Every map below keyed only by
+	/// `ReportId` relies on the same argument, as do maps keyed by a `WasteType` or `Severity`,
+	/// which are registered by root or fixed. Maps keyed by an account or by caller-chosen
+	/// coordinates use `Blake2_128Concat`.
 	#[pallet::storage]
+	#[pallet::getter(fn waste_data)]
 	pub(super) type WasteDataMap<T: Config> = StorageMap<_, Twox64Concat, ReportId, WasteData<T>>;
 
+	/// A copy of every report under its current status, so that the reports in one status can
+	/// be read as a key prefix. Only the statuses in `IndexedStatuses` are mirrored.
 	#[pallet::storage]
 	pub(super) type WasteDataByStatus<T: Config> =
-		StorageMap<_, Blake2_128Concat, (WasteStatus, ReportId), WasteData<T>>;
+		StorageDoubleMap<_, Blake2_128Concat, WasteStatus, Twox64Concat, ReportId, WasteData<T>>;
+
+	/// Ids of the reports filed by each account, bounded by `MaxReportsPerAccount`.
+	#[pallet::storage]
+	pub(super) type ReportsByReporter<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<ReportId, T::MaxReportsPerAccount>,
+		ValueQuery,
+	>;
+
+	/// Set once a total would have gone below zero, which means the aggregates have drifted
+	/// from the reports; `do_try_state` finds out where.
+	#[pallet::storage]
+	#[pallet::getter(fn aggregates_drifted)]
+	pub(super) type AggregatesDrifted<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// For every batch job part way through, the next report id it visits and the number of
+	/// reports it has counted so far. `prune_orphans`, which walks the status index instead,
+	/// keeps the number of entries it has visited and pruned, and its place in
+	/// `PruneOrphansFrom`. Keeping every job here lets `on_runtime_upgrade` find the unfinished
+	/// ones without knowing each job's storage.
+	#[pallet::storage]
+	pub(super) type BatchCursors<T: Config> =
+		StorageMap<_, Twox64Concat, BatchKind, (ReportId, u64)>;
+
+	/// The amount and volume totals of each status over the reports `recompute_aggregates` has
+	/// visited so far, moved into `TotalAmountByStatus` and `TotalVolumeByStatus` once it is done.
+	#[pallet::storage]
+	pub(super) type RecomputedStatusTotals<T: Config> =
+		StorageMap<_, Twox64Concat, WasteStatus, (WasteAmount, u64), ValueQuery>;
+
+	/// The `TotalAmountByType` being rebuilt by `recompute_aggregates`.
+	#[pallet::storage]
+	pub(super) type RecomputedTypeTotals<T: Config> =
+		StorageMap<_, Twox64Concat, WasteType, WasteAmount, ValueQuery>;
+
+	/// The `TotalAmountByZone` being rebuilt by `recompute_aggregates`.
+	#[pallet::storage]
+	pub(super) type RecomputedZoneTotals<T: Config> =
+		StorageMap<_, Twox64Concat, ZoneId, WasteAmount, ValueQuery>;
+
+	/// The raw `WasteDataByStatus` key `prune_orphans` last visited, which the next call carries
+	/// on after.
+	#[pallet::storage]
+	pub(super) type PruneOrphansFrom<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<128>>>;
+
+	/// Sum of `waste_amount` over all reports currently in each status.
+	#[pallet::storage]
+	pub(super) type TotalAmountByStatus<T: Config> =
+		StorageMap<_, Twox64Concat, WasteStatus, WasteAmount, ValueQuery>;
+
+	/// Sum of `volume` over all reports currently in each status, kept in step with
+	/// `TotalAmountByStatus`.
+	#[pallet::storage]
+	pub(super) type TotalVolumeByStatus<T: Config> =
+		StorageMap<_, Twox64Concat, WasteStatus, u64, ValueQuery>;
+
+	/// Sum of the component amounts of each waste type over all reports that are not cancelled.
+	/// Status changes don't touch it.
+	#[pallet::storage]
+	pub(super) type TotalAmountByType<T: Config> =
+		StorageMap<_, Twox64Concat, WasteType, WasteAmount, ValueQuery>;
+
+	/// Sum of `waste_amount` over all reports on file in each service zone, keyed by the
+	/// `zone_id` stored on the report. Reports filed outside any zone are not counted.
+	#[pallet::storage]
+	pub(super) type TotalAmountByZone<T: Config> =
+		StorageMap<_, Twox64Concat, ZoneId, WasteAmount, ValueQuery>;
+
+	/// The usable material recovered from each report finalized with `finalize_utilization`.
+	/// Entries outlive their reports, as part of the recycling record.
+	#[pallet::storage]
+	pub(super) type RecoveredAmount<T: Config> = StorageMap<_, Twox64Concat, ReportId, WasteAmount>;
+
+	/// Sum of `RecoveredAmount`.
+	#[pallet::storage]
+	#[pallet::getter(fn total_recovered)]
+	pub(super) type TotalRecovered<T: Config> = StorageValue<_, WasteAmount, ValueQuery>;
+
+	/// Reports in `Settling`: prepared for settlement by another pallet and waiting for it to
+	/// commit. No other status change is allowed until the settlement is committed or times
+	/// out.
+	#[pallet::storage]
+	pub(super) type Settling<T: Config> =
+		StorageMap<_, Twox64Concat, ReportId, SettlementTicket<BlockNumberFor<T>>>;
+
+	/// The settlements timing out at each block, bounded by `MaxExpiriesPerBlock`.
+	#[pallet::storage]
+	pub(super) type SettlementDeadlines<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ReportId, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
 
+	/// Ids handed out by `reserve_report_id` whose report has not been filled in yet, with the
+	/// account that reserved each and the block its draft expires at. A draft is not a report:
+	/// it is in none of the report maps, indices or aggregates until `finalize_draft`.
+	#[pallet::storage]
+	pub(super) type Drafts<T: Config> =
+		StorageMap<_, Twox64Concat, ReportId, (T::AccountId, BlockNumberFor<T>)>;
+
+	/// The drafts expiring at each block, bounded by `MaxExpiriesPerBlock`.
+	#[pallet::storage]
+	pub(super) type DraftDeadlines<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ReportId, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Why each cancelled report was voided, bounded by `MaxReasonLen`.
+	#[pallet::storage]
+	pub(super) type CancellationReasons<T: Config> =
+		StorageMap<_, Twox64Concat, ReportId, BoundedVec<u8, T::MaxReasonLen>>;
+
+	/// The reports each report was split into, bounded by `MaxSplit`. A report is split at
+	/// most once.
+	#[pallet::storage]
+	pub(super) type ChildReports<T: Config> =
+		StorageMap<_, Twox64Concat, ReportId, BoundedVec<ReportId, T::MaxSplit>>;
+
+	/// The report each merged away report was absorbed into, so that its id can still be
+	/// followed after the report itself is gone.
+	#[pallet::storage]
+	pub(super) type MergedInto<T: Config> = StorageMap<_, Twox64Concat, ReportId, ReportId>;
+
+	/// Completed reports moved out of the active maps by `archive_completed`.
+	#[pallet::storage]
+	pub(super) type ArchivedReports<T: Config> =
+		StorageMap<_, Twox64Concat, ReportId, ArchivedReport<T>>;
+
+	/// Why each rejected report was refused, bounded by `MaxReasonLen`.
+	#[pallet::storage]
+	pub(super) type RejectionReasons<T: Config> =
+		StorageMap<_, Twox64Concat, ReportId, BoundedVec<u8, T::MaxReasonLen>>;
+
+	/// Reports whose reporter disputes their current status, with the reason given. Only
+	/// `ForceOrigin` can change the status of a disputed report until the dispute is resolved.
+	#[pallet::storage]
+	pub(super) type Disputed<T: Config> =
+		StorageMap<_, Twox64Concat, ReportId, BoundedVec<u8, T::MaxReasonLen>>;
+
+	/// Utilized reports sealed by `ForceOrigin` for the record. Nothing about a sealed report
+	/// can change, not even through `ForceOrigin`, until it is unsealed with `unseal_report`.
+	#[pallet::storage]
+	pub(super) type Sealed<T: Config> = StorageMap<_, Twox64Concat, ReportId, ()>;
+
+	/// Inspection notes recorded by operators on each report, oldest first. Bounded by
+	/// `MaxNotes` notes of at most `MaxNoteLen` bytes.
+	#[pallet::storage]
+	pub(super) type ReportNotes<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		ReportId,
+		BoundedVec<BoundedVec<u8, T::MaxNoteLen>, T::MaxNotes>,
+		ValueQuery,
+	>;
+
+	/// The operators who have so far approved moving a report into the status guarded by
+	/// `RequiredApprovals`. Cleared once the report enters that status.
+	#[pallet::storage]
+	pub(super) type PendingApprovals<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		(ReportId, WasteStatus),
+		BoundedVec<T::AccountId, T::MaxApprovers>,
+		ValueQuery,
+	>;
+
+	/// Every status each report has been in, with the block it entered it, oldest first.
+	/// Bounded by `MaxHistoryLen`.
+	#[pallet::storage]
+	pub(super) type StatusHistory<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		ReportId,
+		BoundedVec<(WasteStatus, BlockNumberFor<T>), T::MaxHistoryLen>,
+	>;
+
+	/// Every status each report has been in as one bit per status, see `WasteStatus::bit`. A
+	/// compact stand-in for `StatusHistory` that never fills up.
+	#[pallet::storage]
+	pub(super) type StatusBitmap<T: Config> = StorageMap<_, Twox64Concat, ReportId, u8, ValueQuery>;
+
+	/// The events deposited about each report, as their SCALE variant index and the block they
+	/// were deposited in, oldest first. Only the latest `MaxEventLog` are kept.
+	#[pallet::storage]
+	pub(super) type ReportEventLog<T: Config> =
+		StorageMap<_, Twox64Concat, ReportId, BoundedVec<(u8, BlockNumberFor<T>), T::MaxEventLog>>;
+
+	/// The latest events about any report, as the report id, the SCALE variant index of the
+	/// event and the block it was deposited in, oldest first, for a recent-activity feed. Only
+	/// the latest `RecentActivityLen` are kept.
+	#[pallet::storage]
+	pub(super) type RecentActivity<T: Config> = StorageValue<
+		_,
+		BoundedVec<(ReportId, u8, BlockNumberFor<T>), T::RecentActivityLen>,
+		ValueQuery,
+	>;
+
+	/// Every location each report has been at, oldest first, i.e. the route it travelled.
+	/// Bounded by `MaxLocationHistory`.
+	#[pallet::storage]
+	pub(super) type LocationHistory<T: Config> =
+		StorageMap<_, Twox64Concat, ReportId, BoundedVec<GeoPoint, T::MaxLocationHistory>>;
+
+	/// The real account behind each report filed in `PrivacyMode`, whose `reporter` is only a
+	/// hash. There is no getter and the runtime API doesn't serve it; `ForceOrigin` reads it
+	/// with `resolve_reporter`.
+	#[pallet::storage]
+	pub(super) type ReporterAccounts<T: Config> =
+		StorageMap<_, Twox64Concat, ReportId, T::AccountId>;
+
+	/// The deposit reserved for each open report. It is held by the report's reporter, or in
+	/// `PrivacyMode` by the account in `ReporterAccounts`, and moves with the report when it
+	/// changes hands, so no account is kept here.
+	#[pallet::storage]
+	pub(super) type ReportDeposits<T: Config> = StorageMap<_, Twox64Concat, ReportId, BalanceOf<T>>;
+
+	/// Completion rewards credited to each account and not yet claimed with `claim_rewards`.
+	#[pallet::storage]
+	pub(super) type PendingRewards<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Ids of the reports in each grid cell of `CellSize` by `CellSize` units, bounded by
+	/// `MaxPerCell`. Lets dispatchers find reports near a location without a full scan.
+	#[pallet::storage]
+	pub(super) type ReportsByCell<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(u32, u32),
+		BoundedVec<ReportId, T::MaxPerCell>,
+		ValueQuery,
+	>;
+
+	/// Content hash of the photo evidence anchored for each report. It is opaque to the pallet
+	/// and can't be changed once attached.
+	#[pallet::storage]
+	pub(super) type EvidenceHash<T: Config> = StorageMap<_, Twox64Concat, ReportId, [u8; 32]>;
+
+	/// The reputation of each reporter: one point for every report of theirs that was utilized,
+	/// minus one for every one that was cancelled.
+	#[pallet::storage]
+	pub(super) type ReporterScore<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, i32, ValueQuery>;
+
+	/// The last block each account filed a report in and how many it filed there. The count is
+	/// reset lazily when the account next files in a later block, so nothing needs clearing.
+	#[pallet::storage]
+	pub(super) type ReportsThisBlock<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, u32), ValueQuery>;
+
+	/// The latest report filed at each location for each waste type, and the block it was filed
+	/// in. Entries older than `DedupWindow`, or whose report is no longer on file, are stale and
+	/// are simply overwritten. The entry goes with its report when that is deleted.
+	#[pallet::storage]
+	pub(super) type RecentReports<T: Config> =
+		StorageMap<_, Blake2_128Concat, (u32, u32, WasteType), (ReportId, BlockNumberFor<T>)>;
+
+	/// The report carrying each external reference. The entry goes with the report when it is
+	/// deleted, so the reference can be used again.
+	#[pallet::storage]
+	pub(super) type ReportByExternalRef<T: Config> =
+		StorageMap<_, Blake2_128Concat, ExternalRef, ReportId>;
+
+	/// The report each reporter filed under each idempotency key, keyed by `reporter_key` so
+	/// that it exposes no more than the reports themselves do. Entries aren't taken out when
+	/// their report is removed, but a key whose report is gone is no longer honoured and is
+	/// taken over by the next report filed under it.
+	#[pallet::storage]
+	pub(super) type SeenKeys<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		IdempotencyKey,
+		ReportId,
+	>;
+
+	/// Open reports by severity, so that dispatchers can pull a single severity queue by key
+	/// prefix. Reports leave the index once they are utilized, cancelled or deleted.
+	#[pallet::storage]
+	pub(super) type ReportsBySeverity<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, Severity, Twox64Concat, ReportId, ()>;
+
+	/// Reports flagged as stuck in `Reported` for longer than `StaleThreshold`. A report is
+	/// unflagged once it moves on.
+	#[pallet::storage]
+	pub(super) type StaleFlags<T: Config> = StorageMap<_, Twox64Concat, ReportId, ()>;
+
+	/// Every report by the block it was filed in, so that `on_initialize` can find the ones due
+	/// to expire without a scan. Entries are dropped when their report expires, is deleted or is
+	/// passed over by the expiry cursor.
+	#[pallet::storage]
+	pub(super) type ReportsByCreation<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, ReportId, ()>;
+
+	/// The reports filed in each block, under the big-endian bytes of their id so that a block
+	/// iterates in filing order. Unlike `ReportsByCreation` it is kept after auto-expiry has
+	/// passed the block, so that auditors can list a block range with
+	/// `reports_created_between`. Entries go when their report is deleted.
+	#[pallet::storage]
+	pub(super) type ReportsFiledIn<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, [u8; 8], ()>;
+
+	/// The oldest filing block in `ReportsByCreation` that auto-expiry hasn't finished with.
+	#[pallet::storage]
+	pub(super) type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Accounts allowed to move reports through the waste lifecycle.
+	#[pallet::storage]
+	pub(super) type Operators<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The number of status updates and other operator calls each account has made, for
+	/// performance reviews. Kept after an operator is removed.
+	#[pallet::storage]
+	pub(super) type OperatorActions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// Accounts whose `update_waste_status` calls aren't held to `MinTransitionDelay`, such as
+	/// the service account of automated bins. They still need to be allowed to set the status.
+	#[pallet::storage]
+	pub(super) type CooldownExempt<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Target statuses each account may set on top of what operators can do, so that, for
+	/// example, collection crews can mark reports collected without being full operators.
+	#[pallet::storage]
+	pub(super) type StatusPermissions<T: Config> =
+		StorageMap<_, Blake2_128Concat, (T::AccountId, WasteStatus), ()>;
+
+	/// Accounts that may file reports while `RequireAllowlist` is set.
+	#[pallet::storage]
+	pub(super) type AllowedReporters<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Certified weighbridges whose mass attestations are accepted, managed by root.
+	#[pallet::storage]
+	pub(super) type TrustedWeighbridges<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The latest weighbridge attestation of the mass of each report, with the weighbridge
+	/// that signed it.
+	#[pallet::storage]
+	pub(super) type WeightAttestations<T: Config> =
+		StorageMap<_, Twox64Concat, ReportId, (T::AccountId, WeighbridgeSignature)>;
+
+	/// Delegated keys allowed to update statuses on behalf of a principal, keyed by
+	/// `(principal, proxy)`.
+	#[pallet::storage]
+	pub(super) type Proxies<T: Config> =
+		StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), ()>;
+
+	/// Whether filing new reports is frozen, e.g. during an incident. Status updates and
+	/// deletions keep working.
+	#[pallet::storage]
+	pub(super) type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Set while a new report is handed to `ReportHook` and stored, so that a hook calling
+	/// back into the pallet is refused rather than changing storage halfway through.
+	#[pallet::storage]
+	pub(super) type Mutating<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// The service zones reports may be filed in, each the rectangle between two corners, keyed
+	/// by zone id. Once any zone is defined, every new location must fall in one of them.
+	#[pallet::storage]
+	#[pallet::getter(fn service_zones)]
+	pub(super) type ServiceZones<T: Config> =
+		StorageMap<_, Twox64Concat, ZoneId, (GeoPoint, GeoPoint)>;
+
+	/// The number of blocks reports filed in each service zone have to be collected in, e.g.
+	/// by a municipal contract. Changing it leaves the deadlines of filed reports as they are.
+	#[pallet::storage]
+	pub(super) type ZoneSla<T: Config> = StorageMap<_, Twox64Concat, ZoneId, BlockNumberFor<T>>;
+
+	/// The reports whose collection deadline is each block. Entries are taken out when a report
+	/// is collected or deleted before its deadline, and the rest when the deadline is reached.
+	#[pallet::storage]
+	pub(super) type SlaDeadlines<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, ReportId, ()>;
+
+	/// The reports that were still in `Reported` at their collection deadline.
+	#[pallet::storage]
+	pub(super) type SlaBreaches<T: Config> = StorageMap<_, Twox64Concat, ReportId, ()>;
+
+	/// The reports in `Reported` in each service zone, so that a zone's backlog can be read as
+	/// a key prefix. Reports leave it when their status moves on or they are deleted.
+	#[pallet::storage]
+	pub(super) type ZoneBacklog<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ZoneId, Twox64Concat, ReportId, ()>;
+
+	/// The reports of each zone in each status, under the big-endian bytes of their id. The
+	/// second key isn't hashed, so a prefix iterates in id order, oldest report first, which is
+	/// what `next_unprocessed` reads.
+	#[pallet::storage]
+	pub(super) type ZoneQueue<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, (ZoneId, WasteStatus), Identity, [u8; 8], ()>;
+
+	/// The zones with an emergency collection under way and the number of reports collected
+	/// in each so far. `on_initialize` carries on with each until its backlog is empty.
+	#[pallet::storage]
+	pub(super) type EmergencyCollections<T: Config> =
+		StorageMap<_, Twox64Concat, ZoneId, u32, ValueQuery>;
+
+	/// The statuses each status may move to, seeded at genesis and editable by root. A status
+	/// without an entry falls back to `Pallet::is_default_transition`.
+	#[pallet::storage]
+	pub(super) type AllowedTransitions<T: Config> =
+		StorageMap<_, Blake2_128Concat, WasteStatus, TransitionTargets>;
+
+	/// The workflows of waste types whose lifecycle differs from the default, as the statuses
+	/// each status may move to for reports of that type. A status without an entry for a type
+	/// falls back to `AllowedTransitions`.
+	#[pallet::storage]
+	pub(super) type TypeTransitions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		WasteType,
+		Blake2_128Concat,
+		WasteStatus,
+		TransitionTargets,
+	>;
+
+	/// Human-readable names of the known waste types, bounded by `MaxTypeNameLen`.
+	#[pallet::storage]
+	pub(super) type WasteTypeRegistry<T: Config> =
+		StorageMap<_, Twox64Concat, WasteType, BoundedVec<u8, T::MaxTypeNameLen>>;
+
+	#[pallet::type_value]
+	pub(super) fn DefaultMinAmountOf<T: Config>() -> WasteAmount {
+		T::DefaultMinAmount::get()
+	}
+
+	/// The smallest amount of each waste type a report component may carry. Types without an
+	/// entry use `DefaultMinAmount`.
+	#[pallet::storage]
+	pub(super) type MinAmountByType<T: Config> =
+		StorageMap<_, Twox64Concat, WasteType, WasteAmount, ValueQuery, DefaultMinAmountOf<T>>;
+
+	/// Variants are grouped by topic rather than by age, so their index in the enum, and with
+	/// it their SCALE encoding, may change between releases. Clients should branch on the code
+	/// `Pallet::error_code` gives instead: each error has one in `ERROR_CODES`, and a code is
+	/// never reused, even after its error is removed.
 	#[pallet::error]
 	pub enum Error<T> {
-		/// A waste data report must have a unique identifier
+		/// A report is already stored under the next id, so `NextReportId` fell behind the map
 		DuplicateReport,
 		/// The total number of waste data reports can't exceed the u64 limit
 		BoundsOverflow,
-
+		/// No report with the given id is on file
 		ReportNotFound,
+		/// The requested status change is not allowed by the waste lifecycle
+		InvalidStatusTransition,
+		/// The report already has the requested status
+		StatusUnchanged,
+		/// Only the account that filed a report may perform this action on it
+		NotReportOwner,
+		/// Only a registered operator may perform this action
+		NotAuthorizedOperator,
+		/// The account is neither an operator nor permitted to set the requested status
+		NotAuthorizedForStatus,
+		/// The account already has the maximum number of reports on file
+		TooManyReports,
+		/// The report location lies outside the configured service area
+		LocationOutOfBounds,
+		/// A report can only be collected once a collector has been assigned to it
+		NoCollectorAssigned,
+		/// The waste type has not been registered
+		UnknownWasteType,
+		/// A batch must contain at least one report
+		EmptyBatch,
+		/// The reporter can't cover the report deposit
+		InsufficientBalanceForDeposit,
+		/// The amount would overflow the running total of reported waste
+		AmountOverflow,
+		/// The amount exceeds the configured maximum for a single report
+		AmountTooLarge,
+		/// The volume exceeds the configured maximum for a single report
+		VolumeTooLarge,
+		/// A component carries less than the minimum amount for its waste type
+		AmountBelowMinimum,
+		/// A report must have at least one component
+		EmptyComponents,
+		/// The amount of a report with several components can't be replaced as a whole
+		MultipleComponents,
+		/// Only a single-component report in `Reported` can be split or partly collected, and
+		/// a report that already has children can't be split
+		CannotSplit,
+		/// The split amounts add up to more than the report carries
+		SplitExceedsAmount,
+		/// A report can't be merged into itself
+		MergeWithSelf,
+		/// Only open reports that aren't disputed can be merged
+		CannotMerge,
+		/// The merged report would be made up of more than `MaxComponents` waste types
+		TooManyComponents,
+		/// The report already has the maximum number of notes
+		TooManyNotes,
+		/// The account has already filed the maximum number of reports in this block
+		RateLimited,
+		/// The same waste type was reported at the same location within the dedup window
+		DuplicateLocationReport,
+		/// The report is not waiting in `Reported` for longer than the stale threshold
+		NotStale,
+		/// The grid cell already holds the maximum number of reports
+		CellFull,
+		/// Evidence has already been attached to the report
+		EvidenceAlreadyAttached,
+		/// The report's status history already holds the maximum number of entries
+		HistoryFull,
+		/// The report's location history already holds the maximum number of entries
+		LocationHistoryFull,
+		/// The operator has already approved this status change
+		DuplicateApproval,
+		/// More approvals are pending than `MaxApprovers` allows
+		TooManyApprovals,
+		/// The status needs approval from several operators, so it can't be set in bulk
+		ApprovalRequired,
+		/// Filing new reports is paused
+		CreationPaused,
+		/// The account's reporter score is below `MinScoreToReport`
+		ReputationTooLow,
+		/// The report is disputed, so its status can't change until the dispute is resolved
+		ReportDisputed,
+		/// The report is not disputed
+		NotDisputed,
+		/// The caller is not a proxy of the account it acts on behalf of
+		NotAProxy,
+		/// Only allowlisted accounts may file reports
+		ReporterNotAllowed,
+		/// The report was modified too recently to change status yet
+		TransitionTooSoon,
+		/// The weighbridge is not in the trusted set
+		UntrustedWeighbridge,
+		/// The account has no rewards to claim
+		NoPendingRewards,
+		/// No status has the given index
+		InvalidStatusIndex,
+		/// More material can't be recovered than the report carries
+		RecoveryExceedsInput,
+		/// The report is being settled and can't change status until the settlement ends
+		ReportSettling,
+		/// The ticket doesn't match a settlement in progress
+		NotSettling,
+		/// Too many settlements already time out at the same block
+		TooManySettlements,
+		/// No draft is reserved under the id
+		NotADraft,
+		/// Too many drafts already expire at the same block
+		TooManyDrafts,
+		/// The status is not in `IndexedStatuses`, so its reports can't be listed
+		StatusNotIndexed,
+		/// Service zones are defined and the location is in none of them
+		LocationOutsideServiceArea,
+		/// The zone id is not below `MaxServiceZones` or its corners are out of order
+		InvalidZone,
+		/// Another report on file already carries this external reference
+		DuplicateExternalRef,
+		/// No deposit is held for the report, e.g. because it was already refunded
+		NoDepositHeld,
+		/// The report was archived and can no longer be changed
+		ReportArchived,
+		/// An emergency collection is already under way in the zone
+		EmergencyInProgress,
+		/// The call was made from inside the pallet while it was storing a report, e.g. by
+		/// `ReportHook`
+		Reentrancy,
+		/// The report is sealed and can't be changed until it is unsealed
+		ReportSealed,
+		/// The report is not sealed
+		NotSealed,
+		/// Only utilized reports can be sealed
+		NotUtilized,
+		/// `MaxActiveReports` reports are already active
+		TooManyActiveReports,
+		/// The waste type has already been registered
+		DuplicateWasteType,
+		/// Fewer than `MinOperators` operators are registered
+		InsufficientOperators,
 	}
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		WasteDataCreated { report_id: ReportId, reporter: T::AccountId },
-		WasteStatusUpdated { report_id: ReportId, operator: T::AccountId },
-	}
+	/// The version of the canonical form `Pallet::report_commitment` hashes. Bumped, rather
+	/// than the form changed in place, if a field ever has to join it.
+	pub const REPORT_COMMITMENT_VERSION: u8 = 2;
 
-	#[pallet::config]
-	pub trait Config: frame_system::Config {
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-	}
+	/// Where the buckets of `Pallet::amount_histogram` start after the first, in whole units:
+	/// below 10, 10 to 100, 100 to 1000, and 1000 or more.
+	pub const HISTOGRAM_BOUNDS: [u64; 3] = [10, 100, 1_000];
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		#[pallet::weight(10_000)]
-		#[pallet::call_index(0)]
-		pub fn create_waste_data(
-			origin: OriginFor<T>,
-			waste_type: WasteType,
-			waste_amount: WasteAmount,
-			location_x: u32,
-			location_y: u32,
-		) -> DispatchResultWithPostInfo {
-			let reporter = ensure_signed(origin)?;
+	/// How many ids a walk of the id space such as `Pallet::list_reports` probes at most per
+	/// call, however many of them belong to deleted reports.
+	pub const MAX_ID_PROBES: u32 = 1_000;
 
-			let report_id =
-				WasteDataCount::<T>::get().checked_add(1).ok_or(Error::<T>::BoundsOverflow)?;
-			WasteDataCount::<T>::put(report_id);
+	/// The bit of `WasteData::flags` marking waste that is dangerous to handle.
+	pub const FLAG_HAZARDOUS: u16 = 1 << 0;
+	/// The bit of `WasteData::flags` marking waste that can be recycled.
+	pub const FLAG_RECYCLABLE: u16 = 1 << 1;
+	/// The bit of `WasteData::flags` marking waste that may only be moved under a permit.
+	pub const FLAG_REQUIRES_PERMIT: u16 = 1 << 2;
+	/// The bit of `WasteData::flags` marking wet waste, which needs a sealed container.
+	pub const FLAG_WET: u16 = 1 << 3;
 
-			let waste_data = WasteData {
-				report_id,
-				waste_type,
-				waste_amount,
-				status: WasteStatus::Reported,
-				location_x,
-				location_y,
-				reporter: reporter.clone(),
-			};
+	/// The stable code of every error, by name. New errors take the next unused code.
+	pub const ERROR_CODES: &[(&str, u16)] = &[
+		("DuplicateReport", 1),
+		("BoundsOverflow", 2),
+		("ReportNotFound", 3),
+		("InvalidStatusTransition", 4),
+		("StatusUnchanged", 5),
+		("NotReportOwner", 6),
+		("NotAuthorizedOperator", 7),
+		("NotAuthorizedForStatus", 8),
+		("TooManyReports", 9),
+		("LocationOutOfBounds", 10),
+		("NoCollectorAssigned", 11),
+		("UnknownWasteType", 12),
+		("EmptyBatch", 13),
+		("InsufficientBalanceForDeposit", 14),
+		("AmountOverflow", 15),
+		("AmountTooLarge", 16),
+		("VolumeTooLarge", 17),
+		("AmountBelowMinimum", 18),
+		("EmptyComponents", 19),
+		("MultipleComponents", 20),
+		("CannotSplit", 21),
+		("SplitExceedsAmount", 22),
+		("MergeWithSelf", 23),
+		("CannotMerge", 24),
+		("TooManyComponents", 25),
+		("TooManyNotes", 26),
+		("RateLimited", 27),
+		("DuplicateLocationReport", 28),
+		("NotStale", 29),
+		("CellFull", 30),
+		("EvidenceAlreadyAttached", 31),
+		("HistoryFull", 32),
+		("LocationHistoryFull", 33),
+		("DuplicateApproval", 34),
+		("TooManyApprovals", 35),
+		("ApprovalRequired", 36),
+		("CreationPaused", 37),
+		("ReputationTooLow", 38),
+		("ReportDisputed", 39),
+		("NotDisputed", 40),
+		("NotAProxy", 41),
+		("ReporterNotAllowed", 42),
+		("TransitionTooSoon", 43),
+		("UntrustedWeighbridge", 44),
+		("NoPendingRewards", 45),
+		("InvalidStatusIndex", 46),
+		("RecoveryExceedsInput", 47),
+		("ReportSettling", 48),
+		("NotSettling", 49),
+		("TooManySettlements", 50),
+		("NotADraft", 51),
+		("TooManyDrafts", 52),
+		("StatusNotIndexed", 53),
+		("LocationOutsideServiceArea", 54),
+		("InvalidZone", 55),
+		("DuplicateExternalRef", 56),
+		("NoDepositHeld", 57),
+		("ReportArchived", 58),
+		("EmergencyInProgress", 59),
+		("Reentrancy", 61),
+		("ReportSealed", 62),
+		("NotSealed", 63),
+		("NotUtilized", 64),
+		("TooManyActiveReports", 65),
+		("DuplicateWasteType", 67),
+		("InsufficientOperators", 68),
+	];
 
-			WasteDataMap::<T>::try_mutate_exists(report_id, |waste_data_opt| {
-				ensure!(waste_data_opt.is_none(), Error::<T>::DuplicateReport);
-				*waste_data_opt = Some(waste_data.clone());
+	/// `WasteDataCreated` and `WasteStatusUpdated` are deposited with two topics, so that
+	/// subscribers can filter them without decoding every event: first the `T::Hashing` hash
+	/// of the SCALE encoded `(b"report", report_id)`, then that of `(b"reporter", reporter)`.
+	/// `Pallet::report_topic` and `Pallet::reporter_topic` compute them.
+	///
+	/// Every event carries `seq`, its number in the pallet's event stream. The numbers
+	/// increase by one with every event, across blocks, so an indexer can tell when it missed
+	/// one.
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_numbered_event)]
+	pub enum Event<T: Config> {
+		WasteDataCreated {
+			report_id: ReportId,
+			reporter: T::AccountId,
+			severity: Severity,
+			/// The type of the first component.
+			waste_type: WasteType,
+			/// The total amount over all components.
+			waste_amount: WasteAmount,
+			volume: u64,
+			/// The length of the origin tag, 0 if there is none.
+			origin_tag_len: u32,
+			status: WasteStatus,
+			/// Whether the report was filed with a location.
+			has_location: bool,
+			seq: u64,
+		},
+		WasteStatusUpdated {
+			report_id: ReportId,
+			operator: T::AccountId,
+			old_status: WasteStatus,
+			new_status: WasteStatus,
+			/// The collector on the report after the change.
+			collector: Option<T::AccountId>,
+			seq: u64,
+		},
+		WasteDataDeleted {
+			report_id: ReportId,
+			remover: T::AccountId,
+			seq: u64,
+		},
+		OperatorAdded {
+			operator: T::AccountId,
+			seq: u64,
+		},
+		OperatorRemoved {
+			operator: T::AccountId,
+			seq: u64,
+		},
+		/// Root exempted `account` from `MinTransitionDelay`.
+		CooldownExemptAdded {
+			account: T::AccountId,
+			seq: u64,
+		},
+		/// Root made `account` wait out `MinTransitionDelay` again.
+		CooldownExemptRemoved {
+			account: T::AccountId,
+			seq: u64,
+		},
+		WasteTypeRegistered {
+			waste_type: WasteType,
+			seq: u64,
+		},
+		/// Root set the minimum amount of a waste type, or reset it to the default.
+		MinAmountSet {
+			waste_type: WasteType,
+			min_amount: Option<WasteAmount>,
+			seq: u64,
+		},
+		WasteDataBatchCreated {
+			first_id: ReportId,
+			count: u32,
+			reporter: T::AccountId,
+			seq: u64,
+		},
+		/// `reporter` filed again under an idempotency key they had already used, so nothing
+		/// was filed and the call succeeded with the report the key was first used for.
+		KeyReused {
+			reporter: T::AccountId,
+			key: IdempotencyKey,
+			report_id: ReportId,
+			seq: u64,
+		},
+		WasteAmountUpdated {
+			report_id: ReportId,
+			old_amount: WasteAmount,
+			new_amount: WasteAmount,
+			seq: u64,
+		},
+		ReportCancelled {
+			report_id: ReportId,
+			reason_len: u32,
+			seq: u64,
+		},
+		/// The plant refused a transported load.
+		ReportRejected {
+			report_id: ReportId,
+			reason_len: u32,
+			seq: u64,
+		},
+		/// Part of a pile was split off into `children`, e.g. to load it onto several trucks.
+		ReportSplit {
+			parent: ReportId,
+			children: BoundedVec<ReportId, T::MaxSplit>,
+			seq: u64,
+		},
+		/// `absorb` described the same pile as `keep` and was merged into it.
+		ReportsMerged {
+			keep: ReportId,
+			absorb: ReportId,
+			seq: u64,
+		},
+		/// `amount` of `parent` was collected and moved into `child`.
+		PartialCollection {
+			parent: ReportId,
+			child: ReportId,
+			amount: WasteAmount,
+			seq: u64,
+		},
+		/// `ForceOrigin` moved `count` completed reports to `ArchivedReports`.
+		ReportsArchived {
+			count: u32,
+			seq: u64,
+		},
+		CollectorAssigned {
+			report_id: ReportId,
+			collector: T::AccountId,
+			seq: u64,
+		},
+		ReportTransferred {
+			report_id: ReportId,
+			from: T::AccountId,
+			to: T::AccountId,
+			seq: u64,
+		},
+		/// `count` reports were moved; `truncated` if more were left for a later call.
+		BulkStatusUpdated {
+			count: u32,
+			truncated: bool,
+			seq: u64,
+		},
+		/// `count` reports of `waste_type` were moved; `truncated` if reports were left
+		/// unexamined for a later call.
+		BulkTypedStatusUpdated {
+			waste_type: WasteType,
+			count: u32,
+			truncated: bool,
+			seq: u64,
+		},
+		EvidenceAttached {
+			report_id: ReportId,
+			seq: u64,
+		},
+		StatusPermissionGranted {
+			account: T::AccountId,
+			status: WasteStatus,
+			seq: u64,
+		},
+		StatusPermissionRevoked {
+			account: T::AccountId,
+			status: WasteStatus,
+			seq: u64,
+		},
+		ReporterAllowed {
+			account: T::AccountId,
+			seq: u64,
+		},
+		ReporterDisallowed {
+			account: T::AccountId,
+			seq: u64,
+		},
+		WeighbridgeTrusted {
+			weighbridge: T::AccountId,
+			seq: u64,
+		},
+		WeighbridgeUntrusted {
+			weighbridge: T::AccountId,
+			seq: u64,
+		},
+		/// An operator anchored `weighbridge`'s signed mass of the report.
+		WeightAttested {
+			report_id: ReportId,
+			weighbridge: T::AccountId,
+			seq: u64,
+		},
+		ProxyAdded {
+			principal: T::AccountId,
+			proxy: T::AccountId,
+			seq: u64,
+		},
+		ProxyRemoved {
+			principal: T::AccountId,
+			proxy: T::AccountId,
+			seq: u64,
+		},
+		LocationUpdated {
+			report_id: ReportId,
+			seq: u64,
+		},
+		ReportFlaggedStale {
+			report_id: ReportId,
+			seq: u64,
+		},
+		NoteAdded {
+			report_id: ReportId,
+			note_index: u32,
+			seq: u64,
+		},
+		/// The reporter of a utilized report was credited their share of `CompletionReward`, to
+		/// be claimed with `claim_rewards`.
+		RewardCredited {
+			report_id: ReportId,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+			seq: u64,
+		},
+		/// The completion reward of a report was split between its reporter and the treasury.
+		RewardSplit {
+			report_id: ReportId,
+			beneficiary_amount: BalanceOf<T>,
+			treasury_amount: BalanceOf<T>,
+			seq: u64,
+		},
+		/// `who` was paid the completion rewards credited to them.
+		RewardsClaimed {
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+			seq: u64,
+		},
+		/// `ForceOrigin` found a report fraudulent: `amount` of its deposit was slashed and it
+		/// was rejected.
+		ReportSlashed {
+			report_id: ReportId,
+			amount: BalanceOf<T>,
+			seq: u64,
+		},
+		/// `ForceOrigin` started marking every report in `Reported` in `zone_id` collected.
+		EmergencyCollectionStarted {
+			zone_id: ZoneId,
+			seq: u64,
+		},
+		/// The emergency collection in `zone_id` is over, having collected `count` reports.
+		EmergencyCollectionCompleted {
+			zone_id: ZoneId,
+			count: u32,
+			seq: u64,
+		},
+		/// `ForceOrigin` looked up the account behind a report.
+		ReporterResolved {
+			report_id: ReportId,
+			reporter: T::AccountId,
+			seq: u64,
+		},
+		/// A cancelled or rejected report was returned to `Reported`.
+		ReportReopened {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// `ForceOrigin` sealed a utilized report, so that it can no longer change.
+		ReportSealed {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// `ForceOrigin` lifted the seal on a report.
+		ReportUnsealed {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// `WasteDataCreated` without the details, deposited instead of it unless
+		/// `VerboseEvents` is set.
+		WasteDataCreatedMinimal {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// `WasteStatusUpdated` without the details, deposited instead of it unless
+		/// `VerboseEvents` is set.
+		WasteStatusUpdatedMinimal {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// The reporter or an operator corrected the waste type of a report.
+		WasteTypeUpdated {
+			report_id: ReportId,
+			old_type: WasteType,
+			new_type: WasteType,
+			seq: u64,
+		},
+		/// `recompute_aggregates` finished, replacing the status, type and zone totals with
+		/// those of the `reports` on file.
+		AggregatesRecomputed {
+			reports: u64,
+			seq: u64,
+		},
+		/// A runtime upgrade found a batch job of `kind` part way through and discarded its
+		/// progress. The job starts over when next called.
+		PendingBatchCleared {
+			kind: BatchKind,
+			seq: u64,
+		},
+		/// `prune_orphans` visited every status index entry and removed the `count` of them
+		/// whose report is no longer on file.
+		OrphansPruned {
+			count: u64,
+			seq: u64,
+		},
+		/// Root registered `count` waste types in one `register_waste_types_batch`.
+		WasteTypesBatchRegistered {
+			count: u32,
+			seq: u64,
+		},
+		/// A report was filed again under its `external_ref` and, under
+		/// `ExternalRefMode::UpsertAmount`, its amount replaced with the new one.
+		ReportUpserted {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// An operator changed the handling flags of a report to `flags`.
+		FlagsUpdated {
+			report_id: ReportId,
+			flags: u16,
+			seq: u64,
+		},
+		/// `ForceOrigin` set the status of a report, bypassing the lifecycle.
+		StatusForceUpdated {
+			report_id: ReportId,
+			new_status: WasteStatus,
+			seq: u64,
+		},
+		/// The reporter disputed the current status of a report.
+		StatusDisputed {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// `ForceOrigin` resolved the dispute on a report.
+		DisputeResolved {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// `ForceOrigin` paused or resumed the filing of new reports.
+		PauseToggled {
+			paused: bool,
+			seq: u64,
+		},
+		/// An id was reserved for a report to be filled in later with `finalize_draft`.
+		ReportIdReserved {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// A reserved id was not filled in within `DraftExpiry` blocks and was given up.
+		DraftExpired {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// A report sat in `Reported` or `Collected` for `AutoExpiryBlocks` and was cancelled.
+		ReportAutoExpired {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// The reporter set or cleared the contact on a report.
+		ContactUpdated {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// An operator approved moving a report into a guarded status. The change takes effect
+		/// on the approval that brings `approvals` to the required count.
+		ApprovalRecorded {
+			report_id: ReportId,
+			status: WasteStatus,
+			approver: T::AccountId,
+			approvals: u32,
+			seq: u64,
+		},
+		/// `ForceOrigin` deleted `count` of the reports filed by `account`; `truncated` if more
+		/// were left for a later call.
+		ReportsPurged {
+			account: T::AccountId,
+			count: u32,
+			truncated: bool,
+			seq: u64,
+		},
+		/// `ForceOrigin` moved `count` reports from the retired status `from` to `to`.
+		StatusMigrated {
+			from: WasteStatus,
+			to: WasteStatus,
+			count: u32,
+			seq: u64,
+		},
+		/// An operator assigned `collector` to `count` reports of the backlog of `zone_id`.
+		ZoneAssigned {
+			zone_id: ZoneId,
+			collector: T::AccountId,
+			count: u32,
+			seq: u64,
+		},
+		/// A service zone was defined, moved or, with `zone` `None`, removed.
+		ServiceZoneSet {
+			zone_id: ZoneId,
+			zone: Option<(GeoPoint, GeoPoint)>,
+			seq: u64,
+		},
+		/// Root set the collection SLA of a service zone, or with `sla_blocks` `None` lifted it.
+		ZoneSlaSet {
+			zone_id: ZoneId,
+			sla_blocks: Option<BlockNumberFor<T>>,
+			seq: u64,
+		},
+		/// A report was still waiting for collection at its deadline.
+		SlaBreached {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// Root replaced the statuses reports in `from` may move to.
+		AllowedTransitionsSet {
+			from: WasteStatus,
+			seq: u64,
+		},
+		/// The statuses reports of `waste_type` in `from` may move to were replaced or, when
+		/// cleared, returned to the default workflow.
+		TypeWorkflowSet {
+			waste_type: WasteType,
+			from: WasteStatus,
+			seq: u64,
+		},
+		/// A report reached `Utilized`, the end of its lifecycle.
+		ReportCompleted {
+			report_id: ReportId,
+			reporter: T::AccountId,
+			total_amount: WasteAmount,
+			seq: u64,
+		},
+		/// A report was utilized through `finalize_utilization`, recovering `recovered` of its
+		/// `input` as usable material.
+		Utilized {
+			report_id: ReportId,
+			input: WasteAmount,
+			recovered: WasteAmount,
+			seq: u64,
+		},
+		/// A report entered `Settling` and will time out at `deadline` unless committed.
+		SettlementPrepared {
+			report_id: ReportId,
+			deadline: BlockNumberFor<T>,
+			seq: u64,
+		},
+		/// A settlement was committed and its report utilized.
+		SettlementCommitted {
+			report_id: ReportId,
+			seq: u64,
+		},
+		/// A settlement was not committed in time, leaving its report in `status`.
+		SettlementTimedOut {
+			report_id: ReportId,
+			status: WasteStatus,
+			seq: u64,
+		},
+		/// Checkpoint deposited after every runtime upgrade, so that the counters can be checked
+		/// against the number of reports actually stored.
+		StorageReconciled {
+			map_entries: u64,
+			active_count: u64,
+			next_id: ReportId,
+			seq: u64,
+		},
+		/// Periodic snapshot of the reports on file and the amount of waste in each status.
+		BlockSummary {
+			total_reports: u64,
+			reported: WasteAmount,
+			collected: WasteAmount,
+			transported: WasteAmount,
+			utilized: WasteAmount,
+			seq: u64,
+		},
+	}
+
+	impl<T: Config> Event<T> {
+		/// The report the event is about, if it is about one on file. Reserved ids aren't
+		/// reports yet, and a deleted report takes its log with it, so neither is logged.
+		fn report_id(&self) -> Option<ReportId> {
+			match self {
+				Event::WasteDataCreated { report_id, .. } |
+				Event::WasteStatusUpdated { report_id, .. } |
+				Event::KeyReused { report_id, .. } |
+				Event::WasteAmountUpdated { report_id, .. } |
+				Event::ReportCancelled { report_id, .. } |
+				Event::ReportRejected { report_id, .. } |
+				Event::CollectorAssigned { report_id, .. } |
+				Event::ReportTransferred { report_id, .. } |
+				Event::EvidenceAttached { report_id, .. } |
+				Event::WeightAttested { report_id, .. } |
+				Event::LocationUpdated { report_id, .. } |
+				Event::ReportFlaggedStale { report_id, .. } |
+				Event::NoteAdded { report_id, .. } |
+				Event::RewardCredited { report_id, .. } |
+				Event::RewardSplit { report_id, .. } |
+				Event::ReportSlashed { report_id, .. } |
+				Event::ReporterResolved { report_id, .. } |
+				Event::ReportReopened { report_id, .. } |
+				Event::ReportSealed { report_id, .. } |
+				Event::ReportUnsealed { report_id, .. } |
+				Event::WasteDataCreatedMinimal { report_id, .. } |
+				Event::WasteStatusUpdatedMinimal { report_id, .. } |
+				Event::WasteTypeUpdated { report_id, .. } |
+				Event::SlaBreached { report_id, .. } |
+				Event::ReportUpserted { report_id, .. } |
+				Event::FlagsUpdated { report_id, .. } |
+				Event::StatusForceUpdated { report_id, .. } |
+				Event::StatusDisputed { report_id, .. } |
+				Event::DisputeResolved { report_id, .. } |
+				Event::ReportAutoExpired { report_id, .. } |
+				Event::ContactUpdated { report_id, .. } |
+				Event::ApprovalRecorded { report_id, .. } |
+				Event::ReportCompleted { report_id, .. } |
+				Event::Utilized { report_id, .. } |
+				Event::SettlementPrepared { report_id, .. } |
+				Event::SettlementCommitted { report_id, .. } |
+				Event::SettlementTimedOut { report_id, .. } => Some(*report_id),
+				Event::ReportSplit { parent, .. } | Event::PartialCollection { parent, .. } =>
+					Some(*parent),
+				Event::ReportsMerged { keep, .. } => Some(*keep),
+				_ => None,
+			}
+		}
+	}
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig {
+		/// Waste types available from block zero, as `(category, name)` pairs.
+		pub waste_types: Vec<(WasteType, Vec<u8>)>,
+		/// The lifecycle, as the statuses each status may move to.
+		pub allowed_transitions: Vec<(WasteStatus, Vec<WasteStatus>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl Default for GenesisConfig {
+		fn default() -> Self {
+			use WasteStatus::*;
+			Self {
+				waste_types: Vec::new(),
+				allowed_transitions: vec![
+					(Reported, vec![Collected, Cancelled]),
+					(Collected, vec![Transported, Cancelled]),
+					(Transported, vec![Utilized, Rejected, Cancelled]),
+					(Utilized, vec![]),
+					(Cancelled, vec![]),
+					(Rejected, vec![]),
+				],
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			for (waste_type, name) in &self.waste_types {
+				let name: BoundedVec<u8, T::MaxTypeNameLen> = name
+					.clone()
+					.try_into()
+					.expect("genesis waste type name exceeds MaxTypeNameLen");
+				WasteTypeRegistry::<T>::insert(waste_type, name);
+			}
+			for (from, targets) in &self.allowed_transitions {
+				let targets: TransitionTargets = targets
+					.clone()
+					.try_into()
+					.expect("genesis transition list has more targets than there are statuses");
+				AllowedTransitions::<T>::insert(from, targets);
+			}
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The key the off-chain worker signs `flag_stale` transactions with.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// How many blocks a report may wait in `Reported` before it is considered stale.
+		#[pallet::constant]
+		type StaleThreshold: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of unflagged `Reported` reports the off-chain worker examines
+		/// per block.
+		#[pallet::constant]
+		type MaxStaleScan: Get<u32>;
+
+		/// How many blocks after filing a report still in `Reported` or `Collected` is cancelled
+		/// automatically. Zero disables auto-expiry.
+		#[pallet::constant]
+		type AutoExpiryBlocks: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of expiry steps per block, each either one report or one filing
+		/// block of the index.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		/// How many blocks an id reserved by `reserve_report_id` is held for its report to be
+		/// filled in. At least one block is always given.
+		#[pallet::constant]
+		type DraftExpiry: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of reports a single account can have on file.
+		#[pallet::constant]
+		type MaxReportsPerAccount: Get<u32>;
+
+		/// The maximum number of reports counted in `OpenReportCount` at once. Filing and
+		/// reopening fail at the ceiling until a report is completed, cancelled, rejected or
+		/// deleted.
+		#[pallet::constant]
+		type MaxActiveReports: Get<u64>;
+
+		/// The statuses whose reports are mirrored in `WasteDataByStatus`. Every mirrored
+		/// status costs a remove and an insert on each status change and a rewrite on each
+		/// edit, but only mirrored statuses can be read by prefix: `reports_with_status`, the
+		/// stale report scan, `bulk_update_status`, `migrate_status` and `archive_completed`
+		/// see nothing in the others.
+		type IndexedStatuses: Get<&'static [WasteStatus]>;
+
+		/// The largest accepted `x` coordinate of a location.
+		#[pallet::constant]
+		type MaxLocationX: Get<u32>;
+
+		/// The largest accepted `y` coordinate of a location.
+		#[pallet::constant]
+		type MaxLocationY: Get<u32>;
+
+		/// The number of service zones, whose ids run from 0 up to but not including it. This
+		/// bounds the scan for the zone of a location.
+		#[pallet::constant]
+		type MaxServiceZones: Get<u32>;
+
+		/// The maximum number of reports an emergency collection marks `Collected` in a zone
+		/// per block.
+		#[pallet::constant]
+		type MaxEmergencyBatch: Get<u32>;
+
+		/// The maximum length of a registered waste type name.
+		#[pallet::constant]
+		type MaxTypeNameLen: Get<u32>;
+
+		/// The maximum number of reports a single account can file in one block.
+		#[pallet::constant]
+		type MaxReportsPerBlock: Get<u32>;
+
+		/// The maximum number of reports that can be filed in a single batch.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		/// The maximum number of ids `statuses_of` looks up in one call.
+		#[pallet::constant]
+		type MaxQueryBatch: Get<u32>;
+
+		/// The maximum number of reports a report can be split into.
+		#[pallet::constant]
+		type MaxSplit: Get<u32>;
+
+		/// The maximum length of a cancellation reason.
+		#[pallet::constant]
+		type MaxReasonLen: Get<u32>;
+
+		/// The maximum length of a reporter contact.
+		#[pallet::constant]
+		type MaxContactLen: Get<u32>;
+
+		/// The maximum length of the origin tag of a report.
+		#[pallet::constant]
+		type MaxTagLen: Get<u32>;
+
+		/// The maximum length of an inspection note.
+		#[pallet::constant]
+		type MaxNoteLen: Get<u32>;
+
+		/// The maximum number of inspection notes on a single report.
+		#[pallet::constant]
+		type MaxNotes: Get<u32>;
+
+		/// The maximum number of entries in a report's status history. A report whose history
+		/// is full can't change status, so this should cover the longest lifecycle.
+		#[pallet::constant]
+		type MaxHistoryLen: Get<u32>;
+
+		/// The maximum number of locations in a report's route. What happens to a move once it
+		/// is full is set by `RouteHistoryMode`.
+		#[pallet::constant]
+		type MaxLocationHistory: Get<u32>;
+
+		/// The maximum number of entries in a report's event log. Older entries are dropped
+		/// to make room for new ones.
+		#[pallet::constant]
+		type MaxEventLog: Get<u32>;
+
+		/// The number of entries `RecentActivity` keeps. Older entries are dropped to make room
+		/// for new ones.
+		#[pallet::constant]
+		type RecentActivityLen: Get<u32>;
+
+		/// Whether a move is refused or the oldest location dropped once a report's route is
+		/// full. Either way the route never exceeds `MaxLocationHistory`.
+		#[pallet::constant]
+		type RouteHistoryMode: Get<RouteHistoryMode>;
+
+		/// Whether a report filed under an `external_ref` already on file is refused or updates
+		/// the amount of the report on file.
+		#[pallet::constant]
+		type ExternalRefMode: Get<ExternalRefMode>;
+
+		/// The side length of a grid cell in `ReportsByCell`, in location units.
+		#[pallet::constant]
+		type CellSize: Get<u32>;
+
+		/// The maximum number of reports indexed in a single grid cell.
+		#[pallet::constant]
+		type MaxPerCell: Get<u32>;
+
+		/// How many rings of grid cells around its own cell `nearest_reports` searches at most.
+		#[pallet::constant]
+		type MaxSearchRings: Get<u32>;
+
+		/// For how many blocks a report blocks another of the same waste type at the same
+		/// location. Zero disables deduplication.
+		#[pallet::constant]
+		type DedupWindow: Get<BlockNumberFor<Self>>;
+
+		/// The fewest blocks that must pass after a report was last modified before it can move
+		/// on in the lifecycle, since real waste can't be collected and utilized in an instant.
+		/// Cancellation and `ForceOrigin` updates are exempt. Zero disables the delay.
+		#[pallet::constant]
+		type MinTransitionDelay: Get<BlockNumberFor<Self>>;
+
+		/// Blocks a settlement may stay prepared before it times out and the report is released
+		/// in its prior status. At least one block is always given.
+		#[pallet::constant]
+		type SettlementTimeout: Get<BlockNumberFor<Self>>;
+
+		/// The currency in which report deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The amount reserved from the reporter for every report filed. It is returned once
+		/// the report is utilized or deleted.
+		#[pallet::constant]
+		type ReportDeposit: Get<BalanceOf<Self>>;
+
+		/// Where deposits slashed by `slash_report` go, e.g. the treasury. `()` burns them.
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The amount credited to the reporter when one of their reports is utilized. It is
+		/// minted when they claim it with `claim_rewards`.
+		#[pallet::constant]
+		type CompletionReward: Get<BalanceOf<Self>>;
+
+		/// The share of every completion reward credited to `TreasuryAccount` instead of the
+		/// reporter.
+		#[pallet::constant]
+		type TreasuryCut: Get<Permill>;
+
+		/// The municipal treasury, which claims its cut of completion rewards with
+		/// `claim_rewards` like any reporter.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The maximum number of waste types a single report can be made up of.
+		#[pallet::constant]
+		type MaxComponents: Get<u32>;
+
+		/// The largest amount a single report may carry.
+		#[pallet::constant]
+		type MaxWasteAmount: Get<WasteAmount>;
+
+		/// The largest volume a single report may take up.
+		#[pallet::constant]
+		type MaxVolume: Get<u64>;
+
+		/// The smallest amount a report component may carry, unless root has set a minimum for
+		/// its waste type.
+		#[pallet::constant]
+		type DefaultMinAmount: Get<WasteAmount>;
+
+		/// The number of implied decimal places in a `WasteAmount`, whose whole unit is one
+		/// tonne. With 6 decimals, an amount of 1 is one gram.
+		#[pallet::constant]
+		type AmountDecimals: Get<u8>;
+
+		/// How often, in blocks, a `BlockSummary` event is emitted. Zero disables the summary.
+		#[pallet::constant]
+		type SummaryInterval: Get<BlockNumberFor<Self>>;
+
+		/// The lowest reporter score that may still file reports. Use `i32::MIN` to never block
+		/// anyone.
+		#[pallet::constant]
+		type MinScoreToReport: Get<i32>;
+
+		/// Whether only accounts in `AllowedReporters` may file reports, as on a permissioned
+		/// network.
+		#[pallet::constant]
+		type RequireAllowlist: Get<bool>;
+
+		/// Whether reports are filed under a hash of the reporter rather than their account,
+		/// for public chains. See `Pallet::reporter_key`. Reports filed before it was switched
+		/// on keep their account.
+		#[pallet::constant]
+		type PrivacyMode: Get<bool>;
+
+		/// Whether filing a report and changing its status deposit `WasteDataCreated` and
+		/// `WasteStatusUpdated` with the full details, or `WasteDataCreatedMinimal` and
+		/// `WasteStatusUpdatedMinimal` with only the report id. Minimal events keep blocks small
+		/// on busy chains, but indexers then have to read the details from storage, by which
+		/// time the report may have changed again or been deleted.
+		#[pallet::constant]
+		type VerboseEvents: Get<bool>;
+
+		/// Whether moving a report with no collector to `Collected` records the operator making
+		/// the change as its collector, for crews that collect and update the report themselves.
+		/// If not set, such a change fails with `NoCollectorAssigned` until `assign_collector`
+		/// is called.
+		#[pallet::constant]
+		type AutoStampCollector: Get<bool>;
+
+		/// A status that only takes effect once this many distinct operators have asked for
+		/// it, e.g. `(Utilized, 2)` so that no single operator can trigger the reward. A count
+		/// of 0 or 1 leaves the status unguarded.
+		type RequiredApprovals: Get<(WasteStatus, u32)>;
+
+		/// The maximum number of approvals held for a pending status change. Must be at least
+		/// one less than the count in `RequiredApprovals`.
+		#[pallet::constant]
+		type MaxApprovers: Get<u32>;
+
+		/// The number of operators that must be registered before `update_waste_status` accepts
+		/// any change, so that a chain being bootstrapped isn't run by a single operator. 0 or 1
+		/// leaves it to the operator check alone.
+		#[pallet::constant]
+		type MinOperators: Get<u32>;
+
+		/// The origin that may set any report to any status, e.g. `EnsureRoot`.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Inspects, and may veto, every report before it is stored. Use `()` for none.
+		type ReportHook: OnReportCreated<Self>;
+
+		/// Told about every status `update_waste_status` moves a report to. Use `()` for none.
+		type OnStatusReached: OnStatusReached<Self>;
+
+		/// Weight information for the extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let summary = if Self::is_summary_block(n) {
+				// The summary reads the active count and one total per status.
+				T::DbWeight::get().reads(5)
+			} else {
+				Weight::zero()
+			};
+			summary
+				.saturating_add(Self::expire_reports(n))
+				.saturating_add(Self::time_out_settlements(n))
+				.saturating_add(Self::expire_drafts(n))
+				.saturating_add(Self::flag_sla_breaches(n))
+				.saturating_add(Self::continue_emergency_collections())
+		}
+
+		/// Flags reports that have been waiting in `Reported` for too long, which usually
+		/// means dispatch failed.
+		fn offchain_worker(now: BlockNumberFor<T>) {
+			let stale: Vec<ReportId> = WasteDataByStatus::<T>::iter_prefix(WasteStatus::Reported)
+				.filter(|(report_id, _)| !StaleFlags::<T>::contains_key(report_id))
+				.take(T::MaxStaleScan::get() as usize)
+				.filter(|(_, waste_data)| Self::is_stale(waste_data, now))
+				.map(|(report_id, _)| report_id)
+				.collect();
+			if stale.is_empty() {
+				return
+			}
+
+			let signer = Signer::<T, T::AuthorityId>::any_account();
+			for report_id in stale {
+				// A failed submission is retried by a later block's worker.
+				let _ = signer.send_signed_transaction(|_| Call::flag_stale { report_id });
+			}
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			<crate::migrations::v1::MigrateToV1<T> as OnRuntimeUpgrade>::on_runtime_upgrade()
+				.saturating_add(Self::clear_pending_batches())
+				.saturating_add(Self::reconcile_storage())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			<crate::migrations::v1::MigrateToV1<T> as OnRuntimeUpgrade>::pre_upgrade()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			<crate::migrations::v1::MigrateToV1<T> as OnRuntimeUpgrade>::post_upgrade(state)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+
+		fn on_finalize(n: BlockNumberFor<T>) {
+			if Self::is_summary_block(n) {
+				Self::deposit_event(|seq| Event::BlockSummary {
+					total_reports: ActiveReportCount::<T>::get(),
+					reported: TotalAmountByStatus::<T>::get(WasteStatus::Reported),
+					collected: TotalAmountByStatus::<T>::get(WasteStatus::Collected),
+					transported: TotalAmountByStatus::<T>::get(WasteStatus::Transported),
+					utilized: TotalAmountByStatus::<T>::get(WasteStatus::Utilized),
+					seq,
+				});
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Files a report. A client that may retry can pass an `idempotency_key`: if the caller
+		/// has already filed under that key, nothing is filed and the call succeeds with just a
+		/// `KeyReused` event naming the existing report.
+		///
+		/// The component amounts are in `unit` and converted with `to_canonical`, or are
+		/// already `WasteAmount`s if no unit is given.
+		///
+		/// Reports with more metadata are charged less than the declared weight, by
+		/// `METADATA_DISCOUNT_PERCENT` for each point of `completeness_score`.
+		#[pallet::weight(T::WeightInfo::create_waste_data())]
+		#[pallet::call_index(0)]
+		pub fn create_waste_data(
+			origin: OriginFor<T>,
+			components: Components<T>,
+			volume: u64,
+			location: GeoPoint,
+			severity: Severity,
+			contact: Option<Contact<T>>,
+			origin_tag: Option<OriginTag<T>>,
+			idempotency_key: Option<IdempotencyKey>,
+			external_ref: Option<ExternalRef>,
+			unit: Option<WasteUnit>,
+		) -> DispatchResultWithPostInfo {
+			let reporter = ensure_signed(origin)?;
+			Self::file_from_call(
+				&reporter,
+				components,
+				volume,
+				Some(location),
+				severity,
+				contact,
+				origin_tag,
+				idempotency_key,
+				external_ref,
+				unit,
+			)
+		}
+
+		/// `create_waste_data` for waste with no meaningful map position, such as office e-waste
+		/// inside a facility. The report is left out of every cell, zone and route.
+		#[pallet::weight(T::WeightInfo::create_waste_data())]
+		#[pallet::call_index(59)]
+		pub fn create_waste_data_no_location(
+			origin: OriginFor<T>,
+			components: Components<T>,
+			volume: u64,
+			severity: Severity,
+			contact: Option<Contact<T>>,
+			origin_tag: Option<OriginTag<T>>,
+			idempotency_key: Option<IdempotencyKey>,
+			external_ref: Option<ExternalRef>,
+			unit: Option<WasteUnit>,
+		) -> DispatchResultWithPostInfo {
+			let reporter = ensure_signed(origin)?;
+			Self::file_from_call(
+				&reporter,
+				components,
+				volume,
+				None,
+				severity,
+				contact,
+				origin_tag,
+				idempotency_key,
+				external_ref,
+				unit,
+			)
+		}
+
+		/// Moves a report to `new_status`. With `on_behalf_of`, the caller acts as a proxy of
+		/// that principal, whose permissions apply and who is recorded as the operator.
+		///
+		/// The weight depends on `new_status`, see `status_update_weight`.
+		#[pallet::weight(Pallet::<T>::status_update_weight(new_status))]
+		#[pallet::call_index(1)]
+		pub fn update_waste_status(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			new_status: WasteStatus,
+			on_behalf_of: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			Self::do_update_waste_status(caller, report_id, new_status, on_behalf_of)
+		}
+
+		/// `update_waste_status` with the status given by its index in `WasteStatus::all`, for
+		/// clients that would rather get `InvalidStatusIndex` than a codec error for an unknown
+		/// status.
+		#[pallet::weight(
+			WasteStatus::from_index(*status_index)
+				.map_or(T::WeightInfo::update_waste_status(), |new_status| {
+					Pallet::<T>::status_update_weight(&new_status)
+				})
+		)]
+		#[pallet::call_index(35)]
+		pub fn update_waste_status_by_index(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			status_index: u8,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let new_status =
+				WasteStatus::from_index(status_index).ok_or(Error::<T>::InvalidStatusIndex)?;
+			Self::do_update_waste_status(caller, report_id, new_status, None)
+		}
+
+		/// Moves a report to `Utilized` like `update_waste_status`, recording how much usable
+		/// material the plant recovered from it. Where `Utilized` needs several approvals, each
+		/// one goes through here and the amount given with the last is recorded.
+		#[pallet::weight(T::WeightInfo::update_waste_status_to_utilized())]
+		#[pallet::call_index(41)]
+		pub fn finalize_utilization(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			recovered_amount: WasteAmount,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let input = WasteDataMap::<T>::get(report_id)
+				.ok_or(Error::<T>::ReportNotFound)?
+				.waste_amount;
+			ensure!(recovered_amount <= input, Error::<T>::RecoveryExceedsInput);
+
+			let post_info =
+				Self::do_update_waste_status(caller, report_id, WasteStatus::Utilized, None)?;
+			if Self::status_of(report_id) != Some(WasteStatus::Utilized) {
+				// Approvals are still missing.
+				return Ok(post_info)
+			}
+
+			// A report forced back out of `Utilized` and finalized again replaces its first
+			// record.
+			let previous = RecoveredAmount::<T>::get(report_id).unwrap_or_default();
+			RecoveredAmount::<T>::insert(report_id, recovered_amount);
+			TotalRecovered::<T>::mutate(|total| {
+				Self::decrement_total(total, previous);
+				*total = total.saturating_add(recovered_amount)
+			});
+			Self::deposit_event(|seq| Event::Utilized {
+				report_id,
+				input,
+				recovered: recovered_amount,
+				seq,
+			});
+
+			Ok(post_info)
+		}
+
+		/// Deletes a report and every index entry pointing to it. Removing the id from the
+		/// reporter's list is linear in its length, so the weight is charged for a full list
+		/// and refunded down to the actual one.
+		#[pallet::weight(T::WeightInfo::delete_waste_data(T::MaxReportsPerAccount::get()))]
+		#[pallet::call_index(2)]
+		pub fn delete_waste_data(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+		) -> DispatchResultWithPostInfo {
+			let remover = ensure_signed(origin)?;
+
+			let waste_data = WasteDataMap::<T>::get(report_id).ok_or(Error::<T>::ReportNotFound)?;
+			ensure!(Self::is_reporter(&waste_data, &remover), Error::<T>::NotReportOwner);
+			let reports =
+				ReportsByReporter::<T>::decode_len(&waste_data.reporter).unwrap_or_default() as u32;
+
+			Self::remove_report_everywhere(report_id)?;
+
+			Self::deposit_event(|seq| Event::WasteDataDeleted { report_id, remover, seq });
+
+			Ok(Some(T::WeightInfo::delete_waste_data(reports)).into())
+		}
+
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(3)]
+		pub fn add_operator(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			Operators::<T>::insert(&operator, ());
+			Self::deposit_event(|seq| Event::OperatorAdded { operator, seq });
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(4)]
+		pub fn remove_operator(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			Operators::<T>::remove(&operator);
+			Self::deposit_event(|seq| Event::OperatorRemoved { operator, seq });
+
+			Ok(().into())
+		}
+
+		/// Lets `account` move reports through `update_waste_status` without waiting out
+		/// `MinTransitionDelay`. This grants nothing else.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(51)]
+		pub fn add_cooldown_exempt(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			CooldownExempt::<T>::insert(&account, ());
+			Self::deposit_event(|seq| Event::CooldownExemptAdded { account, seq });
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(52)]
+		pub fn remove_cooldown_exempt(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			CooldownExempt::<T>::remove(&account);
+			Self::deposit_event(|seq| Event::CooldownExemptRemoved { account, seq });
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(5)]
+		pub fn register_waste_type(
+			origin: OriginFor<T>,
+			waste_type: WasteType,
+			name: BoundedVec<u8, T::MaxTypeNameLen>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			WasteTypeRegistry::<T>::insert(waste_type, name);
+			Self::deposit_event(|seq| Event::WasteTypeRegistered { waste_type, seq });
+
+			Ok(().into())
+		}
+
+		/// Registers several waste types at once, e.g. to set up a new region. Unlike
+		/// `register_waste_type`, which renames a type that is already registered, the whole batch
+		/// fails with `DuplicateWasteType` if any type in it is registered already or appears
+		/// twice.
+		#[pallet::weight(
+			Weight::from_parts(10_000, 0)
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul(types.len() as u64))
+		)]
+		#[pallet::call_index(60)]
+		pub fn register_waste_types_batch(
+			origin: OriginFor<T>,
+			types: BoundedVec<(WasteType, BoundedVec<u8, T::MaxTypeNameLen>), T::MaxBatchSize>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let count = types.len() as u32;
+			for (waste_type, name) in types {
+				ensure!(
+					!WasteTypeRegistry::<T>::contains_key(waste_type),
+					Error::<T>::DuplicateWasteType
+				);
+				WasteTypeRegistry::<T>::insert(waste_type, name);
+			}
+			Self::deposit_event(|seq| Event::WasteTypesBatchRegistered { count, seq });
+
+			Ok(().into())
+		}
+
+		/// Sets the smallest amount of `waste_type` a report component may carry, or with
+		/// `None` resets it to `DefaultMinAmount`.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(28)]
+		pub fn set_min_amount(
+			origin: OriginFor<T>,
+			waste_type: WasteType,
+			min_amount: Option<WasteAmount>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			match min_amount {
+				Some(amount) => MinAmountByType::<T>::insert(waste_type, amount),
+				None => MinAmountByType::<T>::remove(waste_type),
+			}
+			Self::deposit_event(|seq| Event::MinAmountSet { waste_type, min_amount, seq });
+
+			Ok(().into())
+		}
+
+		/// Files several single-component reports at once. Ids are assigned sequentially and the
+		/// whole batch is rolled back if any report is rejected.
+		#[pallet::weight(T::WeightInfo::create_waste_data_batch(reports.len() as u32))]
+		#[pallet::call_index(6)]
+		pub fn create_waste_data_batch(
+			origin: OriginFor<T>,
+			reports: BoundedVec<(WasteType, WasteAmount, u64, GeoPoint, Severity), T::MaxBatchSize>,
+		) -> DispatchResultWithPostInfo {
+			let reporter = ensure_signed(origin)?;
+			ensure!(!reports.is_empty(), Error::<T>::EmptyBatch);
+
+			let mut first_id = None;
+			for (waste_type, waste_amount, volume, location, severity) in reports.iter().cloned() {
+				let report_id = Self::insert_new_report(
+					&reporter,
+					Components::<T>::truncate_from(sp_std::vec![(waste_type, waste_amount)]),
+					volume,
+					Some(location),
+					severity,
+					None,
+					None,
+					None,
+					None,
+				)?
+				.report_id;
+				first_id.get_or_insert(report_id);
+			}
+
+			Self::deposit_event(|seq| Event::WasteDataBatchCreated {
+				first_id: first_id.unwrap_or_default(),
+				count: reports.len() as u32,
+				reporter: Self::reporter_key(&reporter),
+				seq,
+			});
+
+			Ok(().into())
+		}
+
+		/// Reserves the next report id, e.g. to print its label in the field before the report
+		/// is filled in with `finalize_draft`. The id is given up if it is not filled in within
+		/// `DraftExpiry` blocks. Only accounts that may file reports may reserve ids, and each
+		/// reservation counts against `MaxReportsPerBlock` like a report does.
+		#[pallet::weight(
+			Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(7, 4))
+		)]
+		#[pallet::call_index(43)]
+		pub fn reserve_report_id(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let reporter = ensure_signed(origin)?;
+			ensure!(!Paused::<T>::get(), Error::<T>::CreationPaused);
+			Self::ensure_may_report(&reporter)?;
+
+			let report_id = Self::next_free_report_id()?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let deadline = now.saturating_add(T::DraftExpiry::get().max(One::one()));
+			DraftDeadlines::<T>::try_mutate(deadline, |due| due.try_push(report_id))
+				.map_err(|_| Error::<T>::TooManyDrafts)?;
+			Drafts::<T>::insert(report_id, (reporter, deadline));
+			NextReportId::<T>::put(report_id.saturating_add(1));
+
+			Self::deposit_event(|seq| Event::ReportIdReserved { report_id, seq });
+
+			Ok(().into())
+		}
+
+		/// Files the report for an id reserved with `reserve_report_id`. Only the account that
+		/// reserved the id may do this, and the report is checked as if it were filed with
+		/// `create_waste_data`.
+		#[pallet::weight(T::WeightInfo::create_waste_data())]
+		#[pallet::call_index(44)]
+		pub fn finalize_draft(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			waste_type: WasteType,
+			waste_amount: WasteAmount,
+			location: GeoPoint,
+		) -> DispatchResultWithPostInfo {
+			let reporter = ensure_signed(origin)?;
+			let (owner, deadline) = Drafts::<T>::get(report_id).ok_or(Error::<T>::NotADraft)?;
+			ensure!(owner == reporter, Error::<T>::NotReportOwner);
+
+			let waste_data = Self::insert_new_report(
+				&reporter,
+				Components::<T>::truncate_from(sp_std::vec![(waste_type, waste_amount)]),
+				0,
+				Some(location),
+				Severity::Low,
+				None,
+				None,
+				None,
+				Some(report_id),
+			)?;
+			Drafts::<T>::remove(report_id);
+			DraftDeadlines::<T>::mutate(deadline, |due| due.retain(|due_id| *due_id != report_id));
+			Self::deposit_created_event(&waste_data);
+
+			Ok(().into())
+		}
+
+		/// Replaces the estimated amount of a report with the weighed amount.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(7)]
+		pub fn update_waste_amount(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			new_amount: WasteAmount,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+			ensure!(Operators::<T>::contains_key(&operator), Error::<T>::NotAuthorizedOperator);
+			Self::note_operator_action(&operator);
+
+			let old_amount = Self::set_amount(report_id, new_amount)?;
+			Self::deposit_event(|seq| Event::WasteAmountUpdated {
+				report_id,
+				old_amount,
+				new_amount,
+				seq,
+			});
+
+			Ok(().into())
+		}
+
+		/// Corrects the waste type of a single-component report, moving its amount over to the
+		/// new type's total. The zone total doesn't depend on the type, so it stays as it is.
+		/// Only the reporter or an operator may correct a report.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(56)]
+		pub fn update_waste_type(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			new_type: WasteType,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(WasteTypeRegistry::<T>::contains_key(new_type), Error::<T>::UnknownWasteType);
+			Self::ensure_not_sealed(report_id)?;
+
+			let old_type = WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				ensure!(
+					Self::is_reporter(waste_data, &who) || Operators::<T>::contains_key(&who),
+					Error::<T>::NotReportOwner
+				);
+				let [(component_type, component_amount)] = waste_data.components.as_mut_slice()
+				else {
+					return Err(Error::<T>::MultipleComponents)
+				};
+				ensure!(
+					*component_amount >= MinAmountByType::<T>::get(new_type),
+					Error::<T>::AmountBelowMinimum
+				);
+				let old_type = *component_type;
+				if waste_data.status != WasteStatus::Cancelled {
+					TotalAmountByType::<T>::mutate(old_type, |total| {
+						Self::decrement_total(total, *component_amount)
+					});
+					TotalAmountByType::<T>::mutate(new_type, |total| {
+						*total = total.saturating_add(*component_amount)
+					});
+				}
+				*component_type = new_type;
+				waste_data.waste_type = new_type;
+				waste_data.updated_at = frame_system::Pallet::<T>::block_number();
+				Self::write_mirror(waste_data);
+
+				Ok::<WasteType, Error<T>>(old_type)
+			})?;
+
+			Self::deposit_event(|seq| Event::WasteTypeUpdated {
+				report_id,
+				old_type,
+				new_type,
+				seq,
+			});
+
+			Ok(().into())
+		}
+
+		/// Voids a report while keeping it on chain. Only the reporter or an operator may cancel,
+		/// and a cancelled report can't change status again unless it is reopened with
+		/// `reopen_report`.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(8)]
+		pub fn cancel_report(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			reason: BoundedVec<u8, T::MaxReasonLen>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				ensure!(
+					Self::is_reporter(waste_data, &who) || Operators::<T>::contains_key(&who),
+					Error::<T>::NotReportOwner
+				);
+				Self::transition(waste_data, WasteStatus::Cancelled).map(|_| ())
+			})?;
+
+			let reason_len = reason.len() as u32;
+			CancellationReasons::<T>::insert(report_id, reason);
+
+			Self::deposit_event(|seq| Event::ReportCancelled { report_id, reason_len, seq });
+
+			Ok(().into())
+		}
+
+		/// Marks a transported load as refused by the recycling plant. Unlike `Utilized`, this
+		/// pays no completion reward. Only operators, or accounts allowed to set `Rejected`,
+		/// may reject.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(25)]
+		pub fn reject_report(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			reason: BoundedVec<u8, T::MaxReasonLen>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_can_set_status(&who, &WasteStatus::Rejected)?;
+
+			WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				Self::transition(waste_data, WasteStatus::Rejected).map(|_| ())
+			})?;
+
+			let reason_len = reason.len() as u32;
+			RejectionReasons::<T>::insert(report_id, reason);
+
+			Self::deposit_event(|seq| Event::ReportRejected { report_id, reason_len, seq });
+
+			Ok(().into())
+		}
+
+		/// Splits part of a reported pile off into one child report per entry of `amounts`,
+		/// each pointing back at `report_id` through `parent`. The volume is shared out in
+		/// proportion to the amounts. The parent keeps what is left, possibly nothing. Only the
+		/// reporter or an operator may split a report. The children are filed for the same reporter
+		/// and location, and the parent's deposit covers them.
+		#[pallet::weight(
+			T::WeightInfo::create_waste_data().saturating_mul(amounts.len() as u64 + 1)
+		)]
+		#[pallet::call_index(29)]
+		pub fn split_report(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			amounts: BoundedVec<WasteAmount, T::MaxSplit>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!amounts.is_empty(), Error::<T>::EmptyBatch);
+
+			let mut parent = WasteDataMap::<T>::get(report_id).ok_or(Error::<T>::ReportNotFound)?;
+			ensure!(
+				Self::is_reporter(&parent, &who) || Operators::<T>::contains_key(&who),
+				Error::<T>::NotReportOwner
+			);
+			ensure!(!Disputed::<T>::contains_key(report_id), Error::<T>::ReportDisputed);
+			ensure!(
+				parent.status == WasteStatus::Reported &&
+					!ChildReports::<T>::contains_key(report_id),
+				Error::<T>::CannotSplit
+			);
+			let &[(waste_type, _)] = parent.components.as_slice() else {
+				return Err(Error::<T>::CannotSplit.into())
+			};
+
+			let min_amount = MinAmountByType::<T>::get(waste_type);
+			let mut allocated: WasteAmount = 0;
+			for amount in amounts.iter() {
+				ensure!(*amount >= min_amount, Error::<T>::AmountBelowMinimum);
+				allocated = allocated.checked_add(*amount).ok_or(Error::<T>::SplitExceedsAmount)?;
+			}
+			ensure!(allocated <= parent.waste_amount, Error::<T>::SplitExceedsAmount);
+
+			let mut children = Vec::new();
+			for amount in amounts {
+				children.push(Self::split_off(&mut parent, amount)?.report_id);
+			}
+			Self::write_mirror(&parent);
+			WasteDataMap::<T>::insert(report_id, parent);
+
+			let children = BoundedVec::<ReportId, T::MaxSplit>::truncate_from(children);
+			ChildReports::<T>::insert(report_id, children.clone());
+			Self::deposit_event(|seq| Event::ReportSplit { parent: report_id, children, seq });
+
+			Ok(().into())
+		}
+
+		/// Records that a truck collected `collected_amount` of a reported pile, leaving the rest
+		/// for later: that much moves into a new child report in `Collected`, which takes over
+		/// the collector, while the remainder stays in the original in `Reported`. The caller
+		/// needs the same rights and the report the same state as for collecting it whole.
+		#[pallet::weight(
+			T::WeightInfo::create_waste_data().saturating_add(T::WeightInfo::update_waste_status())
+		)]
+		#[pallet::call_index(40)]
+		pub fn collect_partial(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			collected_amount: WasteAmount,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_can_set_status(&who, &WasteStatus::Collected)?;
+			ensure!(
+				Self::required_approvals(&WasteStatus::Collected) <= 1,
+				Error::<T>::ApprovalRequired
+			);
+
+			let mut parent = WasteDataMap::<T>::get(report_id).ok_or(Error::<T>::ReportNotFound)?;
+			Self::stamp_collector(&mut parent, &WasteStatus::Collected, &who);
+			Self::ensure_transition(&parent, &WasteStatus::Collected)?;
+			ensure!(
+				parent.status == WasteStatus::Reported && parent.components.len() == 1,
+				Error::<T>::CannotSplit
+			);
+			ensure!(
+				collected_amount >= MinAmountByType::<T>::get(parent.waste_type),
+				Error::<T>::AmountBelowMinimum
+			);
+			ensure!(collected_amount <= parent.waste_amount, Error::<T>::SplitExceedsAmount);
+
+			let mut child = Self::split_off(&mut parent, collected_amount)?;
+			child.collector = parent.collector.clone();
+			Self::apply_status(&mut child, WasteStatus::Collected)?;
+			let child_id = child.report_id;
+			WasteDataMap::<T>::insert(child_id, child);
+			ChildReports::<T>::try_mutate(report_id, |children| {
+				children.get_or_insert_with(Default::default).try_push(child_id)
+			})
+			.map_err(|_| Error::<T>::CannotSplit)?;
+			Self::write_mirror(&parent);
+			WasteDataMap::<T>::insert(report_id, parent);
+
+			Self::deposit_event(|seq| Event::PartialCollection {
+				parent: report_id,
+				child: child_id,
+				amount: collected_amount,
+				seq,
+			});
+
+			Ok(().into())
+		}
+
+		/// Merges `absorb` into `keep` when both describe the same pile: its components, amount
+		/// and volume are added to `keep` and it is removed, refunding its deposit, with
+		/// `MergedInto` pointing its id at `keep`. Only operators may merge, and only reports
+		/// that are open and not disputed, settling or sealed.
+		///
+		/// Removing `absorb` costs as much as deleting it, on top of which `keep` and its
+		/// aggregates are rewritten.
+		#[pallet::weight(
+			T::WeightInfo::delete_waste_data(T::MaxReportsPerAccount::get()).saturating_add(
+				T::DbWeight::get().reads_writes(
+					10 + T::MaxComponents::get() as u64,
+					6 + T::MaxComponents::get() as u64,
+				)
+			)
+		)]
+		#[pallet::call_index(34)]
+		pub fn merge_reports(
+			origin: OriginFor<T>,
+			keep: ReportId,
+			absorb: ReportId,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+			ensure!(Operators::<T>::contains_key(&operator), Error::<T>::NotAuthorizedOperator);
+			Self::note_operator_action(&operator);
+			ensure!(keep != absorb, Error::<T>::MergeWithSelf);
+
+			let mut kept = WasteDataMap::<T>::get(keep).ok_or(Error::<T>::ReportNotFound)?;
+			let absorbed = WasteDataMap::<T>::get(absorb).ok_or(Error::<T>::ReportNotFound)?;
+			for waste_data in [&kept, &absorbed] {
+				ensure!(
+					!matches!(
+						waste_data.status,
+						WasteStatus::Utilized | WasteStatus::Cancelled | WasteStatus::Rejected
+					) && !Disputed::<T>::contains_key(waste_data.report_id) &&
+						!Settling::<T>::contains_key(waste_data.report_id) &&
+						!Sealed::<T>::contains_key(waste_data.report_id),
+					Error::<T>::CannotMerge
+				);
+			}
+
+			for (waste_type, amount) in absorbed.components.iter() {
+				match kept.components.iter_mut().find(|(kept_type, _)| kept_type == waste_type) {
+					Some((_, total)) => *total = total.saturating_add(*amount),
+					None => kept
+						.components
+						.try_push((*waste_type, *amount))
+						.map_err(|_| Error::<T>::TooManyComponents)?,
+				}
+			}
+			kept.waste_amount = kept
+				.waste_amount
+				.checked_add(absorbed.waste_amount)
+				.filter(|amount| *amount <= T::MaxWasteAmount::get())
+				.ok_or(Error::<T>::AmountTooLarge)?;
+			kept.volume = kept
+				.volume
+				.checked_add(absorbed.volume)
+				.filter(|volume| *volume <= T::MaxVolume::get())
+				.ok_or(Error::<T>::VolumeTooLarge)?;
+			kept.updated_at = frame_system::Pallet::<T>::block_number();
+
+			// Removal takes `absorb` out of every aggregate, so it is added back under `keep`,
+			// which is open and so counted in the type totals.
+			Self::remove_report_everywhere(absorb)?;
+			Self::add_to_status_total(&kept.status, absorbed.waste_amount, absorbed.volume);
+			Self::add_to_type_totals(&absorbed.components);
+			Self::add_to_zone_total(kept.zone_id, absorbed.waste_amount);
+			Self::write_mirror(&kept);
+			WasteDataMap::<T>::insert(keep, kept);
+			MergedInto::<T>::insert(absorb, keep);
+
+			Self::deposit_event(|seq| Event::ReportsMerged { keep, absorb, seq });
+
+			Ok(().into())
+		}
+
+		/// Dispatches a report to a collection crew. Reassigning replaces the previous collector.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(9)]
+		pub fn assign_collector(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			collector: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+			ensure!(Operators::<T>::contains_key(&operator), Error::<T>::NotAuthorizedOperator);
+			Self::note_operator_action(&operator);
+			Self::ensure_not_sealed(report_id)?;
+
+			WasteDataMap::<T>::try_mutate(report_id, |waste_data| -> DispatchResult {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				waste_data.collector = Some(collector.clone());
+				Self::write_mirror(waste_data);
+				Ok(())
+			})?;
+			Self::deposit_event(|seq| Event::CollectorAssigned { report_id, collector, seq });
+
+			Ok(().into())
+		}
+
+		/// Hands responsibility for a report over to another account, together with its
+		/// deposit: the new reporter reserves it and the old one gets it back.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(10)]
+		pub fn transfer_report(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			new_reporter: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_sealed(report_id)?;
+
+			let (from, depositor) = WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				ensure!(Self::is_reporter(waste_data, &who), Error::<T>::NotReportOwner);
+				let depositor = Self::real_reporter(waste_data);
+				let from = sp_std::mem::replace(
+					&mut waste_data.reporter,
+					Self::reporter_key(&new_reporter),
+				);
+				waste_data.updated_at = frame_system::Pallet::<T>::block_number();
+
+				Self::write_mirror(waste_data);
+				Ok::<_, Error<T>>((from, depositor))
+			})?;
+			if let Some(deposit) = ReportDeposits::<T>::get(report_id) {
+				T::Currency::reserve(&new_reporter, deposit)
+					.map_err(|_| Error::<T>::InsufficientBalanceForDeposit)?;
+				T::Currency::unreserve(&depositor, deposit);
+			}
+			let to = Self::reporter_key(&new_reporter);
+			if T::PrivacyMode::get() {
+				ReporterAccounts::<T>::insert(report_id, new_reporter);
+			} else {
+				ReporterAccounts::<T>::remove(report_id);
+			}
+
+			Self::unindex_reporter(&from, report_id);
+			ReportsByReporter::<T>::try_mutate(&to, |ids| ids.try_push(report_id))
+				.map_err(|_| Error::<T>::TooManyReports)?;
+
+			Self::deposit_event(|seq| Event::ReportTransferred { report_id, from, to, seq });
+
+			Ok(().into())
+		}
+
+		/// Moves up to `max` reports from `from_status` to `to_status` in one call. The work is
+		/// bounded by `max`; reports beyond it are left for a later call.
+		#[pallet::weight(T::WeightInfo::bulk_update_status(*max))]
+		#[pallet::call_index(11)]
+		pub fn bulk_update_status(
+			origin: OriginFor<T>,
+			from_status: WasteStatus,
+			to_status: WasteStatus,
+			max: u32,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+			Self::ensure_can_set_status(&operator, &to_status)?;
+			ensure!(
+				!matches!(to_status, WasteStatus::Cancelled | WasteStatus::Rejected) &&
+					Self::is_valid_transition(&from_status, &to_status),
+				Error::<T>::InvalidStatusTransition
+			);
+			ensure!(Self::required_approvals(&to_status) <= 1, Error::<T>::ApprovalRequired);
+			ensure!(Self::is_indexed(&from_status), Error::<T>::StatusNotIndexed);
+
+			let mut report_ids = Vec::new();
+			let truncated = Self::bounded_scan(
+				WasteDataByStatus::<T>::iter_key_prefix(&from_status),
+				max,
+				|report_id| report_ids.push(report_id),
+			);
+
+			for report_id in report_ids.iter() {
+				WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+					let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+					Self::stamp_collector(waste_data, &to_status, &operator);
+					Self::transition(waste_data, to_status.clone()).map(|_| ())
+				})?;
+			}
+
+			Self::deposit_event(|seq| Event::BulkStatusUpdated {
+				count: report_ids.len() as u32,
+				truncated,
+				seq,
+			});
+
+			Ok(().into())
+		}
+
+		/// Moves the reports of `waste_type` in `from_status` to `to_status`, leaving reports of
+		/// other types where they are. The work is bounded by `max` reports examined, whatever
+		/// their type, so a call may move fewer than `max`; the rest are left for a later call.
+		#[pallet::weight(T::WeightInfo::bulk_update_status(*max))]
+		#[pallet::call_index(53)]
+		pub fn bulk_update_status_by_type(
+			origin: OriginFor<T>,
+			waste_type: WasteType,
+			from_status: WasteStatus,
+			to_status: WasteStatus,
+			max: u32,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+			Self::ensure_can_set_status(&operator, &to_status)?;
+			ensure!(
+				!matches!(to_status, WasteStatus::Cancelled | WasteStatus::Rejected) &&
+					Self::is_valid_transition_for(waste_type, &from_status, &to_status),
+				Error::<T>::InvalidStatusTransition
+			);
+			ensure!(Self::required_approvals(&to_status) <= 1, Error::<T>::ApprovalRequired);
+			ensure!(Self::is_indexed(&from_status), Error::<T>::StatusNotIndexed);
+
+			let mut report_ids = Vec::new();
+			let truncated = Self::bounded_scan(
+				WasteDataByStatus::<T>::iter_prefix(&from_status),
+				max,
+				|(report_id, waste_data)| {
+					if waste_data.waste_type == waste_type {
+						report_ids.push(report_id);
+					}
+				},
+			);
+
+			for report_id in report_ids.iter() {
+				WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+					let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+					Self::stamp_collector(waste_data, &to_status, &operator);
+					Self::transition(waste_data, to_status.clone()).map(|_| ())
+				})?;
+			}
+
+			Self::deposit_event(|seq| Event::BulkTypedStatusUpdated {
+				waste_type,
+				count: report_ids.len() as u32,
+				truncated,
+				seq,
+			});
+
+			Ok(().into())
+		}
+
+		/// Anchors the content hash of photo evidence to a report. Only the reporter may attach
+		/// evidence, and only once.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(12)]
+		pub fn attach_evidence(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			hash: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_sealed(report_id)?;
+
+			let waste_data = WasteDataMap::<T>::get(report_id).ok_or(Error::<T>::ReportNotFound)?;
+			ensure!(Self::is_reporter(&waste_data, &who), Error::<T>::NotReportOwner);
+			ensure!(
+				!EvidenceHash::<T>::contains_key(report_id),
+				Error::<T>::EvidenceAlreadyAttached
+			);
+
+			EvidenceHash::<T>::insert(report_id, hash);
+			Self::deposit_event(|seq| Event::EvidenceAttached { report_id, seq });
+
+			Ok(().into())
+		}
+
+		/// Corrects the coordinates of a report, moving it to the matching grid cell.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(15)]
+		pub fn update_location(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			new_location: GeoPoint,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_location_in_bounds(&new_location)?;
+			let zone_id = Self::zone_for(&new_location)?;
+			Self::ensure_not_sealed(report_id)?;
+
+			WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				ensure!(Self::is_reporter(waste_data, &who), Error::<T>::NotReportOwner);
+
+				Self::unindex_cell(waste_data, report_id);
+				Self::sub_from_zone_total(waste_data.zone_id, waste_data.waste_amount);
+				Self::add_to_zone_total(zone_id, waste_data.waste_amount);
+				if waste_data.status == WasteStatus::Reported {
+					Self::unindex_backlog(waste_data);
+					if let Some(zone_id) = zone_id {
+						ZoneBacklog::<T>::insert(zone_id, report_id, ());
+					}
+				}
+				Self::dequeue_from_zone(waste_data.zone_id, &waste_data.status, report_id);
+				Self::queue_in_zone(zone_id, &waste_data.status, report_id);
+				waste_data.zone_id = zone_id;
+				waste_data.location = Some(new_location);
+				waste_data.updated_at = frame_system::Pallet::<T>::block_number();
+				ReportsByCell::<T>::try_mutate(Self::cell_of(&new_location), |ids| {
+					ids.try_push(report_id)
+				})
+				.map_err(|_| Error::<T>::CellFull)?;
+				Self::record_location(report_id, new_location)?;
+
+				Self::write_mirror(waste_data);
+				Ok::<(), DispatchError>(())
+			})?;
+
+			Self::deposit_event(|seq| Event::LocationUpdated { report_id, seq });
+
+			Ok(().into())
+		}
+
+		/// Marks a report as stuck in `Reported`. Anyone may call this, as staleness is checked
+		/// on chain; flagging an already flagged report is a no-op.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(16)]
+		pub fn flag_stale(origin: OriginFor<T>, report_id: ReportId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			if StaleFlags::<T>::contains_key(report_id) {
+				return Ok(().into())
+			}
+			let waste_data = WasteDataMap::<T>::get(report_id).ok_or(Error::<T>::ReportNotFound)?;
+			ensure!(
+				Self::is_stale(&waste_data, frame_system::Pallet::<T>::block_number()),
+				Error::<T>::NotStale
+			);
+
+			StaleFlags::<T>::insert(report_id, ());
+			Self::deposit_event(|seq| Event::ReportFlaggedStale { report_id, seq });
+
+			Ok(().into())
+		}
+
+		/// Appends an inspection note to a report.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(17)]
+		pub fn add_note(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			note: BoundedVec<u8, T::MaxNoteLen>,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+			ensure!(Operators::<T>::contains_key(&operator), Error::<T>::NotAuthorizedOperator);
+			Self::note_operator_action(&operator);
+			ensure!(WasteDataMap::<T>::contains_key(report_id), Error::<T>::ReportNotFound);
+			Self::ensure_not_sealed(report_id)?;
+
+			let note_index = ReportNotes::<T>::try_mutate(report_id, |notes| {
+				notes.try_push(note).map_err(|_| Error::<T>::TooManyNotes)?;
+				Ok::<u32, Error<T>>(notes.len() as u32 - 1)
+			})?;
+
+			Self::deposit_event(|seq| Event::NoteAdded { report_id, note_index, seq });
+
+			Ok(().into())
+		}
+
+		/// Sets the `FLAG_*` bits in `set` and clears those in `clear` on a report, leaving the
+		/// others as they are. A bit in both ends up set.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(61)]
+		pub fn update_flags(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			set: u16,
+			clear: u16,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+			ensure!(Operators::<T>::contains_key(&operator), Error::<T>::NotAuthorizedOperator);
+			Self::note_operator_action(&operator);
+			Self::ensure_not_sealed(report_id)?;
+
+			let flags = WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				waste_data.flags = (waste_data.flags & !clear) | set;
+				waste_data.updated_at = frame_system::Pallet::<T>::block_number();
+				Self::write_mirror(waste_data);
+				Ok::<u16, Error<T>>(waste_data.flags)
+			})?;
+
+			Self::deposit_event(|seq| Event::FlagsUpdated { report_id, flags, seq });
+
+			Ok(().into())
+		}
+
+		/// Replaces the statuses reports in `from` may move to. Utilized, Cancelled and Rejected
+		/// stay terminal: the deposit and count bookkeeping done on entering them is not undone.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(18)]
+		pub fn set_allowed_transitions(
+			origin: OriginFor<T>,
+			from: WasteStatus,
+			targets: TransitionTargets,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(
+				!matches!(
+					from,
+					WasteStatus::Utilized | WasteStatus::Cancelled | WasteStatus::Rejected
+				) || targets.is_empty(),
+				Error::<T>::InvalidStatusTransition
+			);
+
+			AllowedTransitions::<T>::insert(&from, targets);
+			Self::deposit_event(|seq| Event::AllowedTransitionsSet { from, seq });
+
+			Ok(().into())
+		}
+
+		/// Defines or moves the service zone `zone_id` as the rectangle between the corners
+		/// `(min, max)`, or with `None` removes it. Reports already filed keep their zone.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(45)]
+		pub fn set_service_zone(
+			origin: OriginFor<T>,
+			zone_id: ZoneId,
+			zone: Option<(GeoPoint, GeoPoint)>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(zone_id < T::MaxServiceZones::get(), Error::<T>::InvalidZone);
+			if let Some((min, max)) = &zone {
+				ensure!(min.x <= max.x && min.y <= max.y, Error::<T>::InvalidZone);
+			}
+
+			ServiceZones::<T>::set(zone_id, zone);
+			Self::deposit_event(|seq| Event::ServiceZoneSet { zone_id, zone, seq });
+
+			Ok(().into())
+		}
+
+		/// Sets the number of blocks reports filed in `zone_id` from now on have to be
+		/// collected in, or with `None` stops giving them a deadline. A report still in
+		/// `Reported` at its deadline is flagged with `SlaBreached`.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(58)]
+		pub fn set_zone_sla(
+			origin: OriginFor<T>,
+			zone_id: ZoneId,
+			sla_blocks: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(zone_id < T::MaxServiceZones::get(), Error::<T>::InvalidZone);
+
+			ZoneSla::<T>::set(zone_id, sla_blocks);
+			Self::deposit_event(|seq| Event::ZoneSlaSet { zone_id, sla_blocks, seq });
+
+			Ok(().into())
+		}
+
+		/// Assigns `collector` to up to `max` of the reports in `Reported` in `zone_id`, e.g. to
+		/// hand a zone's backlog to one crew at shift start. Reports already assigned to another
+		/// collector are reassigned; reports beyond `max` are left for a later call.
+		#[pallet::weight(
+			Weight::from_parts(10_000, 0)
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2).saturating_mul(*max as u64))
+		)]
+		#[pallet::call_index(46)]
+		pub fn assign_zone(
+			origin: OriginFor<T>,
+			zone_id: ZoneId,
+			collector: T::AccountId,
+			max: u32,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+			ensure!(Operators::<T>::contains_key(&operator), Error::<T>::NotAuthorizedOperator);
+			Self::note_operator_action(&operator);
+
+			let mut count = 0u32;
+			Self::bounded_scan(ZoneBacklog::<T>::iter_key_prefix(zone_id), max, |report_id| {
+				WasteDataMap::<T>::mutate_extant(report_id, |waste_data| {
+					waste_data.collector = Some(collector.clone());
+					Self::write_mirror(waste_data);
+				});
+				count += 1;
+			});
+
+			Self::deposit_event(|seq| Event::ZoneAssigned { zone_id, collector, count, seq });
+
+			Ok(().into())
+		}
+
+		/// Replaces the statuses reports of `waste_type` in `from` may move to, so that a type
+		/// can have its own lifecycle. `None` returns the type to the default workflow for
+		/// `from`. The terminal statuses stay terminal, as for `set_allowed_transitions`.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(42)]
+		pub fn set_type_workflow(
+			origin: OriginFor<T>,
+			waste_type: WasteType,
+			from: WasteStatus,
+			targets: Option<TransitionTargets>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(WasteTypeRegistry::<T>::contains_key(waste_type), Error::<T>::UnknownWasteType);
+			ensure!(
+				!matches!(
+					from,
+					WasteStatus::Utilized | WasteStatus::Cancelled | WasteStatus::Rejected
+				) || targets.as_ref().map_or(true, |targets| targets.is_empty()),
+				Error::<T>::InvalidStatusTransition
+			);
+
+			TypeTransitions::<T>::set(waste_type, &from, targets);
+			Self::deposit_event(|seq| Event::TypeWorkflowSet { waste_type, from, seq });
+
+			Ok(().into())
+		}
+
+		/// Sets the status of a report regardless of the lifecycle, operators and collectors,
+		/// e.g. to reconcile the chain with a physical audit.
+		#[pallet::weight(T::WeightInfo::update_waste_status())]
+		#[pallet::call_index(19)]
+		pub fn force_update_status(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			new_status: WasteStatus,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				Self::apply_status(waste_data, new_status.clone())
+			})?;
+
+			Self::deposit_event(|seq| Event::StatusForceUpdated { report_id, new_status, seq });
+
+			Ok(().into())
+		}
+
+		/// Treats a report as fraudulent: the deposit held for it is slashed to `T::Slash`
+		/// instead of refunded, and the report is set to `Rejected`, bypassing the lifecycle
+		/// like `force_update_status`.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(47)]
+		pub fn slash_report(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let depositor = WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				if waste_data.status != WasteStatus::Rejected {
+					Self::apply_status(waste_data, WasteStatus::Rejected)?;
+				}
+				Ok::<_, DispatchError>(Self::real_reporter(waste_data))
+			})?;
+			let deposit = ReportDeposits::<T>::take(report_id).ok_or(Error::<T>::NoDepositHeld)?;
+			let (imbalance, _) = T::Currency::slash_reserved(&depositor, deposit);
+			let amount = imbalance.peek();
+			T::Slash::on_unbalanced(imbalance);
+
+			Self::deposit_event(|seq| Event::ReportSlashed { report_id, amount, seq });
+
+			Ok(().into())
+		}
+
+		/// Returns a `Cancelled` or `Rejected` report to `Reported`, e.g. when it was cancelled
+		/// by mistake. Its reason is cleared and it is counted in the active indices and totals
+		/// again. The reporter may reopen a report they cancelled; a rejected one, which may
+		/// have been slashed and hold no deposit, only `ForceOrigin` may reopen.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(48)]
+		pub fn reopen_report(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+		) -> DispatchResultWithPostInfo {
+			let reporter = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			ensure!(!ArchivedReports::<T>::contains_key(report_id), Error::<T>::ReportArchived);
+
+			WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				if let Some(reporter) = &reporter {
+					ensure!(Self::is_reporter(waste_data, reporter), Error::<T>::NotReportOwner);
+				}
+				ensure!(
+					matches!(waste_data.status, WasteStatus::Cancelled | WasteStatus::Rejected),
+					Error::<T>::InvalidStatusTransition
+				);
+				ensure!(
+					reporter.is_none() || waste_data.status != WasteStatus::Rejected,
+					DispatchError::BadOrigin
+				);
+				// `apply_status` clears the reason and puts the report back in the indices.
+				Self::apply_status(waste_data, WasteStatus::Reported).map(|_| ())
+			})?;
+
+			Self::deposit_event(|seq| Event::ReportReopened { report_id, seq });
+
+			Ok(().into())
+		}
+
+		/// Seals a utilized report for the record. A sealed report can't be changed or deleted
+		/// by anyone, `ForceOrigin` included, and is passed over by archiving and purges.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(54)]
+		pub fn seal_report(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let waste_data = WasteDataMap::<T>::get(report_id).ok_or(Error::<T>::ReportNotFound)?;
+			ensure!(waste_data.status == WasteStatus::Utilized, Error::<T>::NotUtilized);
+			ensure!(!Disputed::<T>::contains_key(report_id), Error::<T>::ReportDisputed);
+			Self::ensure_not_sealed(report_id)?;
+
+			Sealed::<T>::insert(report_id, ());
+			Self::deposit_event(|seq| Event::ReportSealed { report_id, seq });
+
+			Ok(().into())
+		}
+
+		/// Lifts the seal on a report, e.g. to correct a record sealed in error. This is the
+		/// only way to change a sealed report.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(55)]
+		pub fn unseal_report(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(Sealed::<T>::take(report_id).is_some(), Error::<T>::NotSealed);
+
+			Self::deposit_event(|seq| Event::ReportUnsealed { report_id, seq });
+
+			Ok(().into())
+		}
+
+		/// Deposits `ReporterResolved` with the real account behind a report, which in
+		/// `PrivacyMode` only `ForceOrigin` can learn this way.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(49)]
+		pub fn resolve_reporter(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let waste_data = WasteDataMap::<T>::get(report_id).ok_or(Error::<T>::ReportNotFound)?;
+
+			let reporter = Self::real_reporter(&waste_data);
+			Self::deposit_event(|seq| Event::ReporterResolved { report_id, reporter, seq });
+
+			Ok(().into())
+		}
+
+		/// Marks every report in `Reported` in `zone_id` as `Collected` at once, e.g. after a
+		/// hazardous spill, bypassing the lifecycle like `force_update_status`. The first
+		/// `MaxEmergencyBatch` reports are collected right away; `on_initialize` collects the rest
+		/// in batches of the same size in the blocks that follow.
+		#[pallet::weight(
+			T::WeightInfo::update_waste_status()
+				.saturating_mul(T::MaxEmergencyBatch::get() as u64)
+				.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+		)]
+		#[pallet::call_index(50)]
+		pub fn emergency_collect_zone(
+			origin: OriginFor<T>,
+			zone_id: ZoneId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(zone_id < T::MaxServiceZones::get(), Error::<T>::InvalidZone);
+			ensure!(
+				!EmergencyCollections::<T>::contains_key(zone_id),
+				Error::<T>::EmergencyInProgress
+			);
+
+			Self::deposit_event(|seq| Event::EmergencyCollectionStarted { zone_id, seq });
+			EmergencyCollections::<T>::insert(zone_id, 0);
+			Self::continue_emergency_collection(zone_id);
+
+			Ok(().into())
+		}
+
+		/// Sets or, with `None`, clears the contact on a report. Only the reporter may do this.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(21)]
+		pub fn update_contact(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			contact: Option<Contact<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_sealed(report_id)?;
+
+			WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				ensure!(Self::is_reporter(waste_data, &who), Error::<T>::NotReportOwner);
+
+				waste_data.contact = contact;
+				waste_data.updated_at = frame_system::Pallet::<T>::block_number();
+				Self::write_mirror(waste_data);
 				Ok::<(), Error<T>>(())
 			})?;
 
-			WasteDataByStatus::<T>::insert((WasteStatus::Reported, report_id), waste_data.clone());
-
-			Self::deposit_event(Event::WasteDataCreated { report_id, reporter });
+			Self::deposit_event(|seq| Event::ContactUpdated { report_id, seq });
+
+			Ok(().into())
+		}
+
+		/// Disputes the current status of a report, e.g. one wrongly marked `Utilized`. Only the
+		/// reporter may do this. The status is frozen until `resolve_dispute`.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(22)]
+		pub fn dispute_status(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			reason: BoundedVec<u8, T::MaxReasonLen>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_sealed(report_id)?;
+
+			let waste_data = WasteDataMap::<T>::get(report_id).ok_or(Error::<T>::ReportNotFound)?;
+			ensure!(Self::is_reporter(&waste_data, &who), Error::<T>::NotReportOwner);
+			ensure!(!Disputed::<T>::contains_key(report_id), Error::<T>::ReportDisputed);
+
+			Disputed::<T>::insert(report_id, reason);
+			Self::deposit_event(|seq| Event::StatusDisputed { report_id, seq });
+
+			Ok(().into())
+		}
+
+		/// Closes the dispute on a report, unfreezing its status. Any correction is made with
+		/// `force_update_status`.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(23)]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(Disputed::<T>::take(report_id).is_some(), Error::<T>::NotDisputed);
+
+			Self::deposit_event(|seq| Event::DisputeResolved { report_id, seq });
+
+			Ok(().into())
+		}
+
+		/// Deletes up to `max` of the reports filed by `target`, e.g. when a contractor's
+		/// account is terminated. `Utilized` reports are kept unless `include_utilized` is set.
+		#[pallet::weight(
+			Weight::from_parts(10_000, 0)
+				.saturating_add(T::DbWeight::get().reads_writes(18, 18).saturating_mul(*max as u64))
+		)]
+		#[pallet::call_index(24)]
+		pub fn delete_reports_of(
+			origin: OriginFor<T>,
+			target: T::AccountId,
+			max: u32,
+			include_utilized: bool,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let mut purge = Vec::new();
+			let truncated = Self::bounded_scan(
+				ReportsByReporter::<T>::get(Self::reporter_key(&target)).into_iter().filter(
+					|report_id| {
+						!Sealed::<T>::contains_key(report_id) &&
+							WasteDataMap::<T>::get(report_id).map_or(false, |waste_data| {
+								include_utilized || waste_data.status != WasteStatus::Utilized
+							})
+					},
+				),
+				max,
+				|report_id| purge.push(report_id),
+			);
+			for report_id in &purge {
+				Self::remove_report_everywhere(*report_id)?;
+			}
+
+			Self::deposit_event(|seq| Event::ReportsPurged {
+				account: target,
+				count: purge.len() as u32,
+				truncated,
+				seq,
+			});
+
+			Ok(().into())
+		}
+
+		/// Moves up to `max` of the reports in `from` to `to`, regardless of the lifecycle, so
+		/// that a status can be retired before its variant is removed. Each report goes through
+		/// the same bookkeeping as `force_update_status`; call again until `from` is empty.
+		#[pallet::weight(
+			Weight::from_parts(10_000, 0)
+				.saturating_add(T::DbWeight::get().reads_writes(12, 12).saturating_mul(*max as u64))
+		)]
+		#[pallet::call_index(33)]
+		pub fn migrate_status(
+			origin: OriginFor<T>,
+			from: WasteStatus,
+			to: WasteStatus,
+			max: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(from != to, Error::<T>::StatusUnchanged);
+			ensure!(Self::is_indexed(&from), Error::<T>::StatusNotIndexed);
+
+			// Collected first, since moving a report re-keys the prefix being read.
+			let mut batch = Vec::new();
+			Self::bounded_scan(WasteDataByStatus::<T>::iter_key_prefix(&from), max, |report_id| {
+				batch.push(report_id)
+			});
+			for report_id in &batch {
+				WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+					let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+					Self::apply_status(waste_data, to.clone())
+				})?;
+			}
+
+			Self::deposit_event(|seq| Event::StatusMigrated {
+				from,
+				to,
+				count: batch.len() as u32,
+				seq,
+			});
+
+			Ok(().into())
+		}
+
+		/// Rebuilds the status, type and zone totals from the reports, for when they have drifted,
+		/// e.g. after a faulty migration. Each call visits up to `max` report ids; once the last
+		/// one is visited the totals are replaced all at once, `AggregatesDrifted` is cleared and
+		/// `AggregatesRecomputed` is deposited. Until then the old totals stay in use.
+		///
+		/// Reports changed between the calls of a run are counted as they were when visited, so
+		/// a run should be finished in as few blocks as possible.
+		#[pallet::weight(
+			Weight::from_parts(10_000, 0).saturating_add(
+				T::DbWeight::get()
+					.reads_writes(
+						3 + T::MaxComponents::get() as u64,
+						2 + T::MaxComponents::get() as u64,
+					)
+					.saturating_mul(*max as u64)
+			)
+		)]
+		#[pallet::call_index(57)]
+		pub fn recompute_aggregates(origin: OriginFor<T>, max: u32) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let (next_id, mut reports) = BatchCursors::<T>::get(BatchKind::RecomputeAggregates)
+				.unwrap_or((FirstReportId::get(), 0));
+			let end = NextReportId::<T>::get();
+			let stop = next_id.saturating_add(max as u64).min(end);
+			for waste_data in (next_id..stop).filter_map(WasteDataMap::<T>::get) {
+				reports += 1;
+				RecomputedStatusTotals::<T>::mutate(&waste_data.status, |(amount, volume)| {
+					*amount = amount.saturating_add(waste_data.waste_amount);
+					*volume = volume.saturating_add(waste_data.volume);
+				});
+				if waste_data.status != WasteStatus::Cancelled {
+					for (waste_type, amount) in waste_data.components.iter() {
+						RecomputedTypeTotals::<T>::mutate(waste_type, |total| {
+							*total = total.saturating_add(*amount)
+						});
+					}
+				}
+				if let Some(zone_id) = waste_data.zone_id {
+					RecomputedZoneTotals::<T>::mutate(zone_id, |total| {
+						*total = total.saturating_add(waste_data.waste_amount)
+					});
+				}
+			}
+			if stop < end {
+				BatchCursors::<T>::insert(BatchKind::RecomputeAggregates, (stop, reports));
+				return Ok(().into())
+			}
+
+			let _ = TotalAmountByStatus::<T>::clear(u32::MAX, None);
+			let _ = TotalVolumeByStatus::<T>::clear(u32::MAX, None);
+			for (status, (amount, volume)) in RecomputedStatusTotals::<T>::drain() {
+				TotalAmountByStatus::<T>::insert(&status, amount);
+				TotalVolumeByStatus::<T>::insert(&status, volume);
+			}
+			let _ = TotalAmountByType::<T>::clear(u32::MAX, None);
+			for (waste_type, total) in RecomputedTypeTotals::<T>::drain() {
+				TotalAmountByType::<T>::insert(waste_type, total);
+			}
+			let _ = TotalAmountByZone::<T>::clear(u32::MAX, None);
+			for (zone_id, total) in RecomputedZoneTotals::<T>::drain() {
+				TotalAmountByZone::<T>::insert(zone_id, total);
+			}
+			BatchCursors::<T>::remove(BatchKind::RecomputeAggregates);
+			AggregatesDrifted::<T>::kill();
+
+			Self::deposit_event(|seq| Event::AggregatesRecomputed { reports, seq });
+
+			Ok(().into())
+		}
+
+		/// Removes the `WasteDataByStatus` entries left behind by reports that are no longer in
+		/// `WasteDataMap`, which releases before the removal of reports was centralized could
+		/// leak. Each call visits up to `max` entries of the status index, whatever their ids,
+		/// and carries on after the last one on the next call; once every entry is visited
+		/// `OrphansPruned` is deposited with the number removed over the run.
+		#[pallet::weight(
+			Weight::from_parts(10_000, 0).saturating_add(
+				T::DbWeight::get()
+					.reads_writes(2, 1)
+					.saturating_mul(*max as u64)
+					.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+			)
+		)]
+		#[pallet::call_index(62)]
+		pub fn prune_orphans(origin: OriginFor<T>, max: u32) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let (mut visited, mut count) =
+				BatchCursors::<T>::get(BatchKind::PruneOrphans).unwrap_or((0, 0));
+			let keys = match PruneOrphansFrom::<T>::get() {
+				Some(from) => WasteDataByStatus::<T>::iter_keys_from(from.into_inner()),
+				None => WasteDataByStatus::<T>::iter_keys(),
+			};
+			// Collected first, so that nothing is removed from under the iterator.
+			let batch: Vec<(WasteStatus, ReportId)> = keys.take(max as usize).collect();
+			visited = visited.saturating_add(batch.len() as u64);
+			for (status, report_id) in batch.iter() {
+				if !WasteDataMap::<T>::contains_key(report_id) {
+					WasteDataByStatus::<T>::remove(status, report_id);
+					count += 1;
+				}
+			}
+			// A full batch may have stopped short of the end.
+			if batch.len() == max as usize {
+				if let Some((status, report_id)) = batch.last() {
+					let from = WasteDataByStatus::<T>::hashed_key_for(status, report_id);
+					PruneOrphansFrom::<T>::put(BoundedVec::truncate_from(from));
+				}
+				BatchCursors::<T>::insert(BatchKind::PruneOrphans, (visited, count));
+				return Ok(().into())
+			}
+
+			PruneOrphansFrom::<T>::kill();
+			BatchCursors::<T>::remove(BatchKind::PruneOrphans);
+			Self::deposit_event(|seq| Event::OrphansPruned { count, seq });
+
+			Ok(().into())
+		}
+
+		/// Moves up to `max` of the reports utilized before block `older_than` to
+		/// `ArchivedReports`, removing them from every active map and aggregate as if deleted.
+		/// Call again until no report is left to archive.
+		#[pallet::weight(
+			Weight::from_parts(10_000, 0)
+				.saturating_add(T::DbWeight::get().reads_writes(18, 19).saturating_mul(*max as u64))
+		)]
+		#[pallet::call_index(36)]
+		pub fn archive_completed(
+			origin: OriginFor<T>,
+			older_than: BlockNumberFor<T>,
+			max: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_indexed(&WasteStatus::Utilized), Error::<T>::StatusNotIndexed);
+
+			let mut archive = Vec::new();
+			Self::bounded_scan(
+				WasteDataByStatus::<T>::iter_prefix(WasteStatus::Utilized).filter_map(
+					|(report_id, waste_data)| {
+						let completed_at = Self::completed_at(&waste_data)?;
+						(completed_at < older_than && !Sealed::<T>::contains_key(report_id))
+							.then_some((report_id, completed_at))
+					},
+				),
+				max,
+				|entry| archive.push(entry),
+			);
+			for (report_id, completed_at) in &archive {
+				let waste_data = Self::remove_report_everywhere(*report_id)?;
+				ArchivedReports::<T>::insert(
+					report_id,
+					ArchivedReport {
+						waste_type: waste_data.waste_type,
+						waste_amount: waste_data.waste_amount,
+						volume: waste_data.volume,
+						location: waste_data.location,
+						reporter: waste_data.reporter,
+						created_at: waste_data.created_at,
+						completed_at: *completed_at,
+					},
+				);
+			}
+
+			Self::deposit_event(|seq| Event::ReportsArchived { count: archive.len() as u32, seq });
+
+			Ok(().into())
+		}
+
+		/// Pauses or resumes the filing of new reports.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(20)]
+		pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Paused::<T>::put(paused);
+			Self::deposit_event(|seq| Event::PauseToggled { paused, seq });
+
+			Ok(().into())
+		}
+
+		/// Pays out all completion rewards credited to the caller.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(32)]
+		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let amount = PendingRewards::<T>::take(Self::reporter_key(&who));
+			ensure!(!amount.is_zero(), Error::<T>::NoPendingRewards);
+			// The caller signed this, so their account exists.
+			T::Currency::deposit_into_existing(&who, amount)?;
+			Self::deposit_event(|seq| Event::RewardsClaimed { who, amount, seq });
+
+			Ok(().into())
+		}
+
+		/// Adds `account` to the accounts that may file reports while `RequireAllowlist` is set.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(30)]
+		pub fn allow_reporter(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			AllowedReporters::<T>::insert(&account, ());
+			Self::deposit_event(|seq| Event::ReporterAllowed { account, seq });
+
+			Ok(().into())
+		}
+
+		/// Removes `account` from the reporter allowlist. Its reports already on file stay.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(31)]
+		pub fn disallow_reporter(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			AllowedReporters::<T>::remove(&account);
+			Self::deposit_event(|seq| Event::ReporterDisallowed { account, seq });
+
+			Ok(().into())
+		}
+
+		/// Adds `weighbridge` to the weighbridges whose attestations are accepted.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(37)]
+		pub fn trust_weighbridge(
+			origin: OriginFor<T>,
+			weighbridge: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			TrustedWeighbridges::<T>::insert(&weighbridge, ());
+			Self::deposit_event(|seq| Event::WeighbridgeTrusted { weighbridge, seq });
+
+			Ok(().into())
+		}
+
+		/// Removes `weighbridge` from the trusted set. Attestations it already signed stay.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(38)]
+		pub fn untrust_weighbridge(
+			origin: OriginFor<T>,
+			weighbridge: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			TrustedWeighbridges::<T>::remove(&weighbridge);
+			Self::deposit_event(|seq| Event::WeighbridgeUntrusted { weighbridge, seq });
+
+			Ok(().into())
+		}
+
+		/// Anchors a trusted weighbridge's signature over the true mass of a report, replacing
+		/// any earlier attestation. The signature is not checked on chain. Only operators may
+		/// submit attestations.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(39)]
+		pub fn attest_weight(
+			origin: OriginFor<T>,
+			report_id: ReportId,
+			weighbridge: T::AccountId,
+			signature: WeighbridgeSignature,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+			ensure!(Operators::<T>::contains_key(&operator), Error::<T>::NotAuthorizedOperator);
+			Self::note_operator_action(&operator);
+			ensure!(WasteDataMap::<T>::contains_key(report_id), Error::<T>::ReportNotFound);
+			Self::ensure_not_sealed(report_id)?;
+			ensure!(
+				TrustedWeighbridges::<T>::contains_key(&weighbridge),
+				Error::<T>::UntrustedWeighbridge
+			);
+
+			WeightAttestations::<T>::insert(report_id, (weighbridge.clone(), signature));
+			Self::deposit_event(|seq| Event::WeightAttested { report_id, weighbridge, seq });
+
+			Ok(().into())
+		}
+
+		/// Lets `proxy` update statuses on behalf of `principal`. Either root or the principal
+		/// may add a proxy.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(26)]
+		pub fn add_proxy(
+			origin: OriginFor<T>,
+			principal: T::AccountId,
+			proxy: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_root_or(origin, &principal)?;
+
+			Proxies::<T>::insert((&principal, &proxy), ());
+			Self::deposit_event(|seq| Event::ProxyAdded { principal, proxy, seq });
+
+			Ok(().into())
+		}
+
+		/// Revokes a proxy added with `add_proxy`. Either root or the principal may remove it.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(27)]
+		pub fn remove_proxy(
+			origin: OriginFor<T>,
+			principal: T::AccountId,
+			proxy: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_root_or(origin, &principal)?;
+
+			Proxies::<T>::remove((&principal, &proxy));
+			Self::deposit_event(|seq| Event::ProxyRemoved { principal, proxy, seq });
+
+			Ok(().into())
+		}
+
+		/// Allows `account` to move reports into `status`.
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(13)]
+		pub fn grant_status_permission(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			status: WasteStatus,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			StatusPermissions::<T>::insert((&account, status.clone()), ());
+			Self::deposit_event(|seq| Event::StatusPermissionGranted { account, status, seq });
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000)]
+		#[pallet::call_index(14)]
+		pub fn revoke_status_permission(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			status: WasteStatus,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			StatusPermissions::<T>::remove((&account, status.clone()));
+			Self::deposit_event(|seq| Event::StatusPermissionRevoked { account, status, seq });
+
+			Ok(().into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// All reports currently in `status`, as served by the `WasteManagementApi` runtime API.
+		pub fn reports_with_status(status: WasteStatus) -> Vec<WasteData<T>> {
+			WasteDataByStatus::<T>::iter_prefix_values(status).collect()
+		}
+
+		/// Every report on file with its id, decoded lazily in storage order.
+		///
+		/// This is unbounded: callers must cap it with `take` or similar before using it
+		/// on-chain.
+		pub fn iter_reports() -> impl Iterator<Item = (ReportId, WasteData<T>)> {
+			WasteDataMap::<T>::iter()
+		}
+
+		/// Every report currently in `status` with its id, decoded lazily in storage order.
+		///
+		/// This is unbounded: callers must cap it with `take` or similar before using it
+		/// on-chain.
+		pub fn iter_reports_by_status(
+			status: WasteStatus,
+		) -> impl Iterator<Item = (ReportId, WasteData<T>)> {
+			WasteDataByStatus::<T>::iter_prefix(status)
+		}
+
+		/// The number of reports currently in `status` with a component of `waste_type`.
+		///
+		/// This is O(n) in the number of reports in `status`, so it is meant for off-chain
+		/// queries only. `scan_limit` caps how many reports in `status` are examined; the count
+		/// is then a lower bound.
+		pub fn count_by_type_and_status(
+			waste_type: WasteType,
+			status: WasteStatus,
+			scan_limit: Option<u32>,
+		) -> u64 {
+			WasteDataByStatus::<T>::iter_prefix_values(status)
+				.take(scan_limit.map_or(usize::MAX, |limit| limit as usize))
+				.filter(|waste_data| {
+					waste_data.components.iter().any(|(component, _)| *component == waste_type)
+				})
+				.count() as u64
+		}
+
+		/// The number of reports with a component of `waste_type` that are not cancelled in each
+		/// of the amount buckets split at `HISTOGRAM_BOUNDS`, each bucket including its lower
+		/// bound. A mixed report is binned by the amount of that component, not its total.
+		///
+		/// This is O(n) in the number of reports on file, whatever their type, so it is meant
+		/// for off-chain queries only. `scan_limit` caps how many reports are examined; the
+		/// counts are then lower bounds.
+		pub fn amount_histogram(waste_type: WasteType, scan_limit: Option<u32>) -> [u64; 4] {
+			let unit = Self::amount_unit();
+			let mut histogram = [0u64; 4];
+			WasteDataMap::<T>::iter_values()
+				.take(scan_limit.map_or(usize::MAX, |limit| limit as usize))
+				.filter(|waste_data| waste_data.status != WasteStatus::Cancelled)
+				.filter_map(|waste_data| {
+					waste_data
+						.components
+						.iter()
+						.find(|(component, _)| *component == waste_type)
+						.map(|(_, amount)| *amount)
+				})
+				.for_each(|amount| {
+					let bucket = HISTOGRAM_BOUNDS
+						.iter()
+						.take_while(|bound| amount >= bound.saturating_mul(unit))
+						.count();
+					histogram[bucket] = histogram[bucket].saturating_add(1);
+				});
+			histogram
+		}
+
+		/// Up to `limit` reports with ids greater than `start_after`, in ascending id order,
+		/// with the cursor to pass for the next page, `None` once the last id is reached.
+		/// `start_after = None` starts from the lowest id.
+		///
+		/// `WasteDataMap` keys are hashed, so storage iteration order is not id order; instead
+		/// this walks the sequential id space and skips ids whose reports were deleted. At most
+		/// `MAX_ID_PROBES` ids are read per call, so a page can come back short, or empty, with a
+		/// cursor past a run of deleted ids.
+		pub fn list_reports(
+			start_after: Option<ReportId>,
+			limit: u32,
+		) -> (Vec<WasteData<T>>, Option<ReportId>) {
+			Self::walk_ids(start_after, limit, WasteDataMap::<T>::get)
+		}
+
+		/// Walks the ids after `start_after` in ascending order, keeping what `probe` finds
+		/// under each until it found `limit` or read `MAX_ID_PROBES` ids. Returns them with the
+		/// last id read, unless that was the last id handed out.
+		fn walk_ids<R>(
+			start_after: Option<ReportId>,
+			limit: u32,
+			probe: impl Fn(ReportId) -> Option<R>,
+		) -> (Vec<R>, Option<ReportId>) {
+			let first = start_after.map_or(1, |id| id.saturating_add(1));
+			let last = NextReportId::<T>::get().saturating_sub(1);
+
+			let mut found = Vec::new();
+			let mut reached = first.saturating_sub(1);
+			for report_id in (first..=last).take(MAX_ID_PROBES as usize) {
+				if found.len() >= limit as usize {
+					break
+				}
+				reached = report_id;
+				found.extend(probe(report_id));
+			}
+			(found, (reached < last).then_some(reached))
+		}
+
+		/// A page of `list_reports` for a client syncing every report, with its cursor and a
+		/// `blake2_256` hash of the page. At most `MaxQueryBatch` reports are returned, whatever
+		/// `limit` asks for, and only a `None` cursor means the sync is complete.
+		///
+		/// The hash starts from `prev_hash`, the hash returned with the previous page, or all
+		/// zeroes for the first, and folds in each report in turn, each step hashing the one
+		/// before together with the encoded report. The hash of the last page so commits to
+		/// every report synced, and a client holding it can check that it got an unbroken run of
+		/// pages no report of which was altered.
+		pub fn report_chunk(
+			start_after: Option<ReportId>,
+			limit: u32,
+			prev_hash: Option<[u8; 32]>,
+		) -> (Vec<WasteData<T>>, Option<ReportId>, [u8; 32]) {
+			let limit = limit.min(T::MaxQueryBatch::get());
+			let (chunk, next) = Self::list_reports(start_after, limit);
+			let hash = chunk.iter().fold(prev_hash.unwrap_or_default(), |hash, waste_data| {
+				(hash, waste_data).using_encoded(sp_core::hashing::blake2_256)
+			});
+			(chunk, next, hash)
+		}
+
+		/// The report with id `report_id`, if it exists.
+		pub fn get_report(report_id: ReportId) -> Option<WasteData<T>> {
+			WasteDataMap::<T>::get(report_id)
+		}
+
+		/// The archived remains of the completed report with id `report_id`, if it was archived.
+		pub fn get_archived(report_id: ReportId) -> Option<ArchivedReport<T>> {
+			ArchivedReports::<T>::get(report_id)
+		}
+
+		/// The block a `Utilized` report was utilized at, from its status history.
+		fn completed_at(waste_data: &WasteData<T>) -> Option<BlockNumberFor<T>> {
+			if waste_data.status != WasteStatus::Utilized {
+				return None
+			}
+			StatusHistory::<T>::get(waste_data.report_id)
+				.and_then(|history| history.last().cloned())
+				.filter(|(status, _)| *status == WasteStatus::Utilized)
+				.map(|(_, at)| at)
+				.or(Some(waste_data.updated_at))
+		}
+
+		/// The number of reports on file that have not been deleted or cancelled, in one read.
+		/// Every path that files, deletes, cancels or reopens a report keeps it exact.
+		pub fn active_count() -> u64 {
+			ActiveReportCount::<T>::get()
+		}
+
+		/// The number of report ids ever handed out, including those of deleted reports and of
+		/// drafts, in one read. It never goes down.
+		pub fn total_ever_created() -> u64 {
+			NextReportId::<T>::get().saturating_sub(1)
+		}
+
+		/// Whether a report with id `report_id` is on file, cancelled or not.
+		pub fn exists(report_id: ReportId) -> bool {
+			WasteDataMap::<T>::contains_key(report_id)
+		}
+
+		/// The current status of a report, if it exists.
+		pub fn status_of(report_id: ReportId) -> Option<WasteStatus> {
+			WasteDataMap::<T>::get(report_id).map(|waste_data| waste_data.status)
+		}
+
+		/// The current status of each of `ids`, `None` for those not on file, in the order
+		/// given, so that a client syncing many reports needs one call rather than one per
+		/// report. Each id is still one read.
+		pub fn statuses_of(
+			ids: BoundedVec<ReportId, T::MaxQueryBatch>,
+		) -> Vec<(ReportId, Option<WasteStatus>)> {
+			ids.into_iter()
+				.map(|report_id| (report_id, Self::status_of(report_id)))
+				.collect()
+		}
+
+		/// The ids of up to `limit` reports on file after `start_after`, in ascending order,
+		/// with the cursor to pass for the next page, `None` once the last id is reached. Like
+		/// `list_reports`, at most `MAX_ID_PROBES` ids are read per call.
+		pub fn all_report_ids(
+			start_after: Option<ReportId>,
+			limit: u32,
+		) -> (Vec<ReportId>, Option<ReportId>) {
+			Self::walk_ids(start_after, limit, |report_id| {
+				WasteDataMap::<T>::contains_key(report_id).then_some(report_id)
+			})
+		}
+
+		/// The oldest report in `zone_id` that is in `status`, if any, so that crews can work
+		/// through a zone first come, first served. One read, however many reports the zone
+		/// holds.
+		pub fn next_unprocessed(zone_id: ZoneId, status: WasteStatus) -> Option<ReportId> {
+			ZoneQueue::<T>::iter_key_prefix((zone_id, status))
+				.next()
+				.map(ReportId::from_be_bytes)
+		}
+
+		/// Whether a report is on file with every bit of `flag` set.
+		pub fn has_flag(report_id: ReportId, flag: u16) -> bool {
+			WasteDataMap::<T>::get(report_id)
+				.map_or(false, |waste_data| waste_data.flags & flag == flag)
+		}
+
+		/// Whether a report is flagged `FLAG_HAZARDOUS`.
+		pub fn is_hazardous(report_id: ReportId) -> bool {
+			Self::has_flag(report_id, FLAG_HAZARDOUS)
+		}
+
+		/// Whether a report is flagged `FLAG_RECYCLABLE`.
+		pub fn is_recyclable(report_id: ReportId) -> bool {
+			Self::has_flag(report_id, FLAG_RECYCLABLE)
+		}
+
+		/// Whether a report is flagged `FLAG_REQUIRES_PERMIT`.
+		pub fn requires_permit(report_id: ReportId) -> bool {
+			Self::has_flag(report_id, FLAG_REQUIRES_PERMIT)
+		}
+
+		/// Whether a report is flagged `FLAG_WET`.
+		pub fn is_wet(report_id: ReportId) -> bool {
+			Self::has_flag(report_id, FLAG_WET)
+		}
+
+		/// The collector a report has been dispatched to, if any.
+		pub fn collector_of(report_id: ReportId) -> Option<T::AccountId> {
+			WasteDataMap::<T>::get(report_id).and_then(|waste_data| waste_data.collector)
+		}
+
+		/// The grid cell containing a location.
+		pub fn cell_of(location: &GeoPoint) -> (u32, u32) {
+			let cell_size = T::CellSize::get().max(1);
+			(location.x / cell_size, location.y / cell_size)
+		}
+
+		/// Ids of all reports located in `cell`.
+		pub fn reports_in_cell(cell: (u32, u32)) -> Vec<ReportId> {
+			ReportsByCell::<T>::get(cell).into_inner()
+		}
+
+		/// Up to `limit` reports that are not cancelled and lie within the rectangle spanned by
+		/// `min` and `max`, edges included. Only the grid cells overlapping the box are read, so
+		/// the cost is one read per overlapping cell plus one per report indexed in them. An
+		/// empty box, with `min` beyond `max` on either axis, yields nothing.
+		pub fn reports_in_box(min: GeoPoint, max: GeoPoint, limit: u32) -> Vec<WasteData<T>> {
+			let mut found = Vec::new();
+			if min.x > max.x || min.y > max.y {
+				return found
+			}
+			let (min_cx, min_cy) = Self::cell_of(&min);
+			let (max_cx, max_cy) = Self::cell_of(&max);
+			for cx in min_cx..=max_cx {
+				for cy in min_cy..=max_cy {
+					for report_id in ReportsByCell::<T>::get((cx, cy)) {
+						if found.len() >= limit as usize {
+							return found
+						}
+						let Some(waste_data) = WasteDataMap::<T>::get(report_id) else { continue };
+						let Some(GeoPoint { x, y }) = waste_data.location else { continue };
+						if waste_data.status != WasteStatus::Cancelled &&
+							(min.x..=max.x).contains(&x) && (min.y..=max.y).contains(&y)
+						{
+							found.push(waste_data);
+						}
+					}
+				}
+			}
+			found
+		}
+
+		/// The `k` reports closest to `point`, in `status_filter` if given, with their squared
+		/// distances, closest first and ties broken by id. The search reads the cell of `point`
+		/// and then ring after ring of cells around it, stopping once no unread cell can hold a
+		/// closer report or after `MaxSearchRings` rings, so reports further out may be missed.
+		pub fn nearest_reports(
+			point: GeoPoint,
+			k: u32,
+			status_filter: Option<WasteStatus>,
+		) -> Vec<(ReportId, u64)> {
+			let mut found = Vec::new();
+			if k == 0 {
+				return found
+			}
+			let cell_size = T::CellSize::get().max(1) as u64;
+			let (cx, cy) = Self::cell_of(&point);
+			let (last_cx, last_cy) =
+				Self::cell_of(&GeoPoint { x: T::MaxLocationX::get(), y: T::MaxLocationY::get() });
+			let (cx, cy, last_cx, last_cy) = (cx as i64, cy as i64, last_cx as i64, last_cy as i64);
+
+			for ring in 0..=T::MaxSearchRings::get() as i64 {
+				// The cells on the border of the square `ring` cells out from the centre.
+				let mut cells = Vec::new();
+				for dx in -ring..=ring {
+					cells.push((cx + dx, cy - ring));
+					if ring > 0 {
+						cells.push((cx + dx, cy + ring));
+					}
+				}
+				for dy in (1 - ring)..ring {
+					cells.push((cx - ring, cy + dy));
+					cells.push((cx + ring, cy + dy));
+				}
+
+				for (x, y) in cells {
+					if !(0..=last_cx).contains(&x) || !(0..=last_cy).contains(&y) {
+						continue
+					}
+					for report_id in ReportsByCell::<T>::get((x as u32, y as u32)) {
+						let Some(waste_data) = WasteDataMap::<T>::get(report_id) else { continue };
+						let Some(location) = waste_data.location else { continue };
+						if status_filter
+							.as_ref()
+							.map_or(true, |status| waste_data.status == *status)
+						{
+							found.push((report_id, point.distance_sq(&location)));
+						}
+					}
+				}
+
+				found.sort_by_key(|&(report_id, distance)| (distance, report_id));
+				found.truncate(k as usize);
+				// Every cell in the next ring is at least `ring` whole cells away from `point`.
+				let reach = (ring as u64).saturating_mul(cell_size);
+				if found.len() == k as usize &&
+					found
+						.last()
+						.map_or(false, |&(_, distance)| distance < reach.saturating_mul(reach))
+				{
+					break
+				}
+			}
+			found
+		}
+
+		/// Whether reports in `status` are mirrored in `WasteDataByStatus`.
+		pub fn is_indexed(status: &WasteStatus) -> bool {
+			T::IndexedStatuses::get().contains(status)
+		}
+
+		/// Writes `waste_data` to `WasteDataByStatus` under its status, if that status is
+		/// mirrored.
+		fn write_mirror(waste_data: &WasteData<T>) {
+			if Self::is_indexed(&waste_data.status) {
+				WasteDataByStatus::<T>::insert(
+					waste_data.status.clone(),
+					waste_data.report_id,
+					waste_data,
+				);
+			}
+		}
+
+		/// Removes the `WasteDataByStatus` entry of `report_id` under `status`, if that status
+		/// is mirrored.
+		fn remove_mirror(status: &WasteStatus, report_id: ReportId) {
+			if Self::is_indexed(status) {
+				WasteDataByStatus::<T>::remove(status, report_id);
+			}
+		}
+
+		/// Drops `report_id` from the grid cell its location falls in, if it has a location.
+		fn unindex_cell(waste_data: &WasteData<T>, report_id: ReportId) {
+			let Some(location) = &waste_data.location else { return };
+			let cell = Self::cell_of(location);
+			ReportsByCell::<T>::mutate_exists(cell, |ids| {
+				if let Some(list) = ids {
+					list.retain(|id| *id != report_id);
+					if list.is_empty() {
+						*ids = None;
+					}
+				}
+			});
+		}
+
+		/// Ids of up to `limit` reports filed from block `from` to block `to`, both included,
+		/// in filing order.
+		pub fn reports_created_between(
+			from: BlockNumberFor<T>,
+			to: BlockNumberFor<T>,
+			limit: u32,
+		) -> Vec<ReportId> {
+			let mut ids = Vec::new();
+			let mut block = from;
+			while block <= to && (ids.len() as u32) < limit {
+				let left = limit as usize - ids.len();
+				ids.extend(
+					ReportsFiledIn::<T>::iter_key_prefix(block)
+						.take(left)
+						.map(ReportId::from_be_bytes),
+				);
+				if block == to {
+					break
+				}
+				block += One::one();
+			}
+			ids
+		}
+
+		/// Ids of the open reports with `severity`.
+		pub fn reports_by_severity(severity: Severity) -> Vec<ReportId> {
+			ReportsBySeverity::<T>::iter_key_prefix(severity).collect()
+		}
+
+		/// The inspection notes on a report, oldest first.
+		pub fn notes_of(report_id: ReportId) -> Vec<Vec<u8>> {
+			ReportNotes::<T>::get(report_id)
+				.into_iter()
+				.map(|note| note.into_inner())
+				.collect()
+		}
+
+		/// The sum of the squared lengths of the legs of a report's route, saturating at
+		/// `u64::MAX`. Under `RouteHistoryMode::DropOldest` only the legs still kept count.
+		pub fn route_distance_sq(report_id: ReportId) -> u64 {
+			let route = LocationHistory::<T>::get(report_id).unwrap_or_default();
+			route
+				.windows(2)
+				.fold(0u64, |total, leg| total.saturating_add(leg[0].distance_sq(&leg[1])))
+		}
+
+		/// The events deposited about a report, as their SCALE variant index and the block they
+		/// were deposited in, oldest first.
+		pub fn event_log_of(report_id: ReportId) -> Vec<(u8, BlockNumberFor<T>)> {
+			ReportEventLog::<T>::get(report_id)
+				.map(|log| log.into_inner())
+				.unwrap_or_default()
+		}
+
+		/// The latest events about any report, as the report id, the SCALE variant index of the
+		/// event and the block it was deposited in, oldest first.
+		pub fn recent_activity() -> Vec<(ReportId, u8, BlockNumberFor<T>)> {
+			RecentActivity::<T>::get().into_inner()
+		}
+
+		/// The statuses a report has been in, with the block it entered each, oldest first.
+		pub fn history_of(report_id: ReportId) -> Vec<(WasteStatus, BlockNumberFor<T>)> {
+			StatusHistory::<T>::get(report_id)
+				.map(|history| history.into_inner())
+				.unwrap_or_default()
+		}
+
+		/// The statuses a report has been in as a `StatusBitmap` entry, 0 for an unknown report.
+		pub fn statuses_seen(report_id: ReportId) -> u8 {
+			StatusBitmap::<T>::get(report_id)
+		}
+
+		/// The statuses a report has been in, in index order rather than the order it entered
+		/// them.
+		pub fn statuses_seen_list(report_id: ReportId) -> Vec<WasteStatus> {
+			WasteStatus::from_bitmap(Self::statuses_seen(report_id))
+		}
+
+		/// How far through its lifecycle a report is, see `WasteStatus::progress_permill`, if it
+		/// is on file.
+		pub fn report_progress(report_id: ReportId) -> Option<u32> {
+			Self::status_of(report_id).map(|status| status.progress_permill())
+		}
+
+		/// A `blake2_256` commitment to what a report says, so that a reporter can prove with a
+		/// storage proof of the report that it is on file without handing over every field.
+		///
+		/// The hash is over a fixed canonical form rather than `WasteData` itself, so that
+		/// fields added to reports later don't change it: `REPORT_COMMITMENT_VERSION` followed
+		/// by the id, reporter, components, total amount, volume, location, severity and filing
+		/// block. Status and other bookkeeping are left out, so only correcting the report
+		/// changes its commitment.
+		pub fn report_commitment(report_id: ReportId) -> Option<[u8; 32]> {
+			let waste_data = WasteDataMap::<T>::get(report_id)?;
+			let canonical = (
+				REPORT_COMMITMENT_VERSION,
+				waste_data.report_id,
+				&waste_data.reporter,
+				&waste_data.components,
+				waste_data.waste_amount,
+				waste_data.volume,
+				&waste_data.location,
+				&waste_data.severity,
+				waste_data.created_at,
+			);
+			Some(canonical.using_encoded(sp_core::hashing::blake2_256))
+		}
+
+		/// Whether a report was still waiting for collection at its deadline.
+		pub fn sla_breached(report_id: ReportId) -> bool {
+			SlaBreaches::<T>::contains_key(report_id)
+		}
+
+		/// The evidence hash attached to a report, if any.
+		pub fn evidence_of(report_id: ReportId) -> Option<[u8; 32]> {
+			EvidenceHash::<T>::get(report_id)
+		}
+
+		/// The reports filed by `who` that are currently in `status`. Ids in the reporter index
+		/// whose report no longer exists are skipped.
+		pub fn reports_of_by_status(who: &T::AccountId, status: WasteStatus) -> Vec<WasteData<T>> {
+			ReportsByReporter::<T>::get(Self::reporter_key(who))
+				.into_iter()
+				.filter_map(WasteDataMap::<T>::get)
+				.filter(|waste_data| waste_data.status == status)
+				.collect()
+		}
+
+		/// Ids of all reports filed by `who`.
+		pub fn reports_of(who: &T::AccountId) -> Vec<ReportId> {
+			ReportsByReporter::<T>::get(Self::reporter_key(who)).into_inner()
+		}
+
+		/// The weight `update_waste_status` declares for moving a report to `new_status`, from
+		/// the benchmark of that target. Moving to `Utilized` also releases the deposit and
+		/// credits the completion reward, so it costs far more than the earlier steps. Other
+		/// targets are charged `update_waste_status`.
+		pub fn status_update_weight(new_status: &WasteStatus) -> Weight {
+			match new_status {
+				WasteStatus::Collected => T::WeightInfo::update_waste_status_to_collected(),
+				WasteStatus::Transported => T::WeightInfo::update_waste_status_to_transported(),
+				WasteStatus::Utilized => T::WeightInfo::update_waste_status_to_utilized(),
+				_ => T::WeightInfo::update_waste_status(),
+			}
+		}
+
+		/// The body of `update_waste_status`, shared with `update_waste_status_by_index`.
+		fn do_update_waste_status(
+			caller: T::AccountId,
+			report_id: ReportId,
+			new_status: WasteStatus,
+			on_behalf_of: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let operator = match on_behalf_of {
+				Some(principal) => {
+					ensure!(
+						Proxies::<T>::contains_key((&principal, &caller)),
+						Error::<T>::NotAProxy
+					);
+					principal
+				},
+				None => caller,
+			};
+			ensure!(!Mutating::<T>::get(), Error::<T>::Reentrancy);
+			Self::ensure_can_set_status(&operator, &new_status)?;
+			Self::ensure_enough_operators()?;
+
+			// Cancellation and rejection go through `cancel_report` and `reject_report` so that a
+			// reason is always recorded.
+			ensure!(
+				!matches!(new_status, WasteStatus::Cancelled | WasteStatus::Rejected),
+				Error::<T>::InvalidStatusTransition
+			);
+
+			let applied = WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				let stamped = Self::stamp_collector(waste_data, &new_status, &operator);
+				Self::ensure_lifecycle_allows(waste_data, &new_status)?;
+				if !CooldownExempt::<T>::contains_key(&operator) {
+					Self::ensure_cooled_down(waste_data, &new_status)?;
+				}
+				if !Self::approve(report_id, &new_status, &operator)? {
+					// Only the operator whose change takes effect becomes the collector.
+					if stamped {
+						waste_data.collector = None;
+					}
+					return Ok(None)
+				}
+				let old_status = Self::apply_status(waste_data, new_status.clone())?;
+				Ok(Some((old_status, waste_data.clone())))
+			})
+			.map_err(|e| {
+				if e == Error::<T>::StatusUnchanged.into() {
+					// Nothing was written, so only the reads are charged.
+					e.with_weight(T::WeightInfo::update_waste_status_noop())
+				} else {
+					e.into()
+				}
+			})?;
+			Self::note_operator_action(&operator);
+			let Some((old_status, report)) = applied else {
+				// Recorded the approval; the status waits for the remaining ones, so only the
+				// approvals and the report were written.
+				return Ok(Some(
+					T::WeightInfo::update_waste_status_noop()
+						.saturating_add(T::DbWeight::get().reads_writes(1, 2)),
+				)
+				.into())
+			};
+
+			if T::VerboseEvents::get() {
+				Self::deposit_report_event(
+					|seq| Event::WasteStatusUpdated {
+						report_id,
+						operator,
+						old_status,
+						new_status,
+						collector: report.collector.clone(),
+						seq,
+					},
+					report_id,
+					&report.reporter,
+				);
+			} else {
+				Self::deposit_report_event(
+					|seq| Event::WasteStatusUpdatedMinimal { report_id, seq },
+					report_id,
+					&report.reporter,
+				);
+			}
+			T::OnStatusReached::on_reached(report_id, &report.status, &report);
 
 			Ok(().into())
 		}
 
-		#[pallet::weight(10_000)]
-		#[pallet::call_index(1)]
-		pub fn update_waste_status(
-			origin: OriginFor<T>,
+		/// Moves `amount` of a single-component report in `Reported` into a new child report in
+		/// `Reported`, filed for the same reporter and location with a share of the volume in
+		/// proportion, and returns the child. The totals move with it; the caller has checked
+		/// `amount` and writes `parent` back to storage.
+		fn split_off(
+			parent: &mut WasteData<T>,
+			amount: WasteAmount,
+		) -> Result<WasteData<T>, DispatchError> {
+			let waste_type = parent.waste_type;
+			// At most `parent.volume`, since `amount` is at most `parent.waste_amount`.
+			let volume = (parent.volume as u128 * amount as u128)
+				.checked_div(parent.waste_amount as u128)
+				.unwrap_or_default() as u64;
+			let mut child = WasteData::<T>::new_validated(
+				Self::next_free_report_id()?,
+				parent.reporter.clone(),
+				Components::<T>::truncate_from(sp_std::vec![(waste_type, amount)]),
+				volume,
+				parent.location,
+				parent.severity.clone(),
+				parent.contact.clone(),
+				parent.origin_tag.clone(),
+			)?;
+			child.parent = Some(parent.report_id);
+			let now = child.created_at;
+			Self::non_reentrant(|| {
+				T::ReportHook::on_created(&child)?;
+				Self::store_report(&child)
+			})?;
+			if let Some(account) = ReporterAccounts::<T>::get(parent.report_id) {
+				ReporterAccounts::<T>::insert(child.report_id, account);
+			}
+
+			parent.waste_amount = parent.waste_amount.saturating_sub(amount);
+			parent.components =
+				Components::<T>::truncate_from(sp_std::vec![(waste_type, parent.waste_amount)]);
+			parent.volume = parent.volume.saturating_sub(volume);
+			parent.updated_at = now;
+			Self::sub_from_status_total(&WasteStatus::Reported, amount, volume);
+			TotalAmountByType::<T>::mutate(waste_type, |total| {
+				Self::decrement_total(total, amount)
+			});
+			Self::sub_from_zone_total(parent.zone_id, amount);
+			Ok(child)
+		}
+
+		/// Moves a report to `new_status` if the lifecycle allows it, keeping the status index and
+		/// totals in step, and returns the previous status. The caller writes `waste_data` back to
+		/// `WasteDataMap`.
+		fn transition(
+			waste_data: &mut WasteData<T>,
+			new_status: WasteStatus,
+		) -> Result<WasteStatus, DispatchError> {
+			Self::ensure_transition(waste_data, &new_status)?;
+			Self::apply_status(waste_data, new_status)
+		}
+
+		/// Checks that the lifecycle lets a report move to `new_status` right now.
+		fn ensure_transition(
+			waste_data: &WasteData<T>,
+			new_status: &WasteStatus,
+		) -> DispatchResult {
+			Self::ensure_lifecycle_allows(waste_data, new_status)?;
+			Self::ensure_cooled_down(waste_data, new_status)
+		}
+
+		/// Checks that the lifecycle lets a report move to `new_status`, however recently it
+		/// last changed.
+		fn ensure_lifecycle_allows(
+			waste_data: &WasteData<T>,
+			new_status: &WasteStatus,
+		) -> DispatchResult {
+			ensure!(waste_data.status != *new_status, Error::<T>::StatusUnchanged);
+			Self::ensure_not_sealed(waste_data.report_id)?;
+			ensure!(!Disputed::<T>::contains_key(waste_data.report_id), Error::<T>::ReportDisputed);
+			ensure!(!Settling::<T>::contains_key(waste_data.report_id), Error::<T>::ReportSettling);
+			ensure!(
+				Self::is_valid_transition_for(
+					waste_data.waste_type,
+					&waste_data.status,
+					new_status
+				),
+				Error::<T>::InvalidStatusTransition
+			);
+			if *new_status == WasteStatus::Collected {
+				ensure!(waste_data.collector.is_some(), Error::<T>::NoCollectorAssigned);
+			}
+			Ok(())
+		}
+
+		/// Records `operator` as the collector of a report that is about to be collected and has
+		/// none, if `AutoStampCollector` is set. Returns whether it did.
+		fn stamp_collector(
+			waste_data: &mut WasteData<T>,
+			new_status: &WasteStatus,
+			operator: &T::AccountId,
+		) -> bool {
+			let stamp = *new_status == WasteStatus::Collected &&
+				waste_data.collector.is_none() &&
+				T::AutoStampCollector::get();
+			if stamp {
+				waste_data.collector = Some(operator.clone());
+			}
+			stamp
+		}
+
+		/// Checks that `MinTransitionDelay` has passed since a report last changed, unless it is
+		/// being cancelled.
+		fn ensure_cooled_down(
+			waste_data: &WasteData<T>,
+			new_status: &WasteStatus,
+		) -> DispatchResult {
+			if *new_status != WasteStatus::Cancelled {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(
+					now.saturating_sub(waste_data.updated_at) >= T::MinTransitionDelay::get(),
+					Error::<T>::TransitionTooSoon
+				);
+			}
+			Ok(())
+		}
+
+		/// The number of distinct operators that must ask for `status` before it is set.
+		fn required_approvals(status: &WasteStatus) -> u32 {
+			let (guarded, required) = T::RequiredApprovals::get();
+			if guarded == *status {
+				required
+			} else {
+				1
+			}
+		}
+
+		/// Records the approval of `approver` for moving `report_id` into `status`, and returns
+		/// whether the required number has now been reached. Unguarded statuses need no record.
+		fn approve(
 			report_id: ReportId,
+			status: &WasteStatus,
+			approver: &T::AccountId,
+		) -> Result<bool, DispatchError> {
+			let required = Self::required_approvals(status);
+			if required <= 1 {
+				return Ok(true)
+			}
+
+			let key = (report_id, status.clone());
+			let mut approvers = PendingApprovals::<T>::get(&key);
+			ensure!(!approvers.contains(approver), Error::<T>::DuplicateApproval);
+			let approvals = approvers.len() as u32 + 1;
+			Self::deposit_event(|seq| Event::ApprovalRecorded {
+				report_id,
+				status: status.clone(),
+				approver: approver.clone(),
+				approvals,
+				seq,
+			});
+			if approvals >= required {
+				return Ok(true)
+			}
+
+			approvers.try_push(approver.clone()).map_err(|_| Error::<T>::TooManyApprovals)?;
+			PendingApprovals::<T>::insert(&key, approvers);
+			Ok(false)
+		}
+
+		/// Sets the status of a report without consulting the lifecycle, and does all the
+		/// bookkeeping that goes with it: indices, totals, the active count, the history, and
+		/// refund and reward on completion. A deposit refunded on completion isn't reserved
+		/// again if the report later leaves `Utilized`.
+		fn apply_status(
+			waste_data: &mut WasteData<T>,
 			new_status: WasteStatus,
+		) -> Result<WasteStatus, DispatchError> {
+			let old_status = waste_data.status.clone();
+			ensure!(old_status != new_status, Error::<T>::StatusUnchanged);
+			Self::ensure_not_sealed(waste_data.report_id)?;
+			if old_status.is_closed() && !new_status.is_closed() {
+				// The report takes up an open slot again.
+				Self::ensure_below_active_ceiling()?;
+			}
+			waste_data.status = new_status.clone();
+			waste_data.updated_at = frame_system::Pallet::<T>::block_number();
+
+			let report_id = waste_data.report_id;
+			Self::record_history(report_id, new_status.clone(), waste_data.updated_at)?;
+			PendingApprovals::<T>::remove((report_id, new_status.clone()));
+			StaleFlags::<T>::remove(report_id);
+			Self::remove_mirror(&old_status, report_id);
+			Self::write_mirror(waste_data);
+			Self::dequeue_from_zone(waste_data.zone_id, &old_status, report_id);
+			Self::queue_in_zone(waste_data.zone_id, &new_status, report_id);
+			if let (WasteStatus::Collected, Some(deadline)) = (&new_status, waste_data.deadline) {
+				SlaDeadlines::<T>::remove(deadline, report_id);
+			}
+			if old_status == WasteStatus::Reported {
+				Self::unindex_backlog(waste_data);
+			} else if let (WasteStatus::Reported, Some(zone_id)) = (&new_status, waste_data.zone_id)
+			{
+				ZoneBacklog::<T>::insert(zone_id, report_id, ());
+			}
+			Self::sub_from_status_total(&old_status, waste_data.waste_amount, waste_data.volume);
+			Self::add_to_status_total(&new_status, waste_data.waste_amount, waste_data.volume);
+
+			if new_status == WasteStatus::Utilized {
+				ReporterScore::<T>::mutate(&waste_data.reporter, |score| {
+					*score = score.saturating_add(1)
+				});
+				Self::release_deposit(waste_data);
+				Self::credit_completion_reward(waste_data);
+				Self::deposit_event(|seq| Event::ReportCompleted {
+					report_id,
+					reporter: waste_data.reporter.clone(),
+					total_amount: waste_data.waste_amount,
+					seq,
+				});
+			}
+			if new_status.is_closed() && !old_status.is_closed() {
+				ReportsBySeverity::<T>::remove(&waste_data.severity, report_id);
+				OpenReportCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			} else if old_status.is_closed() && !new_status.is_closed() {
+				ReportsBySeverity::<T>::insert(&waste_data.severity, report_id, ());
+				OpenReportCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			}
+			if new_status == WasteStatus::Cancelled {
+				ReporterScore::<T>::mutate(&waste_data.reporter, |score| {
+					*score = score.saturating_sub(1)
+				});
+				ActiveReportCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+				Self::sub_from_type_totals(&waste_data.components);
+			} else if old_status == WasteStatus::Cancelled {
+				ActiveReportCount::<T>::mutate(|count| *count = count.saturating_add(1));
+				Self::add_to_type_totals(&waste_data.components);
+				CancellationReasons::<T>::remove(report_id);
+			}
+			if old_status == WasteStatus::Rejected {
+				RejectionReasons::<T>::remove(report_id);
+			}
+
+			Ok(old_status)
+		}
+
+		/// Removes a report together with every index, aggregate and side table entry that
+		/// refers to it, and refunds its deposit. All removal goes through here so that no index
+		/// is forgotten. Cancellation doesn't: a cancelled report stays on chain for audit until
+		/// it is deleted.
+		fn remove_report_everywhere(report_id: ReportId) -> Result<WasteData<T>, DispatchError> {
+			Self::ensure_not_sealed(report_id)?;
+			let waste_data =
+				WasteDataMap::<T>::take(report_id).ok_or(Error::<T>::ReportNotFound)?;
+
+			// Cancelled reports were already taken out of the active count and type totals.
+			if waste_data.status != WasteStatus::Cancelled {
+				ActiveReportCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+				Self::sub_from_type_totals(&waste_data.components);
+			}
+			if !waste_data.status.is_closed() {
+				OpenReportCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			}
+			// The status index is keyed by the report's current status.
+			Self::remove_mirror(&waste_data.status, report_id);
+			Self::sub_from_status_total(
+				&waste_data.status,
+				waste_data.waste_amount,
+				waste_data.volume,
+			);
+			Self::sub_from_zone_total(waste_data.zone_id, waste_data.waste_amount);
+			Self::unindex_backlog(&waste_data);
+			Self::dequeue_from_zone(waste_data.zone_id, &waste_data.status, report_id);
+			if let Some(external_ref) = waste_data.external_ref {
+				ReportByExternalRef::<T>::remove(external_ref);
+			}
+			if let Some(location) = waste_data.location {
+				let location_key = (location.x, location.y, waste_data.waste_type);
+				if RecentReports::<T>::get(location_key).map_or(false, |(id, _)| id == report_id) {
+					RecentReports::<T>::remove(location_key);
+				}
+			}
+			// The depositor is looked up in `ReporterAccounts`, so refund before clearing it.
+			Self::release_deposit(&waste_data);
+			Self::unindex_reporter(&waste_data.reporter, report_id);
+			ReporterAccounts::<T>::remove(report_id);
+			Self::unindex_cell(&waste_data, report_id);
+			ReportsBySeverity::<T>::remove(&waste_data.severity, report_id);
+			EvidenceHash::<T>::remove(report_id);
+			WeightAttestations::<T>::remove(report_id);
+			Disputed::<T>::remove(report_id);
+			Settling::<T>::remove(report_id);
+			StaleFlags::<T>::remove(report_id);
+			CancellationReasons::<T>::remove(report_id);
+			RejectionReasons::<T>::remove(report_id);
+			ChildReports::<T>::remove(report_id);
+			ReportNotes::<T>::remove(report_id);
+			StatusHistory::<T>::remove(report_id);
+			StatusBitmap::<T>::remove(report_id);
+			SlaBreaches::<T>::remove(report_id);
+			LocationHistory::<T>::remove(report_id);
+			ReportEventLog::<T>::remove(report_id);
+			PendingApprovals::<T>::remove((report_id, T::RequiredApprovals::get().0));
+			ReportsByCreation::<T>::remove(waste_data.created_at, report_id);
+			ReportsFiledIn::<T>::remove(waste_data.created_at, report_id.to_be_bytes());
+			if let Some(deadline) = waste_data.deadline {
+				SlaDeadlines::<T>::remove(deadline, report_id);
+			}
+
+			Ok(waste_data)
+		}
+
+		/// Returns the deposit held for `waste_data` to its real reporter, who holds it.
+		fn release_deposit(waste_data: &WasteData<T>) {
+			if let Some(amount) = ReportDeposits::<T>::take(waste_data.report_id) {
+				T::Currency::unreserve(&Self::real_reporter(waste_data), amount);
+			}
+		}
+
+		/// Credits `CompletionReward` to the reporter of a report that was just utilized, less
+		/// the `TreasuryCut` credited to `TreasuryAccount`. Only storage is touched, so the status
+		/// change can't fail on the balance side; both shares are paid out by `claim_rewards`.
+		fn credit_completion_reward(waste_data: &WasteData<T>) {
+			let reward = T::CompletionReward::get();
+			if reward.is_zero() {
+				return
+			}
+			// The cut is rounded down and the reporter gets the rest, so no part of the reward
+			// is lost to rounding.
+			let treasury_amount = T::TreasuryCut::get().mul_floor(reward);
+			let amount = reward.saturating_sub(treasury_amount);
+			// The treasury is credited under the key `claim_rewards` looks it up by.
+			let treasury = Self::reporter_key(&T::TreasuryAccount::get());
+			for (account, share) in
+				[(waste_data.reporter.clone(), amount), (treasury, treasury_amount)]
+			{
+				if !share.is_zero() {
+					PendingRewards::<T>::mutate(account, |pending| {
+						*pending = pending.saturating_add(share)
+					});
+				}
+			}
+			Self::deposit_event(|seq| Event::RewardCredited {
+				report_id: waste_data.report_id,
+				beneficiary: waste_data.reporter.clone(),
+				amount,
+				seq,
+			});
+			if !treasury_amount.is_zero() {
+				Self::deposit_event(|seq| Event::RewardSplit {
+					report_id: waste_data.report_id,
+					beneficiary_amount: amount,
+					treasury_amount,
+					seq,
+				});
+			}
+		}
+
+		/// The account `who` files reports under: in `PrivacyMode` the `blake2_256` hash of
+		/// `who`, decoded as an account id, and otherwise `who` itself. The real account of a
+		/// report filed in privacy mode is kept in `ReporterAccounts`.
+		pub fn reporter_key(who: &T::AccountId) -> T::AccountId {
+			if !T::PrivacyMode::get() {
+				return who.clone()
+			}
+			let hash = sp_core::hashing::blake2_256(&who.encode());
+			T::AccountId::decode(&mut TrailingZeroInput::new(&hash))
+				.expect("infinite input; no invalid account ids; qed")
+		}
+
+		/// The account that filed, or was handed, `waste_data`, whichever mode it was filed in.
+		fn real_reporter(waste_data: &WasteData<T>) -> T::AccountId {
+			ReporterAccounts::<T>::get(waste_data.report_id)
+				.unwrap_or_else(|| waste_data.reporter.clone())
+		}
+
+		/// Whether `who` filed, or was handed, `waste_data`, whichever mode it was filed in.
+		fn is_reporter(waste_data: &WasteData<T>, who: &T::AccountId) -> bool {
+			Self::real_reporter(waste_data) == *who
+		}
+
+		/// Drops `report_id` from the reporter index of `reporter`.
+		fn unindex_reporter(reporter: &T::AccountId, report_id: ReportId) {
+			ReportsByReporter::<T>::mutate_exists(reporter, |ids| {
+				if let Some(list) = ids {
+					list.retain(|id| *id != report_id);
+					if list.is_empty() {
+						*ids = None;
+					}
+				}
+			});
+		}
+
+		/// Discards the progress of every batch job left part way through, since the upgrade may
+		/// have changed how it would carry on, and deposits `PendingBatchCleared` for each. The
+		/// rebuilding jobs only write their results once they finish, so what they were rebuilding
+		/// is left as it was before they started; pruning just goes over the entries again.
+		fn clear_pending_batches() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			for (kind, _) in BatchCursors::<T>::drain() {
+				let removed = match kind {
+					BatchKind::RecomputeAggregates => {
+						let statuses = RecomputedStatusTotals::<T>::clear(u32::MAX, None).unique;
+						let types = RecomputedTypeTotals::<T>::clear(u32::MAX, None).unique;
+						let zones = RecomputedZoneTotals::<T>::clear(u32::MAX, None).unique;
+						statuses.saturating_add(types).saturating_add(zones)
+					},
+					BatchKind::PruneOrphans => {
+						PruneOrphansFrom::<T>::kill();
+						1
+					},
+				};
+				weight =
+					weight.saturating_add(T::DbWeight::get().reads_writes(1, 1 + removed as u64));
+				Self::deposit_event(|seq| Event::PendingBatchCleared { kind, seq });
+			}
+			weight
+		}
+
+		/// Counts `WasteDataMap` and deposits `StorageReconciled` with the counters. This is
+		/// O(n) in the number of reports, so it only runs on runtime upgrade. `OpenReportCount`
+		/// is recounted on the way, since releases before it was kept left it at zero.
+		fn reconcile_storage() -> Weight {
+			let mut map_entries = 0u64;
+			let mut open = 0u64;
+			for waste_data in WasteDataMap::<T>::iter_values() {
+				map_entries += 1;
+				if !waste_data.status.is_closed() {
+					open += 1;
+				}
+			}
+			OpenReportCount::<T>::put(open);
+			Self::deposit_event(|seq| Event::StorageReconciled {
+				map_entries,
+				active_count: ActiveReportCount::<T>::get(),
+				next_id: NextReportId::<T>::get(),
+				seq,
+			});
+			T::DbWeight::get().reads_writes(map_entries.saturating_add(2), 1)
+		}
+
+		/// Checks that the indices and aggregates agree with `WasteDataMap`.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			let mut mirrored = 0u64;
+			let mut active = 0u64;
+			let mut open = 0u64;
+			for (report_id, waste_data) in WasteDataMap::<T>::iter() {
+				if Self::is_indexed(&waste_data.status) {
+					ensure!(
+						WasteDataByStatus::<T>::get(waste_data.status.clone(), report_id).as_ref() ==
+							Some(&waste_data),
+						"report is missing from the status index under its current status"
+					);
+					mirrored += 1;
+				}
+				if waste_data.status != WasteStatus::Cancelled {
+					active += 1;
+				}
+				if !waste_data.status.is_closed() {
+					open += 1;
+				}
+			}
+
+			ensure!(
+				WasteDataByStatus::<T>::iter_keys().count() as u64 == mirrored,
+				"status index holds entries for reports that don't exist or aren't mirrored"
+			);
+			ensure!(
+				ActiveReportCount::<T>::get() == active,
+				"ActiveReportCount doesn't match the reports that are not cancelled"
+			);
+			ensure!(
+				OpenReportCount::<T>::get() == open,
+				"OpenReportCount doesn't match the reports that are not closed"
+			);
+			for status in WasteStatus::all() {
+				let expected = WasteDataMap::<T>::iter_values()
+					.filter(|waste_data| waste_data.status == *status)
+					.fold(0, |total: WasteAmount, waste_data| {
+						total.saturating_add(waste_data.waste_amount)
+					});
+				ensure!(
+					TotalAmountByStatus::<T>::get(status) == expected,
+					"TotalAmountByStatus doesn't match the reports in that status"
+				);
+				let expected = WasteDataMap::<T>::iter_values()
+					.filter(|waste_data| waste_data.status == *status)
+					.fold(0, |total: u64, waste_data| total.saturating_add(waste_data.volume));
+				ensure!(
+					TotalVolumeByStatus::<T>::get(status) == expected,
+					"TotalVolumeByStatus doesn't match the reports in that status"
+				);
+			}
+
+			let mut by_type =
+				sp_std::collections::btree_map::BTreeMap::<WasteType, WasteAmount>::new();
+			for waste_data in WasteDataMap::<T>::iter_values() {
+				if waste_data.status == WasteStatus::Cancelled {
+					continue
+				}
+				for (waste_type, amount) in waste_data.components.iter() {
+					let total = by_type.entry(*waste_type).or_default();
+					*total = total.saturating_add(*amount);
+				}
+			}
+			for (waste_type, total) in TotalAmountByType::<T>::iter() {
+				ensure!(
+					by_type.remove(&waste_type).unwrap_or_default() == total,
+					"TotalAmountByType doesn't match the reports of that type"
+				);
+			}
+			ensure!(
+				by_type.values().all(|total| total.is_zero()),
+				"TotalAmountByType is missing a waste type"
+			);
+			let mut by_zone =
+				sp_std::collections::btree_map::BTreeMap::<ZoneId, WasteAmount>::new();
+			for waste_data in WasteDataMap::<T>::iter_values() {
+				if let Some(zone_id) = waste_data.zone_id {
+					let total = by_zone.entry(zone_id).or_default();
+					*total = total.saturating_add(waste_data.waste_amount);
+				}
+			}
+			for (zone_id, total) in TotalAmountByZone::<T>::iter() {
+				ensure!(
+					by_zone.remove(&zone_id).unwrap_or_default() == total,
+					"TotalAmountByZone doesn't match the reports in that zone"
+				);
+			}
+			ensure!(
+				by_zone.values().all(|total| total.is_zero()),
+				"TotalAmountByZone is missing a zone"
+			);
+			for (zone_id, report_id, ()) in ZoneBacklog::<T>::iter() {
+				ensure!(
+					WasteDataMap::<T>::get(report_id).map_or(false, |waste_data| {
+						waste_data.status == WasteStatus::Reported &&
+							waste_data.zone_id == Some(zone_id)
+					}),
+					"ZoneBacklog lists a report that isn't in Reported in that zone"
+				);
+			}
+			for ((zone_id, status), key, ()) in ZoneQueue::<T>::iter() {
+				ensure!(
+					WasteDataMap::<T>::get(ReportId::from_be_bytes(key)).map_or(
+						false,
+						|waste_data| {
+							waste_data.status == status && waste_data.zone_id == Some(zone_id)
+						}
+					),
+					"ZoneQueue lists a report that isn't in that status and zone"
+				);
+			}
+			Ok(())
+		}
+
+		/// Cancels reports filed `AutoExpiryBlocks` or more before `now` that are still in
+		/// `Reported` or `Collected`, oldest first, taking at most `MaxExpiriesPerBlock` steps.
+		/// Whatever is left is picked up in the next block.
+		fn expire_reports(now: BlockNumberFor<T>) -> Weight {
+			let expiry = T::AutoExpiryBlocks::get();
+			if expiry.is_zero() || now < expiry {
+				return Weight::zero()
+			}
+			let deadline = now - expiry;
+
+			let mut cursor = ExpiryCursor::<T>::get();
+			let mut budget = T::MaxExpiriesPerBlock::get();
+			let mut expired = 0u64;
+			let mut steps = 0u64;
+			while cursor <= deadline && budget > 0 {
+				let due: Vec<ReportId> =
+					ReportsByCreation::<T>::iter_key_prefix(cursor).take(budget as usize).collect();
+				budget -= due.len() as u32;
+				steps += due.len() as u64;
+				for report_id in due {
+					ReportsByCreation::<T>::remove(cursor, report_id);
+					if Self::expire_report(report_id) {
+						expired += 1;
+					}
+				}
+				if budget > 0 && ReportsByCreation::<T>::iter_key_prefix(cursor).next().is_none() {
+					cursor += One::one();
+					budget -= 1;
+					steps += 1;
+				}
+			}
+			ExpiryCursor::<T>::put(cursor);
+
+			T::DbWeight::get()
+				.reads_writes(steps.saturating_add(1), steps.saturating_add(1))
+				.saturating_add(T::WeightInfo::update_waste_status().saturating_mul(expired))
+		}
+
+		/// Releases the settlements due at `now` that were never committed. Their reports never
+		/// left their prior status, so only the hold is lifted.
+		fn time_out_settlements(now: BlockNumberFor<T>) -> Weight {
+			let due = SettlementDeadlines::<T>::take(now);
+			for report_id in due.iter() {
+				let Some(ticket) = Settling::<T>::get(report_id) else { continue };
+				if ticket.deadline != now {
+					continue
+				}
+				Settling::<T>::remove(report_id);
+				Self::deposit_event(|seq| Event::SettlementTimedOut {
+					report_id: *report_id,
+					status: ticket.prior_status,
+					seq,
+				});
+			}
+
+			let entries = due.len() as u64;
+			T::DbWeight::get().reads_writes(entries.saturating_add(1), entries.saturating_add(1))
+		}
+
+		/// Gives up the drafts due at `now` that were never filled in. Their ids are not reused.
+		fn expire_drafts(now: BlockNumberFor<T>) -> Weight {
+			let due = DraftDeadlines::<T>::take(now);
+			for report_id in due.iter() {
+				if Drafts::<T>::get(report_id).map_or(false, |(_, deadline)| deadline == now) {
+					Drafts::<T>::remove(report_id);
+					Self::deposit_event(|seq| Event::DraftExpired { report_id: *report_id, seq });
+				}
+			}
+
+			let entries = due.len() as u64;
+			T::DbWeight::get().reads_writes(entries.saturating_add(1), entries.saturating_add(1))
+		}
+
+		/// Flags the reports due at `now` that are still waiting for collection. Each report is
+		/// due at one block only, so it is flagged at most once. Collected and deleted reports
+		/// have already left the index.
+		fn flag_sla_breaches(now: BlockNumberFor<T>) -> Weight {
+			let mut entries = 0u64;
+			let mut breaches = 0u64;
+			for (report_id, ()) in SlaDeadlines::<T>::drain_prefix(now) {
+				entries += 1;
+				let Some(waste_data) = WasteDataMap::<T>::get(report_id) else { continue };
+				if waste_data.deadline != Some(now) || waste_data.status != WasteStatus::Reported {
+					continue
+				}
+				SlaBreaches::<T>::insert(report_id, ());
+				breaches += 1;
+				Self::deposit_report_event(
+					|seq| Event::SlaBreached { report_id, seq },
+					report_id,
+					&waste_data.reporter,
+				);
+			}
+
+			T::DbWeight::get().reads_writes(
+				entries.saturating_mul(2).saturating_add(1),
+				entries.saturating_add(breaches.saturating_mul(2)).saturating_add(1),
+			)
+		}
+
+		/// Cancels `report_id` if it is still waiting for collection or transport, returning
+		/// whether it did. Nothing is written for a report that can't be cancelled.
+		fn expire_report(report_id: ReportId) -> bool {
+			let result = frame_support::storage::with_storage_layer(|| {
+				WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+					let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+					ensure!(
+						matches!(waste_data.status, WasteStatus::Reported | WasteStatus::Collected),
+						Error::<T>::InvalidStatusTransition
+					);
+					ensure!(!Disputed::<T>::contains_key(report_id), Error::<T>::ReportDisputed);
+					ensure!(!Settling::<T>::contains_key(report_id), Error::<T>::ReportSettling);
+					Self::apply_status(waste_data, WasteStatus::Cancelled)
+				})
+			});
+			if result.is_ok() {
+				Self::deposit_event(|seq| Event::ReportAutoExpired { report_id, seq });
+			}
+			result.is_ok()
+		}
+
+		/// Collects the next batch of every zone with an emergency collection under way.
+		fn continue_emergency_collections() -> Weight {
+			// At most `MaxServiceZones` zones.
+			let zones: Vec<ZoneId> = EmergencyCollections::<T>::iter_keys().collect();
+			let mut attempted = 0u64;
+			for zone_id in zones.iter() {
+				attempted += Self::continue_emergency_collection(*zone_id) as u64;
+			}
+
+			let zones = zones.len() as u64;
+			T::DbWeight::get()
+				.reads_writes(zones.saturating_add(1), zones)
+				.saturating_add(T::WeightInfo::update_waste_status().saturating_mul(attempted))
+		}
+
+		/// Collects up to `MaxEmergencyBatch` reports of the backlog of `zone_id` and returns
+		/// how many it tried. The collection ends once the backlog is empty, or once a whole batch
+		/// fails, so that reports that can't be collected, e.g. with a full history, don't hold it
+		/// open forever; those stay in `Reported`. Only `MinTransitionDelay` is waived: reports
+		/// disputed or prepared for settlement since the collection started are passed over and
+		/// stay in the backlog the same way.
+		fn continue_emergency_collection(zone_id: ZoneId) -> u32 {
+			let batch = T::MaxEmergencyBatch::get();
+			let due: Vec<ReportId> =
+				ZoneBacklog::<T>::iter_key_prefix(zone_id).take(batch as usize).collect();
+			let mut collected = 0u32;
+			for report_id in due.iter() {
+				if Disputed::<T>::contains_key(report_id) || Settling::<T>::contains_key(report_id)
+				{
+					continue
+				}
+				let result = frame_support::storage::with_storage_layer(|| {
+					WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+						let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+						Self::apply_status(waste_data, WasteStatus::Collected)
+					})
+				});
+				collected += result.is_ok() as u32;
+			}
+
+			let count = EmergencyCollections::<T>::get(zone_id).saturating_add(collected);
+			if collected == 0 || ZoneBacklog::<T>::iter_key_prefix(zone_id).next().is_none() {
+				EmergencyCollections::<T>::remove(zone_id);
+				Self::deposit_event(|seq| Event::EmergencyCollectionCompleted {
+					zone_id,
+					count,
+					seq,
+				});
+			} else {
+				EmergencyCollections::<T>::insert(zone_id, count);
+			}
+			due.len() as u32
+		}
+
+		/// Whether a report has been waiting in `Reported` for at least `StaleThreshold` blocks
+		/// as of block `now`.
+		pub fn is_stale(waste_data: &WasteData<T>, now: BlockNumberFor<T>) -> bool {
+			waste_data.status == WasteStatus::Reported &&
+				now.saturating_sub(waste_data.created_at) >= T::StaleThreshold::get()
+		}
+
+		/// Whether a `BlockSummary` is due at the end of block `n`.
+		fn is_summary_block(n: BlockNumberFor<T>) -> bool {
+			let interval = T::SummaryInterval::get();
+			!interval.is_zero() && (n % interval).is_zero()
+		}
+
+		/// The number of status updates and other operator calls `operator` has made.
+		pub fn actions_of(operator: &T::AccountId) -> u64 {
+			OperatorActions::<T>::get(operator)
+		}
+
+		/// Counts a successful call towards the actions of `operator`.
+		fn note_operator_action(operator: &T::AccountId) {
+			OperatorActions::<T>::mutate(operator, |actions| *actions = actions.saturating_add(1));
+		}
+
+		/// The reporter score of `who`.
+		pub fn score_of(who: &T::AccountId) -> i32 {
+			ReporterScore::<T>::get(Self::reporter_key(who))
+		}
+
+		/// Total amount of waste of `waste_type` over all reports that are not cancelled.
+		pub fn total_amount_of_type(waste_type: WasteType) -> WasteAmount {
+			TotalAmountByType::<T>::get(waste_type)
+		}
+
+		/// Every registered waste type with its name, in `WasteCategory` order. The registry has
+		/// no bound of its own, but only root writes to it, so it holds as many types as were
+		/// registered, each name at most `MaxTypeNameLen` bytes.
+		pub fn all_waste_types() -> Vec<(WasteType, Vec<u8>)> {
+			let mut types: Vec<_> = WasteTypeRegistry::<T>::iter()
+				.map(|(waste_type, name)| (waste_type, name.into_inner()))
+				.collect();
+			types.sort_by_key(|(waste_type, _)| *waste_type);
+			types
+		}
+
+		/// The report carrying the external reference `external_ref`, if one is on file.
+		pub fn report_by_ref(external_ref: ExternalRef) -> Option<ReportId> {
+			ReportByExternalRef::<T>::get(external_ref)
+		}
+
+		/// Total amount of waste on file in the service zone `zone_id`.
+		pub fn total_in_zone(zone_id: ZoneId) -> WasteAmount {
+			TotalAmountByZone::<T>::get(zone_id)
+		}
+
+		/// Total amount of waste currently in `status`.
+		pub fn total_amount_in(status: WasteStatus) -> WasteAmount {
+			TotalAmountByStatus::<T>::get(status)
+		}
+
+		/// How much optional metadata a new report carries: a point for a contact, and one for
+		/// a severity above `Low`, which means the reporter triaged the pile. Both save
+		/// operators a site visit. Evidence is attached after filing, through
+		/// `attach_evidence`, so it doesn't count.
+		pub fn completeness_score(severity: &Severity, contact: &Option<Contact<T>>) -> u32 {
+			contact.is_some() as u32 + (*severity != Severity::Low) as u32
+		}
+
+		/// Total volume of waste currently in `status`.
+		pub fn total_volume_in(status: WasteStatus) -> u64 {
+			TotalVolumeByStatus::<T>::get(status)
+		}
+
+		/// The figures of `DashboardSummary`, in O(statuses) reads.
+		pub fn dashboard_summary() -> DashboardSummary {
+			let by_status = WasteStatus::ALL.map(|status| {
+				let amount = TotalAmountByStatus::<T>::get(&status);
+				(status, amount)
+			});
+			let total_amount = by_status
+				.iter()
+				.fold(0, |total: WasteAmount, (_, amount)| total.saturating_add(*amount));
+			// At most `MaxServiceZones` entries.
+			let mut by_zone = TotalAmountByZone::<T>::iter().collect::<Vec<_>>();
+			by_zone.sort_unstable_by_key(|(zone_id, _)| *zone_id);
+			DashboardSummary {
+				total: ActiveReportCount::<T>::get(),
+				by_status,
+				total_amount,
+				by_zone,
+			}
+		}
+
+		/// The stable code of `error` from `ERROR_CODES`, or 0 for an error without one.
+		pub fn error_code(error: &Error<T>) -> u16 {
+			let name = error.as_str();
+			ERROR_CODES
+				.iter()
+				.find(|(known, _)| *known == name)
+				.map_or(0, |(_, code)| *code)
+		}
+
+		/// The `WasteAmount` of one whole unit, `10^AmountDecimals`.
+		pub fn amount_unit() -> WasteAmount {
+			10u64.saturating_pow(T::AmountDecimals::get().into())
+		}
+
+		/// Converts `amount` whole `unit`s into a `WasteAmount`, rounding down to its resolution.
+		/// Integer arithmetic only; fails with `AmountTooLarge` if the result doesn't fit.
+		pub fn to_canonical(amount: u64, unit: WasteUnit) -> Result<WasteAmount, Error<T>> {
+			// Each unit as the fraction of a tonne `numerator / denominator`.
+			let (numerator, denominator): (u128, u128) = match unit {
+				WasteUnit::Canonical => return Ok(amount),
+				WasteUnit::Tonnes => (1, 1),
+				WasteUnit::Kilograms => (1, 1_000),
+				WasteUnit::Pounds => (45_359_237, 100_000_000_000),
+			};
+			(amount as u128)
+				.checked_mul(numerator)
+				.and_then(|scaled| scaled.checked_mul(Self::amount_unit() as u128))
+				.and_then(|scaled| WasteAmount::try_from(scaled / denominator).ok())
+				.ok_or(Error::<T>::AmountTooLarge)
+		}
+
+		/// Converts a number of whole units into a `WasteAmount`, or `None` if it doesn't fit.
+		pub fn amount_from_units(units: u64) -> Option<WasteAmount> {
+			units.checked_mul(Self::amount_unit())
+		}
+
+		/// Splits an amount into whole units and the remaining fraction, for display.
+		pub fn amount_parts(amount: WasteAmount) -> (u64, u64) {
+			let unit = Self::amount_unit();
+			(amount / unit, amount % unit)
+		}
+
+		/// Calls `f` on at most `max` items of `iter` and returns whether any were left over.
+		/// Extrinsics that scan storage go through here so that their work is bounded by an
+		/// argument their weight accounts for; finding a leftover reads one item more.
+		pub(crate) fn bounded_scan<I: Iterator>(
+			mut iter: I,
+			max: u32,
+			mut f: impl FnMut(I::Item),
+		) -> bool {
+			iter.by_ref().take(max as usize).for_each(&mut f);
+			iter.next().is_some()
+		}
+
+		/// Adds a report's amount and volume to the totals of `status`.
+		pub(crate) fn add_to_status_total(status: &WasteStatus, amount: WasteAmount, volume: u64) {
+			TotalAmountByStatus::<T>::mutate(status, |total| *total = total.saturating_add(amount));
+			TotalVolumeByStatus::<T>::mutate(status, |total| *total = total.saturating_add(volume));
+		}
+
+		/// Takes a report's amount and volume out of the totals of `status`.
+		fn sub_from_status_total(status: &WasteStatus, amount: WasteAmount, volume: u64) {
+			TotalAmountByStatus::<T>::mutate(status, |total| Self::decrement_total(total, amount));
+			TotalVolumeByStatus::<T>::mutate(status, |total| Self::decrement_total(total, volume));
+		}
+
+		pub(crate) fn add_to_type_totals(components: &Components<T>) {
+			for (waste_type, amount) in components.iter() {
+				TotalAmountByType::<T>::mutate(waste_type, |total| {
+					*total = total.saturating_add(*amount)
+				});
+			}
+		}
+
+		fn sub_from_type_totals(components: &Components<T>) {
+			for (waste_type, amount) in components.iter() {
+				TotalAmountByType::<T>::mutate(waste_type, |total| {
+					Self::decrement_total(total, *amount)
+				});
+			}
+		}
+
+		/// Adds a report to the `ZoneQueue` of its zone and status, if it has a zone.
+		fn queue_in_zone(zone_id: Option<ZoneId>, status: &WasteStatus, report_id: ReportId) {
+			if let Some(zone_id) = zone_id {
+				ZoneQueue::<T>::insert((zone_id, status.clone()), report_id.to_be_bytes(), ());
+			}
+		}
+
+		/// Takes a report out of the `ZoneQueue` of its zone and status, if it has a zone.
+		fn dequeue_from_zone(zone_id: Option<ZoneId>, status: &WasteStatus, report_id: ReportId) {
+			if let Some(zone_id) = zone_id {
+				ZoneQueue::<T>::remove((zone_id, status.clone()), report_id.to_be_bytes());
+			}
+		}
+
+		/// Takes a report out of the backlog of its zone, if it has one.
+		fn unindex_backlog(waste_data: &WasteData<T>) {
+			if let Some(zone_id) = waste_data.zone_id {
+				ZoneBacklog::<T>::remove(zone_id, waste_data.report_id);
+			}
+		}
+
+		/// Adds a report's amount to the total of its zone, if it has one.
+		fn add_to_zone_total(zone_id: Option<ZoneId>, amount: WasteAmount) {
+			if let Some(zone_id) = zone_id {
+				TotalAmountByZone::<T>::mutate(zone_id, |total| {
+					*total = total.saturating_add(amount)
+				});
+			}
+		}
+
+		/// Takes a report's amount out of the total of its zone, if it has one.
+		fn sub_from_zone_total(zone_id: Option<ZoneId>, amount: WasteAmount) {
+			if let Some(zone_id) = zone_id {
+				TotalAmountByZone::<T>::mutate(zone_id, |total| {
+					Self::decrement_total(total, amount)
+				});
+			}
+		}
+
+		/// Subtracts `amount` from an aggregate, stopping at zero. Going below zero can only
+		/// happen if the aggregate has drifted from the reports, so it also raises
+		/// `AggregatesDrifted` and, in debug builds, logs the shortfall.
+		fn decrement_total(total: &mut WasteAmount, amount: WasteAmount) {
+			if *total < amount {
+				#[cfg(debug_assertions)]
+				log::warn!(
+					target: "runtime::waste-management",
+					"aggregate of {} is less than the {} being removed from it",
+					*total,
+					amount,
+				);
+				AggregatesDrifted::<T>::put(true);
+			}
+			*total = total.saturating_sub(amount);
+		}
+
+		/// The shared body of `create_waste_data` and `create_waste_data_no_location`: converts
+		/// the amounts from `unit` and refunds the metadata discount.
+		#[allow(clippy::too_many_arguments)]
+		fn file_from_call(
+			reporter: &T::AccountId,
+			mut components: Components<T>,
+			volume: u64,
+			location: Option<GeoPoint>,
+			severity: Severity,
+			contact: Option<Contact<T>>,
+			origin_tag: Option<OriginTag<T>>,
+			idempotency_key: Option<IdempotencyKey>,
+			external_ref: Option<ExternalRef>,
+			unit: Option<WasteUnit>,
 		) -> DispatchResultWithPostInfo {
-			let operator = ensure_signed(origin)?;
-	
+			if let Some(unit) = unit {
+				for (_, amount) in components.iter_mut() {
+					*amount = Self::to_canonical(*amount, unit)?;
+				}
+			}
+			let discount = Perbill::from_percent(
+				METADATA_DISCOUNT_PERCENT
+					.saturating_mul(Self::completeness_score(&severity, &contact)),
+			);
+			Self::do_create_waste_data(
+				reporter,
+				components,
+				volume,
+				location,
+				severity,
+				contact,
+				origin_tag,
+				idempotency_key,
+				external_ref,
+			)?;
+
+			let weight = T::WeightInfo::create_waste_data();
+			Ok(Some(weight.saturating_sub(discount * weight)).into())
+		}
+
+		/// Files a report on behalf of `reporter` and returns its id. This is `create_waste_data`
+		/// for other runtime code, with the same checks and event. A reused `idempotency_key`
+		/// returns the id of the report it was first used for, as does an `external_ref` already
+		/// on file under `ExternalRefMode::UpsertAmount`, after updating that report's amount.
+		#[allow(clippy::too_many_arguments)]
+		pub fn do_create_waste_data(
+			reporter: &T::AccountId,
+			components: Components<T>,
+			volume: u64,
+			location: Option<GeoPoint>,
+			severity: Severity,
+			contact: Option<Contact<T>>,
+			origin_tag: Option<OriginTag<T>>,
+			idempotency_key: Option<IdempotencyKey>,
+			external_ref: Option<ExternalRef>,
+		) -> Result<ReportId, DispatchError> {
+			if let Some(key) = idempotency_key {
+				let seen = SeenKeys::<T>::get(Self::reporter_key(reporter), key)
+					.filter(|report_id| WasteDataMap::<T>::contains_key(report_id));
+				if let Some(report_id) = seen {
+					Self::deposit_event(|seq| Event::KeyReused {
+						reporter: Self::reporter_key(reporter),
+						key,
+						report_id,
+						seq,
+					});
+					return Ok(report_id)
+				}
+			}
+
+			let upserted = match external_ref.and_then(ReportByExternalRef::<T>::get) {
+				Some(report_id) if T::ExternalRefMode::get() == ExternalRefMode::UpsertAmount =>
+					Some(Self::upsert_amount(reporter, report_id, &components)?),
+				_ => None,
+			};
+			let report_id = match upserted {
+				Some(report_id) => report_id,
+				None => {
+					let waste_data = Self::insert_new_report(
+						reporter,
+						components,
+						volume,
+						location,
+						severity,
+						contact,
+						origin_tag,
+						external_ref,
+						None,
+					)?;
+					Self::deposit_created_event(&waste_data);
+					waste_data.report_id
+				},
+			};
+			if let Some(key) = idempotency_key {
+				SeenKeys::<T>::insert(Self::reporter_key(reporter), key, report_id);
+			}
+			Ok(report_id)
+		}
+
+		/// Replaces the amount of report `report_id`, filed by `reporter` under an
+		/// `external_ref` that was sent again with `components`, and deposits `ReportUpserted`.
+		/// Only the amount is taken over: a resent report that isn't a single component of the
+		/// same type is refused with `DuplicateExternalRef`, as it would be under
+		/// `ExternalRefMode::Reject`.
+		fn upsert_amount(
+			reporter: &T::AccountId,
+			report_id: ReportId,
+			components: &Components<T>,
+		) -> Result<ReportId, DispatchError> {
+			let waste_data = WasteDataMap::<T>::get(report_id).ok_or(Error::<T>::ReportNotFound)?;
+			ensure!(Self::is_reporter(&waste_data, reporter), Error::<T>::NotReportOwner);
+			let [(waste_type, new_amount)] = components.as_slice() else {
+				return Err(Error::<T>::DuplicateExternalRef.into())
+			};
+			ensure!(
+				waste_data.components.len() == 1 && waste_data.waste_type == *waste_type,
+				Error::<T>::DuplicateExternalRef
+			);
+			ensure!(
+				*new_amount >= MinAmountByType::<T>::get(waste_type),
+				Error::<T>::AmountBelowMinimum
+			);
+
+			Self::set_amount(report_id, *new_amount)?;
+			Self::deposit_report_event(
+				|seq| Event::ReportUpserted { report_id, seq },
+				report_id,
+				&waste_data.reporter,
+			);
+			Ok(report_id)
+		}
+
+		/// Replaces the amount of a single-component report, keeping the totals in step, and
+		/// returns the old amount.
+		fn set_amount(
+			report_id: ReportId,
+			new_amount: WasteAmount,
+		) -> Result<WasteAmount, DispatchError> {
+			ensure!(new_amount <= T::MaxWasteAmount::get(), Error::<T>::AmountTooLarge);
+			Self::ensure_not_sealed(report_id)?;
+
+			let old_amount = WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
+				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
+				let [(component_type, component_amount)] = waste_data.components.as_mut_slice()
+				else {
+					return Err(Error::<T>::MultipleComponents)
+				};
+				*component_amount = new_amount;
+				if waste_data.status != WasteStatus::Cancelled {
+					TotalAmountByType::<T>::mutate(*component_type, |total| {
+						Self::decrement_total(total, waste_data.waste_amount);
+						*total = total.saturating_add(new_amount)
+					});
+				}
+				let old_amount = waste_data.waste_amount;
+				waste_data.waste_amount = new_amount;
+				waste_data.updated_at = frame_system::Pallet::<T>::block_number();
+
+				Self::write_mirror(waste_data);
+				Self::sub_from_status_total(&waste_data.status, old_amount, 0);
+				Self::add_to_status_total(&waste_data.status, new_amount, 0);
+				Self::sub_from_zone_total(waste_data.zone_id, old_amount);
+				Self::add_to_zone_total(waste_data.zone_id, new_amount);
+
+				Ok::<WasteAmount, Error<T>>(old_amount)
+			})?;
+			Ok(old_amount)
+		}
+
+		/// Deposits `WasteDataCreated` for a newly filed report, or `WasteDataCreatedMinimal`
+		/// unless `VerboseEvents` is set.
+		fn deposit_created_event(waste_data: &WasteData<T>) {
+			if !T::VerboseEvents::get() {
+				Self::deposit_report_event(
+					|seq| Event::WasteDataCreatedMinimal { report_id: waste_data.report_id, seq },
+					waste_data.report_id,
+					&waste_data.reporter,
+				);
+				return
+			}
+			Self::deposit_report_event(
+				|seq| Event::WasteDataCreated {
+					report_id: waste_data.report_id,
+					reporter: waste_data.reporter.clone(),
+					severity: waste_data.severity.clone(),
+					waste_type: waste_data.waste_type,
+					waste_amount: waste_data.waste_amount,
+					volume: waste_data.volume,
+					origin_tag_len: waste_data
+						.origin_tag
+						.as_ref()
+						.map_or(0, |tag| tag.len() as u32),
+					status: waste_data.status.clone(),
+					has_location: waste_data.location.is_some(),
+					seq,
+				},
+				waste_data.report_id,
+				&waste_data.reporter,
+			);
+		}
+
+		/// Puts a report that may become `Utilized` into `Settling`, for a settlement pallet that
+		/// has to utilize it and release an escrow together. Until the returned ticket is passed
+		/// to `commit_settlement`, or `SettlementTimeout` blocks pass, no other status change is
+		/// allowed. The report keeps its status, which it returns to on timeout.
+		pub fn prepare_settlement(
+			report_id: ReportId,
+		) -> Result<SettlementTicket<BlockNumberFor<T>>, DispatchError> {
+			let waste_data = WasteDataMap::<T>::get(report_id).ok_or(Error::<T>::ReportNotFound)?;
+			Self::ensure_transition(&waste_data, &WasteStatus::Utilized)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let deadline = now.saturating_add(T::SettlementTimeout::get().max(One::one()));
+			SettlementDeadlines::<T>::try_mutate(deadline, |due| due.try_push(report_id))
+				.map_err(|_| Error::<T>::TooManySettlements)?;
+			let ticket = SettlementTicket { report_id, prior_status: waste_data.status, deadline };
+			Settling::<T>::insert(report_id, &ticket);
+
+			Self::deposit_event(|seq| Event::SettlementPrepared { report_id, deadline, seq });
+			Ok(ticket)
+		}
+
+		/// Utilizes the report held by `ticket`, ending its settlement. The settlement pallet
+		/// has already made the checks, so no approvals are collected.
+		pub fn commit_settlement(ticket: SettlementTicket<BlockNumberFor<T>>) -> DispatchResult {
+			let report_id = ticket.report_id;
+			ensure!(Settling::<T>::get(report_id) == Some(ticket.clone()), Error::<T>::NotSettling);
+
 			WasteDataMap::<T>::try_mutate(report_id, |waste_data| {
 				let waste_data = waste_data.as_mut().ok_or(Error::<T>::ReportNotFound)?;
-				let old_status = waste_data.status.clone();
-				waste_data.status = new_status.clone();
-	
-				if old_status != new_status {
-					WasteDataByStatus::<T>::remove((old_status, report_id));
-					WasteDataByStatus::<T>::insert((new_status, report_id), waste_data.clone());
-				}
-	
-				Ok::<(), Error<T>>(())
+				// A `ForceOrigin` update may have moved the report since it was prepared.
+				ensure!(waste_data.status == ticket.prior_status, Error::<T>::NotSettling);
+				Self::apply_status(waste_data, WasteStatus::Utilized)
 			})?;
-	
-			Self::deposit_event(Event::WasteStatusUpdated { report_id, operator });
-	
-			Ok(().into())
+			Settling::<T>::remove(report_id);
+			SettlementDeadlines::<T>::mutate(ticket.deadline, |due| {
+				due.retain(|due_id| *due_id != report_id)
+			});
+
+			Self::deposit_event(|seq| Event::SettlementCommitted { report_id, seq });
+			Ok(())
+		}
+
+		/// The topic of events about `report_id`.
+		pub fn report_topic(report_id: ReportId) -> T::Hash {
+			T::Hashing::hash_of(&(b"report", report_id))
+		}
+
+		/// The topic of events about reports filed by `reporter`.
+		pub fn reporter_topic(reporter: &T::AccountId) -> T::Hash {
+			T::Hashing::hash_of(&(b"reporter", reporter))
+		}
+
+		/// Deposits the event `event` builds from the next `EventSeq` number. Every event goes
+		/// through here or `deposit_report_event`, so that no number is skipped.
+		pub(super) fn deposit_event(event: impl FnOnce(u64) -> Event<T>) {
+			let event = event(Self::next_event_seq());
+			Self::log_report_event(&event);
+			Self::deposit_numbered_event(event);
+		}
+
+		/// Deposits the event `event` builds from the next `EventSeq` number under the topics
+		/// of `report_id` and its reporter.
+		fn deposit_report_event(
+			event: impl FnOnce(u64) -> Event<T>,
+			report_id: ReportId,
+			reporter: &T::AccountId,
+		) {
+			let topics = [Self::report_topic(report_id), Self::reporter_topic(reporter)];
+			let event = event(Self::next_event_seq());
+			Self::log_report_event(&event);
+			let event = <T as Config>::RuntimeEvent::from(event);
+			frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+		}
+
+		/// Appends `event` to the log of the report it is about, if any, and to
+		/// `RecentActivity`, dropping the oldest entry first from either if it is full.
+		fn log_report_event(event: &Event<T>) {
+			let Some(report_id) = event.report_id() else { return };
+			let code = event.using_encoded(|bytes| bytes[0]);
+			let now = frame_system::Pallet::<T>::block_number();
+			ReportEventLog::<T>::mutate(report_id, |log| {
+				let log = log.get_or_insert_with(Default::default);
+				if !log.is_empty() && log.len() as u32 >= T::MaxEventLog::get() {
+					log.remove(0);
+				}
+				let _ = log.try_push((code, now));
+			});
+			RecentActivity::<T>::mutate(|recent| {
+				if !recent.is_empty() && recent.len() as u32 >= T::RecentActivityLen::get() {
+					recent.remove(0);
+				}
+				let _ = recent.try_push((report_id, code, now));
+			});
+		}
+
+		/// Advances `EventSeq` and returns the number for the event about to be deposited.
+		fn next_event_seq() -> u64 {
+			EventSeq::<T>::mutate(|seq| {
+				*seq = seq.wrapping_add(1);
+				*seq
+			})
+		}
+
+		/// Validates and stores a new report filed by `reporter`, returning it. It takes the
+		/// next free id unless `reserved_id`, an id reserved by `reserve_report_id`, is given.
+		/// Events are left to the caller.
+		#[allow(clippy::too_many_arguments)]
+		fn insert_new_report(
+			reporter: &T::AccountId,
+			components: Components<T>,
+			volume: u64,
+			location: Option<GeoPoint>,
+			severity: Severity,
+			contact: Option<Contact<T>>,
+			origin_tag: Option<OriginTag<T>>,
+			external_ref: Option<ExternalRef>,
+			reserved_id: Option<ReportId>,
+		) -> Result<WasteData<T>, DispatchError> {
+			ensure!(!Mutating::<T>::get(), Error::<T>::Reentrancy);
+			ensure!(!Paused::<T>::get(), Error::<T>::CreationPaused);
+			Self::ensure_below_active_ceiling()?;
+			Self::ensure_may_report(reporter)?;
+			let report_id = match reserved_id {
+				Some(report_id) => report_id,
+				None => Self::next_free_report_id()?,
+			};
+			let mut waste_data = WasteData::<T>::new_validated(
+				report_id,
+				Self::reporter_key(reporter),
+				components,
+				volume,
+				location,
+				severity,
+				contact,
+				origin_tag,
+			)?;
+			if let Some(external_ref) = external_ref {
+				ensure!(
+					!ReportByExternalRef::<T>::contains_key(external_ref),
+					Error::<T>::DuplicateExternalRef
+				);
+				waste_data.external_ref = Some(external_ref);
+			}
+			ensure!(
+				TotalAmountByStatus::<T>::get(WasteStatus::Reported)
+					.checked_add(waste_data.waste_amount)
+					.is_some(),
+				Error::<T>::AmountOverflow
+			);
+
+			// Reports without a location can't be told apart by place, so they aren't deduplicated.
+			if let Some(location) = location {
+				let now = waste_data.created_at;
+				let location_key = (location.x, location.y, waste_data.waste_type);
+				if let Some((seen_id, seen_at)) = RecentReports::<T>::get(location_key) {
+					ensure!(
+						now.saturating_sub(seen_at) >= T::DedupWindow::get() ||
+							!WasteDataMap::<T>::contains_key(seen_id),
+						Error::<T>::DuplicateLocationReport
+					);
+				}
+				RecentReports::<T>::insert(location_key, (report_id, now));
+			}
+
+			Self::non_reentrant(|| {
+				T::ReportHook::on_created(&waste_data)?;
+				Self::store_report(&waste_data)
+			})?;
+
+			let deposit = T::ReportDeposit::get();
+			T::Currency::reserve(reporter, deposit)
+				.map_err(|_| Error::<T>::InsufficientBalanceForDeposit)?;
+			ReportDeposits::<T>::insert(report_id, deposit);
+			if T::PrivacyMode::get() {
+				ReporterAccounts::<T>::insert(report_id, reporter);
+			}
+
+			Ok(waste_data)
+		}
+
+		/// The id the next report will be stored under. Nothing is written: `store_report`
+		/// advances `NextReportId` once the report is in, so a report that fails to store leaves
+		/// no gap in the id space.
+		///
+		/// While `NextReportId` only moves that way the `DuplicateReport` check can't trip. It is
+		/// kept anyway, for one read, so that a migration or `set_storage` that leaves the counter
+		/// behind the map fails filing loudly instead of overwriting a report.
+		fn next_free_report_id() -> Result<ReportId, DispatchError> {
+			let report_id = NextReportId::<T>::get();
+			report_id.checked_add(1).ok_or(Error::<T>::BoundsOverflow)?;
+			if WasteDataMap::<T>::contains_key(report_id) {
+				// Ids are never reused, so this means `NextReportId` fell behind the map.
+				log::error!(
+					target: "runtime::waste-management",
+					"report {} already exists, NextReportId is out of step",
+					report_id,
+				);
+				return Err(Error::<T>::DuplicateReport.into())
+			}
+			Ok(report_id)
+		}
+
+		/// Writes a new `Reported` report, whose id came from `next_free_report_id` or
+		/// `reserve_report_id`, enters it in every index and aggregate and then makes sure
+		/// `NextReportId` is past it.
+		fn store_report(waste_data: &WasteData<T>) -> DispatchResult {
+			let report_id = waste_data.report_id;
+			WasteDataMap::<T>::insert(report_id, waste_data);
+
+			ActiveReportCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			OpenReportCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			Self::write_mirror(waste_data);
+			ReportsByReporter::<T>::try_mutate(&waste_data.reporter, |ids| ids.try_push(report_id))
+				.map_err(|_| Error::<T>::TooManyReports)?;
+			if let Some(location) = &waste_data.location {
+				ReportsByCell::<T>::try_mutate(Self::cell_of(location), |ids| {
+					ids.try_push(report_id)
+				})
+				.map_err(|_| Error::<T>::CellFull)?;
+			}
+			ReportsBySeverity::<T>::insert(&waste_data.severity, report_id, ());
+			Self::add_to_status_total(
+				&WasteStatus::Reported,
+				waste_data.waste_amount,
+				waste_data.volume,
+			);
+			Self::add_to_type_totals(&waste_data.components);
+			Self::add_to_zone_total(waste_data.zone_id, waste_data.waste_amount);
+			if let Some(zone_id) = waste_data.zone_id {
+				ZoneBacklog::<T>::insert(zone_id, report_id, ());
+			}
+			Self::queue_in_zone(waste_data.zone_id, &WasteStatus::Reported, report_id);
+			if let Some(external_ref) = waste_data.external_ref {
+				ReportByExternalRef::<T>::insert(external_ref, report_id);
+			}
+			ReportsByCreation::<T>::insert(waste_data.created_at, report_id, ());
+			ReportsFiledIn::<T>::insert(waste_data.created_at, report_id.to_be_bytes(), ());
+			if let Some(deadline) = waste_data.deadline {
+				SlaDeadlines::<T>::insert(deadline, report_id, ());
+			}
+			Self::record_history(report_id, WasteStatus::Reported, waste_data.created_at)?;
+			if let Some(location) = waste_data.location {
+				Self::record_location(report_id, location)?;
+			}
+			NextReportId::<T>::mutate(|next| *next = (*next).max(report_id.saturating_add(1)));
+			Ok(())
+		}
+
+		/// Refuses another open report once `MaxActiveReports` are open.
+		fn ensure_below_active_ceiling() -> DispatchResult {
+			ensure!(
+				OpenReportCount::<T>::get() < T::MaxActiveReports::get(),
+				Error::<T>::TooManyActiveReports
+			);
+			Ok(())
+		}
+
+		/// Refuses any change to a sealed report.
+		fn ensure_not_sealed(report_id: ReportId) -> DispatchResult {
+			ensure!(!Sealed::<T>::contains_key(report_id), Error::<T>::ReportSealed);
+			Ok(())
+		}
+
+		/// Runs `f` with `Mutating` set, clearing it again whatever `f` returns.
+		fn non_reentrant(f: impl FnOnce() -> DispatchResult) -> DispatchResult {
+			Mutating::<T>::put(true);
+			let result = f();
+			Mutating::<T>::kill();
+			result
+		}
+
+		/// Appends `status` to the history of `report_id` and sets its bit in the bitmap.
+		fn record_history(
+			report_id: ReportId,
+			status: WasteStatus,
+			at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			StatusBitmap::<T>::mutate(report_id, |bitmap| *bitmap |= status.bit());
+			StatusHistory::<T>::try_mutate(report_id, |history| {
+				history
+					.get_or_insert_with(Default::default)
+					.try_push((status, at))
+					.map_err(|_| Error::<T>::HistoryFull.into())
+			})
+		}
+
+		/// Appends `location` to the route of `report_id`, dropping the oldest location first if
+		/// the route is full and `RouteHistoryMode` allows it.
+		fn record_location(report_id: ReportId, location: GeoPoint) -> DispatchResult {
+			LocationHistory::<T>::try_mutate(report_id, |route| {
+				let route = route.get_or_insert_with(Default::default);
+				if T::RouteHistoryMode::get() == RouteHistoryMode::DropOldest &&
+					!route.is_empty() && route.len() as u32 >= T::MaxLocationHistory::get()
+				{
+					route.remove(0);
+				}
+				route.try_push(location).map_err(|_| Error::<T>::LocationHistoryFull.into())
+			})
+		}
+
+		/// Checks that `origin` is root or signed by `account`.
+		fn ensure_root_or(origin: OriginFor<T>, account: &T::AccountId) -> DispatchResult {
+			if let Some(who) = ensure_signed_or_root(origin)? {
+				ensure!(who == *account, DispatchError::BadOrigin);
+			}
+			Ok(())
+		}
+
+		/// Checks that `who` may move reports into `status`, either as an operator or through a
+		/// status permission.
+		fn ensure_can_set_status(who: &T::AccountId, status: &WasteStatus) -> DispatchResult {
+			ensure!(
+				Operators::<T>::contains_key(who) ||
+					StatusPermissions::<T>::contains_key((who, status.clone())),
+				Error::<T>::NotAuthorizedForStatus
+			);
+			Ok(())
+		}
+
+		/// Fails with `InsufficientOperators` until `MinOperators` operators are registered.
+		/// Reads no more than that many of them.
+		fn ensure_enough_operators() -> DispatchResult {
+			let min = T::MinOperators::get() as usize;
+			ensure!(
+				Operators::<T>::iter_keys().take(min).count() >= min,
+				Error::<T>::InsufficientOperators
+			);
+			Ok(())
+		}
+
+		/// Checks that `reporter` may file a report, by the allowlist and its score, and counts
+		/// the report against its per-block limit.
+		fn ensure_may_report(reporter: &T::AccountId) -> DispatchResult {
+			ensure!(
+				!T::RequireAllowlist::get() || AllowedReporters::<T>::contains_key(reporter),
+				Error::<T>::ReporterNotAllowed
+			);
+			ensure!(
+				Self::score_of(reporter) >= T::MinScoreToReport::get(),
+				Error::<T>::ReputationTooLow
+			);
+			Self::note_report_this_block(reporter)
+		}
+
+		/// Counts a report against the per-block limit of `reporter`.
+		fn note_report_this_block(reporter: &T::AccountId) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			ReportsThisBlock::<T>::try_mutate(reporter, |(block, count)| {
+				if *block != now {
+					*block = now;
+					*count = 0;
+				}
+				ensure!(*count < T::MaxReportsPerBlock::get(), Error::<T>::RateLimited);
+				*count += 1;
+				Ok(())
+			})
+		}
+
+		/// The lowest id of the service zones `point` falls in, corners included.
+		pub fn zone_of(point: &GeoPoint) -> Option<ZoneId> {
+			ServiceZones::<T>::iter()
+				.filter(|(_, (min, max))| {
+					(min.x..=max.x).contains(&point.x) && (min.y..=max.y).contains(&point.y)
+				})
+				.map(|(zone_id, _)| zone_id)
+				.min()
+		}
+
+		/// The zone a new or moved report at `location` is filed under: `None` while no zone is
+		/// defined, and an error if zones are defined but `location` is outside all of them.
+		fn zone_for(location: &GeoPoint) -> Result<Option<ZoneId>, Error<T>> {
+			if ServiceZones::<T>::iter_keys().next().is_none() {
+				return Ok(None)
+			}
+			Self::zone_of(location).map(Some).ok_or(Error::<T>::LocationOutsideServiceArea)
+		}
+
+		/// Checks that a location lies within the configured service area.
+		fn ensure_location_in_bounds(location: &GeoPoint) -> DispatchResult {
+			ensure!(
+				location.x <= T::MaxLocationX::get() && location.y <= T::MaxLocationY::get(),
+				Error::<T>::LocationOutOfBounds
+			);
+			Ok(())
+		}
+
+		/// Whether the lifecycle allows moving from `from` to `to`, as configured in
+		/// `AllowedTransitions`.
+		pub fn is_valid_transition(from: &WasteStatus, to: &WasteStatus) -> bool {
+			match AllowedTransitions::<T>::get(from) {
+				Some(targets) => targets.contains(to),
+				None => Self::is_default_transition(from, to),
+			}
+		}
+
+		/// Whether the workflow of `waste_type` allows moving from `from` to `to`, falling back to
+		/// `is_valid_transition` where the type has no entry for `from`.
+		pub fn is_valid_transition_for(
+			waste_type: WasteType,
+			from: &WasteStatus,
+			to: &WasteStatus,
+		) -> bool {
+			match TypeTransitions::<T>::get(waste_type, from) {
+				Some(targets) => targets.contains(to),
+				None => Self::is_valid_transition(from, to),
+			}
+		}
+
+		/// The default lifecycle transition table. Waste only ever moves forward through its
+		/// physical lifecycle, one stage at a time: Reported -> Collected -> Transported ->
+		/// Utilized. A plant may instead reject a transported load. Any report that has not been
+		/// utilized may be cancelled. Utilized, Cancelled and Rejected are terminal.
+		pub fn is_default_transition(from: &WasteStatus, to: &WasteStatus) -> bool {
+			use WasteStatus::*;
+			matches!(
+				(from, to),
+				(Reported, Collected) |
+					(Collected, Transported) |
+					(Transported, Utilized | Rejected) |
+					(Reported | Collected | Transported, Cancelled)
+			)
 		}
 	}
-}
\ No newline at end of file
+}
@@ -0,0 +1,219 @@
+//! Storage migrations for the waste-management pallet.
+
+pub mod v1 {
+	//! Upgrades storage written before the pallet was versioned (on-chain version 0) to
+	//! version 1: `waste_type` changes from a bare number to a `WasteCategory`, `WasteData`
+	//! gains `components`, `volume`, `zone_id`, `severity`, `contact`, `origin_tag`,
+	//! `external_ref`, `parent`, `created_at`/`updated_at`, `deadline`, `collector` and `flags`,
+	//! `WasteDataCount` is split into `NextReportId`, `ActiveReportCount` and `OpenReportCount`,
+	//! `WasteDataByStatus` becomes a double map keyed by status that keeps only the statuses in
+	//! `IndexedStatuses`, and the indices and totals added since are built from the reports.
+
+	use crate::{
+		ActiveReportCount, Components, Config, ExpiryCursor, GeoPoint, LocationHistory,
+		NextReportId, OpenReportCount, Pallet, ReportId, ReportsByCell, ReportsByCreation,
+		ReportsByReporter, ReportsBySeverity, ReportsFiledIn, Severity, StatusBitmap,
+		StatusHistory, WasteAmount, WasteCategory, WasteData, WasteDataByStatus, WasteDataMap,
+		WasteStatus,
+	};
+	use frame_support::{
+		pallet_prelude::*,
+		storage_alias,
+		traits::{GetStorageVersion, OnRuntimeUpgrade},
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_std::vec::Vec;
+
+	/// `WasteData` as stored at version 0.
+	#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+	pub struct OldWasteData<AccountId> {
+		pub report_id: ReportId,
+		pub waste_type: u32,
+		pub waste_amount: WasteAmount,
+		pub status: WasteStatus,
+		pub location_x: u32,
+		pub location_y: u32,
+		pub reporter: AccountId,
+	}
+
+	impl<AccountId> OldWasteData<AccountId> {
+		/// The version 1 layout. The numeric waste type is mapped onto its `WasteCategory`. Old
+		/// reports hold a single component of unknown volume, were never triaged, so they start at
+		/// `Low` severity, and their filing block is unknown, so it is taken to be the block the
+		/// migration runs in. They were filed under no SLA, so they have no deadline, and were
+		/// dispatched to no collector and carry no flags.
+		pub fn upgrade<T>(self, now: BlockNumberFor<T>) -> WasteData<T>
+		where
+			T: Config<AccountId = AccountId>,
+		{
+			let waste_type = WasteCategory::from_legacy(self.waste_type);
+			WasteData {
+				report_id: self.report_id,
+				waste_type,
+				waste_amount: self.waste_amount,
+				components: Components::<T>::truncate_from(sp_std::vec![(
+					waste_type,
+					self.waste_amount
+				)]),
+				volume: 0,
+				status: self.status,
+				location: Some(GeoPoint { x: self.location_x, y: self.location_y }),
+				zone_id: None,
+				reporter: self.reporter,
+				severity: Severity::Low,
+				contact: None,
+				origin_tag: None,
+				external_ref: None,
+				parent: None,
+				created_at: now,
+				updated_at: now,
+				deadline: None,
+				collector: None,
+				flags: 0,
+			}
+		}
+	}
+
+	/// Storage items whose key layout changed in version 1.
+	pub mod v0 {
+		use super::OldWasteData;
+		use crate::{Config, Pallet, ReportId, WasteStatus};
+		use frame_support::{pallet_prelude::*, storage_alias};
+
+		/// The status index as a single map keyed by the `(status, id)` pair.
+		#[storage_alias]
+		pub type WasteDataByStatus<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			(WasteStatus, ReportId),
+			OldWasteData<<T as frame_system::Config>::AccountId>,
+		>;
+	}
+
+	/// The version 0 report counter, which was also the id of the latest report.
+	#[storage_alias]
+	pub type WasteDataCount<T: Config> = StorageValue<Pallet<T>, u64, ValueQuery>;
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut reports = 0u64;
+			let mut active = 0u64;
+			let mut open = 0u64;
+			WasteDataMap::<T>::translate::<OldWasteData<T::AccountId>, _>(|report_id, old| {
+				reports += 1;
+				let waste_data = old.upgrade::<T>(now);
+				Self::index(report_id, &waste_data, now);
+				if waste_data.status != WasteStatus::Cancelled {
+					active += 1;
+				}
+				if !waste_data.status.is_closed() {
+					open += 1;
+				}
+				Some(waste_data)
+			});
+			// The status index moves from a map keyed by `(status, id)` to a double map, so its
+			// entries are taken out under the old keys before being written under the new ones.
+			let legacy: Vec<_> = v0::WasteDataByStatus::<T>::drain().collect();
+			let mirrors = legacy.len() as u64;
+			for ((status, report_id), old) in legacy {
+				if Pallet::<T>::is_indexed(&status) {
+					WasteDataByStatus::<T>::insert(status, report_id, old.upgrade::<T>(now));
+				}
+			}
+
+			NextReportId::<T>::put(WasteDataCount::<T>::take().saturating_add(1));
+			ActiveReportCount::<T>::put(active);
+			OpenReportCount::<T>::put(open);
+			// Nothing was indexed by filing block before now.
+			ExpiryCursor::<T>::put(now);
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			// Besides the translated entries, every report reads and writes the reporter, cell
+			// and block indices and the status and type totals, and writes its severity, filing
+			// block, route, history and status bitmap entries.
+			let entries = reports.saturating_add(mirrors);
+			T::DbWeight::get().reads_writes(
+				entries.saturating_add(reports.saturating_mul(5)).saturating_add(2),
+				entries.saturating_add(reports.saturating_mul(10)).saturating_add(6),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let version = Pallet::<T>::on_chain_storage_version();
+			let reports = WasteDataMap::<T>::iter_keys().count() as u64;
+			let mirrors = v0::WasteDataByStatus::<T>::iter_keys()
+				.filter(|(status, _)| Pallet::<T>::is_indexed(status))
+				.count() as u64;
+			Ok((version, reports, mirrors).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (version, reports, mirrors): (StorageVersion, u64, u64) =
+				Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not bumped");
+			if version != 0 {
+				return Ok(())
+			}
+
+			// Iteration skips values that fail to decode, so matching counts mean every entry
+			// was re-encoded in the new layout.
+			ensure!(
+				WasteDataMap::<T>::iter_values().count() as u64 == reports,
+				"WasteDataMap entries lost in migration"
+			);
+			ensure!(
+				WasteDataByStatus::<T>::iter_values().count() as u64 == mirrors,
+				"WasteDataByStatus entries lost in migration"
+			);
+			Pallet::<T>::do_try_state()
+		}
+	}
+
+	impl<T: Config> MigrateToV1<T> {
+		/// Builds the index and aggregate entries of a legacy report, none of which existed at
+		/// version 0. Reports beyond the bound of the reporter or cell index are left out
+		/// of it rather than failing the upgrade.
+		fn index(report_id: ReportId, waste_data: &WasteData<T>, now: BlockNumberFor<T>) {
+			let _ = ReportsByReporter::<T>::try_mutate(&waste_data.reporter, |ids| {
+				ids.try_push(report_id)
+			});
+			// Version 0 reports always have a location.
+			if let Some(location) = &waste_data.location {
+				let _ = ReportsByCell::<T>::try_mutate(Pallet::<T>::cell_of(location), |ids| {
+					ids.try_push(report_id)
+				});
+				LocationHistory::<T>::insert(
+					report_id,
+					BoundedVec::truncate_from(sp_std::vec![*location]),
+				);
+			}
+			Pallet::<T>::add_to_status_total(
+				&waste_data.status,
+				waste_data.waste_amount,
+				waste_data.volume,
+			);
+			if !matches!(waste_data.status, WasteStatus::Utilized | WasteStatus::Cancelled) {
+				ReportsBySeverity::<T>::insert(&waste_data.severity, report_id, ());
+			}
+			if waste_data.status != WasteStatus::Cancelled {
+				Pallet::<T>::add_to_type_totals(&waste_data.components);
+			}
+			ReportsByCreation::<T>::insert(now, report_id, ());
+			ReportsFiledIn::<T>::insert(now, report_id.to_be_bytes(), ());
+			StatusBitmap::<T>::insert(report_id, waste_data.status.bit());
+			StatusHistory::<T>::insert(
+				report_id,
+				BoundedVec::truncate_from(sp_std::vec![(waste_data.status.clone(), now)]),
+			);
+		}
+	}
+}
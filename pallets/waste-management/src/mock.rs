@@ -0,0 +1,308 @@
+use crate as waste_management;
+use crate::{WasteCategory, WasteType};
+use frame_support::{
+	ensure,
+	traits::{ConstU16, ConstU32, ConstU64, ConstU8},
+};
+use sp_core::H256;
+use sp_runtime::{
+	testing::{Header, TestSignature, TestXt, UintAuthorityId},
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, DispatchResult, Permill,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		WasteManagement: waste_management,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	fn create_transaction<S: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: UintAuthorityId,
+		_account: u64,
+		nonce: u64,
+	) -> Option<(RuntimeCall, <Extrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload)> {
+		Some((call, (nonce, ())))
+	}
+}
+
+/// Signs off-chain worker transactions with test keys.
+pub struct TestAuthId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+/// Deposit reserved for every report in tests.
+pub const REPORT_DEPOSIT: u64 = 10;
+
+/// The largest amount a single report may carry in tests.
+pub const MAX_WASTE_AMOUNT: u64 = u64::MAX / 4;
+
+/// Blocks between two `BlockSummary` events in tests.
+pub const SUMMARY_INTERVAL: u64 = 5;
+
+/// Blocks after which a report still in `Reported` is stale in tests.
+pub const STALE_THRESHOLD: u64 = 20;
+
+/// The largest volume a single report may take up in tests.
+pub const MAX_VOLUME: u64 = 1_000;
+
+/// Blocks a settlement may stay prepared in tests.
+pub const SETTLEMENT_TIMEOUT: u64 = 10;
+
+/// Blocks a reserved report id is held for in tests.
+pub const DRAFT_EXPIRY: u64 = 10;
+
+/// Balance every test account starts with.
+pub const INITIAL_BALANCE: u64 = 1_000;
+
+frame_support::parameter_types! {
+	pub static MaxReportsPerBlock: u32 = 10;
+	pub static MaxActiveReports: u64 = u64::MAX;
+	pub static DedupWindow: u64 = 0;
+	pub static MinTransitionDelay: u64 = 0;
+	pub static CompletionReward: u64 = 5;
+	pub static TreasuryCut: Permill = Permill::zero();
+	pub static MaxHistoryLen: u32 = 8;
+	pub static MinScoreToReport: i32 = i32::MIN;
+	pub static AutoExpiryBlocks: u64 = 0;
+	pub static RequireAllowlist: bool = false;
+	pub static PrivacyMode: bool = false;
+	pub static VerboseEvents: bool = true;
+	pub static AutoStampCollector: bool = false;
+	pub static RouteHistoryMode: waste_management::RouteHistoryMode =
+		waste_management::RouteHistoryMode::Reject;
+	pub static ExternalRefMode: waste_management::ExternalRefMode =
+		waste_management::ExternalRefMode::Reject;
+	pub static IndexedStatuses: &'static [waste_management::WasteStatus] =
+		&waste_management::WasteStatus::ALL;
+	pub static RequiredApprovals: (waste_management::WasteStatus, u32) =
+		(waste_management::WasteStatus::Utilized, 1);
+	pub static MinOperators: u32 = 1;
+	pub static ReachedTransported: Vec<waste_management::ReportId> = Vec::new();
+}
+
+/// Custom waste type whose reports make `TestReportHook` file another report.
+pub const REENTRANT_TYPE: WasteType = WasteCategory::Other(13);
+
+/// Vetoes every report of the custom waste type 0, standing in for a parent pallet's policy,
+/// and for `REENTRANT_TYPE` tries to file a second report from inside the hook, like a buggy
+/// parent pallet would.
+pub struct TestReportHook;
+
+impl waste_management::OnReportCreated<Test> for TestReportHook {
+	fn on_created(report: &waste_management::WasteData<Test>) -> DispatchResult {
+		ensure!(
+			report.waste_type != WasteCategory::Other(0),
+			DispatchError::Other("waste type 0 is not accepted")
+		);
+		if report.waste_type == REENTRANT_TYPE {
+			WasteManagement::do_create_waste_data(
+				&report.reporter,
+				report.components.clone(),
+				report.volume,
+				report.location,
+				report.severity.clone(),
+				None,
+				None,
+				None,
+				None,
+			)?;
+		}
+		Ok(())
+	}
+}
+
+/// Records every report that reaches `Transported`, standing in for an incineration
+/// scheduler.
+pub struct RecordTransported;
+
+impl waste_management::OnStatusReached<Test> for RecordTransported {
+	fn on_reached(
+		report_id: waste_management::ReportId,
+		status: &waste_management::WasteStatus,
+		_report: &waste_management::WasteData<Test>,
+	) {
+		if *status == waste_management::WasteStatus::Transported {
+			let mut reached = ReachedTransported::get();
+			reached.push(report_id);
+			ReachedTransported::set(reached);
+		}
+	}
+}
+
+impl waste_management::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityId = TestAuthId;
+	type StaleThreshold = ConstU64<STALE_THRESHOLD>;
+	type MaxStaleScan = ConstU32<8>;
+	type AutoExpiryBlocks = AutoExpiryBlocks;
+	type MaxExpiriesPerBlock = ConstU32<3>;
+	type DraftExpiry = ConstU64<DRAFT_EXPIRY>;
+	type MaxReportsPerAccount = ConstU32<10>;
+	type MaxActiveReports = MaxActiveReports;
+	type IndexedStatuses = IndexedStatuses;
+	type MaxLocationX = ConstU32<1_000>;
+	type MaxLocationY = ConstU32<500>;
+	type MaxServiceZones = ConstU32<4>;
+	type MaxEmergencyBatch = ConstU32<3>;
+	type MaxTypeNameLen = ConstU32<16>;
+	type MaxReportsPerBlock = MaxReportsPerBlock;
+	type MaxBatchSize = ConstU32<5>;
+	type MaxQueryBatch = ConstU32<8>;
+	type MaxSplit = ConstU32<3>;
+	type MaxReasonLen = ConstU32<32>;
+	type MaxContactLen = ConstU32<16>;
+	type MaxTagLen = ConstU32<16>;
+	type MaxNoteLen = ConstU32<32>;
+	type MaxNotes = ConstU32<3>;
+	type MaxHistoryLen = MaxHistoryLen;
+	type MaxLocationHistory = ConstU32<4>;
+	type MaxEventLog = ConstU32<6>;
+	type RecentActivityLen = ConstU32<4>;
+	type RouteHistoryMode = RouteHistoryMode;
+	type ExternalRefMode = ExternalRefMode;
+	type CellSize = ConstU32<100>;
+	type MaxPerCell = ConstU32<10>;
+	type MaxSearchRings = ConstU32<3>;
+	type DedupWindow = DedupWindow;
+	type MinTransitionDelay = MinTransitionDelay;
+	type SettlementTimeout = ConstU64<SETTLEMENT_TIMEOUT>;
+	type Currency = Balances;
+	type ReportDeposit = ConstU64<REPORT_DEPOSIT>;
+	type Slash = ();
+	type CompletionReward = CompletionReward;
+	type TreasuryCut = TreasuryCut;
+	type TreasuryAccount = ConstU64<TREASURY>;
+	type MaxComponents = ConstU32<4>;
+	type MaxWasteAmount = ConstU64<MAX_WASTE_AMOUNT>;
+	type MaxVolume = ConstU64<MAX_VOLUME>;
+	type DefaultMinAmount = ConstU64<1>;
+	type AmountDecimals = ConstU8<6>;
+	type SummaryInterval = ConstU64<SUMMARY_INTERVAL>;
+	type MinScoreToReport = MinScoreToReport;
+	type RequireAllowlist = RequireAllowlist;
+	type PrivacyMode = PrivacyMode;
+	type VerboseEvents = VerboseEvents;
+	type AutoStampCollector = AutoStampCollector;
+	type RequiredApprovals = RequiredApprovals;
+	type MaxApprovers = ConstU32<2>;
+	type MinOperators = MinOperators;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type ReportHook = TestReportHook;
+	type OnStatusReached = RecordTransported;
+	type WeightInfo = ();
+}
+
+/// Account registered as an operator in every test externality.
+pub const OPERATOR: u64 = 2;
+
+/// Account used as the collection crew in tests.
+pub const COLLECTOR: u64 = 4;
+
+/// Account the treasury's cut of completion rewards is credited to in tests.
+pub const TREASURY: u64 = 5;
+
+/// Waste types registered in every test externality.
+pub const PLASTIC: WasteType = WasteCategory::Plastic;
+pub const ORGANIC: WasteType = WasteCategory::Organic;
+pub const GLASS: WasteType = WasteCategory::Glass;
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	new_test_ext_with_types(vec![
+		(PLASTIC, b"plastic".to_vec()),
+		(ORGANIC, b"organic".to_vec()),
+		(GLASS, b"glass".to_vec()),
+	])
+}
+
+// Build genesis storage with a custom set of registered waste types.
+pub fn new_test_ext_with_types(waste_types: Vec<(WasteType, Vec<u8>)>) -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = GenesisConfig {
+		system: Default::default(),
+		balances: BalancesConfig { balances: (1..=5).map(|who| (who, INITIAL_BALANCE)).collect() },
+		waste_management: WasteManagementConfig { waste_types, ..Default::default() },
+	}
+	.build_storage()
+	.unwrap()
+	.into();
+	// Go past genesis block so events get deposited
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		crate::Operators::<Test>::insert(OPERATOR, ());
+	});
+	ext
+}
@@ -0,0 +1,7033 @@
+use crate::{
+	mock::*, Components, Error, Event, GeoPoint, ReportId, Severity, WasteAmount, WasteCategory,
+	WasteStatus, WasteType,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+
+/// Whether the pallet emitted an event matching `pattern`, whatever its `seq`.
+macro_rules! emitted {
+	($pattern:pat $(if $guard:expr)?) => {
+		System::events().iter().any(|record| {
+			matches!(record.event, RuntimeEvent::WasteManagement($pattern) $(if $guard)?)
+		})
+	};
+}
+
+fn components(items: &[(WasteType, WasteAmount)]) -> Components<Test> {
+	items.to_vec().try_into().unwrap()
+}
+
+fn create_report(who: u64, waste_type: WasteType, waste_amount: WasteAmount) -> ReportId {
+	assert_ok!(WasteManagement::create_waste_data(
+		RuntimeOrigin::signed(who),
+		components(&[(waste_type, waste_amount)]),
+		0,
+		GeoPoint { x: 10, y: 20 },
+		Severity::Low,
+		None,
+		None,
+		None,
+		None,
+		None
+	));
+	WasteManagement::next_report_id() - 1
+}
+
+fn set_status(
+	report_id: ReportId,
+	status: WasteStatus,
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	WasteManagement::update_waste_status(RuntimeOrigin::signed(OPERATOR), report_id, status, None)
+}
+
+fn assign_collector(report_id: ReportId) {
+	assert_ok!(WasteManagement::assign_collector(
+		RuntimeOrigin::signed(OPERATOR),
+		report_id,
+		COLLECTOR
+	));
+}
+
+fn collect(report_id: ReportId) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	assign_collector(report_id);
+	set_status(report_id, WasteStatus::Collected)
+}
+
+/// Walks a report forward through the lifecycle until it reaches `target`.
+fn advance_to(report_id: ReportId, target: WasteStatus) {
+	for status in [WasteStatus::Collected, WasteStatus::Transported, WasteStatus::Utilized] {
+		if status == WasteStatus::Collected {
+			assign_collector(report_id);
+		}
+		assert_ok!(set_status(report_id, status.clone()));
+		if status == target {
+			return
+		}
+	}
+}
+
+#[test]
+fn create_waste_data_works() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_eq!(report_id, 1);
+
+		let report = crate::WasteDataMap::<Test>::get(report_id).unwrap();
+		assert_eq!(report.status, WasteStatus::Reported);
+		assert_eq!(report.reporter, 1);
+		assert!(crate::WasteDataByStatus::<Test>::contains_key(WasteStatus::Reported, report_id));
+		System::assert_last_event(
+			Event::WasteDataCreated {
+				report_id,
+				reporter: 1,
+				severity: Severity::Low,
+				waste_type: GLASS,
+				waste_amount: 100,
+				volume: 0,
+				origin_tag_len: 0,
+				status: WasteStatus::Reported,
+				has_location: true,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn status_follows_the_full_lifecycle() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assign_collector(report_id);
+
+		for status in [WasteStatus::Collected, WasteStatus::Transported, WasteStatus::Utilized] {
+			assert_ok!(set_status(report_id, status.clone()));
+			assert_eq!(crate::WasteDataMap::<Test>::get(report_id).unwrap().status, status);
+			assert!(crate::WasteDataByStatus::<Test>::contains_key(status, report_id));
+		}
+		assert!(!crate::WasteDataByStatus::<Test>::contains_key(WasteStatus::Reported, report_id));
+		System::assert_last_event(
+			Event::WasteStatusUpdated {
+				report_id,
+				operator: OPERATOR,
+				old_status: WasteStatus::Transported,
+				new_status: WasteStatus::Utilized,
+				collector: Some(COLLECTOR),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn transition_table_allows_only_forward_steps() {
+	use WasteStatus::*;
+	let all = [Reported, Collected, Transported, Utilized, Cancelled];
+	let legal = [
+		(Reported, Collected),
+		(Collected, Transported),
+		(Transported, Utilized),
+		(Reported, Cancelled),
+		(Collected, Cancelled),
+		(Transported, Cancelled),
+	];
+
+	new_test_ext().execute_with(|| {
+		for from in all.iter() {
+			for to in all.iter() {
+				let expected = legal.contains(&(from.clone(), to.clone()));
+				// The genesis table and the built-in fallback must agree.
+				assert_eq!(
+					WasteManagement::is_valid_transition(from, to),
+					expected,
+					"{:?} -> {:?}",
+					from,
+					to
+				);
+				assert_eq!(
+					WasteManagement::is_default_transition(from, to),
+					expected,
+					"{:?} -> {:?}",
+					from,
+					to
+				);
+			}
+		}
+	});
+}
+
+#[test]
+fn illegal_transitions_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+
+		// Skipping a stage.
+		assert_noop!(
+			set_status(report_id, WasteStatus::Transported),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_noop!(
+			set_status(report_id, WasteStatus::Utilized),
+			Error::<Test>::InvalidStatusTransition
+		);
+
+		// Moving backwards from the end of the lifecycle.
+		assert_ok!(collect(report_id));
+		assert_ok!(set_status(report_id, WasteStatus::Transported));
+		assert_ok!(set_status(report_id, WasteStatus::Utilized));
+		assert_noop!(
+			set_status(report_id, WasteStatus::Reported),
+			Error::<Test>::InvalidStatusTransition
+		);
+	});
+}
+
+#[test]
+fn update_unknown_report_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(set_status(42, WasteStatus::Collected), Error::<Test>::ReportNotFound);
+	});
+}
+
+#[test]
+fn delete_waste_data_removes_report_from_all_maps() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_ok!(collect(report_id));
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+
+		assert!(crate::WasteDataMap::<Test>::get(report_id).is_none());
+		assert!(!crate::WasteDataByStatus::<Test>::contains_key(WasteStatus::Collected, report_id));
+		assert!(!crate::WasteDataByStatus::<Test>::contains_key(WasteStatus::Reported, report_id));
+		System::assert_last_event(
+			Event::WasteDataDeleted { report_id, remover: 1, seq: WasteManagement::event_seq() }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn only_reporter_can_delete() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+
+		assert_noop!(
+			WasteManagement::delete_waste_data(RuntimeOrigin::signed(2), report_id),
+			Error::<Test>::NotReportOwner
+		);
+		assert_noop!(
+			WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), 42),
+			Error::<Test>::ReportNotFound
+		);
+	});
+}
+
+#[test]
+fn status_update_refreshes_updated_at_only() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		let report = crate::WasteDataMap::<Test>::get(report_id).unwrap();
+		assert_eq!((report.created_at, report.updated_at), (1, 1));
+
+		System::set_block_number(5);
+		assert_ok!(collect(report_id));
+
+		let report = crate::WasteDataMap::<Test>::get(report_id).unwrap();
+		assert_eq!((report.created_at, report.updated_at), (1, 5));
+		let mirrored =
+			crate::WasteDataByStatus::<Test>::get(WasteStatus::Collected, report_id).unwrap();
+		assert_eq!(mirrored, report);
+	});
+}
+
+#[test]
+fn status_updates_wait_for_enough_operators() {
+	new_test_ext().execute_with(|| {
+		MinOperators::set(3);
+		let report_id = create_report(1, GLASS, 100);
+		assign_collector(report_id);
+		let update = || {
+			WasteManagement::update_waste_status(
+				RuntimeOrigin::signed(OPERATOR),
+				report_id,
+				WasteStatus::Collected,
+				None,
+			)
+		};
+
+		assert_noop!(update(), Error::<Test>::InsufficientOperators);
+		assert_ok!(WasteManagement::add_operator(RuntimeOrigin::root(), 1));
+		// Registering the same operator twice doesn't count it twice.
+		assert_ok!(WasteManagement::add_operator(RuntimeOrigin::root(), 1));
+		assert_noop!(update(), Error::<Test>::InsufficientOperators);
+
+		assert_ok!(WasteManagement::add_operator(RuntimeOrigin::root(), 3));
+		assert_ok!(update());
+		assert_eq!(WasteManagement::status_of(report_id), Some(WasteStatus::Collected));
+
+		// Dropping below the minimum blocks updates again.
+		assert_ok!(WasteManagement::remove_operator(RuntimeOrigin::root(), 3));
+		assert_noop!(
+			WasteManagement::update_waste_status(
+				RuntimeOrigin::signed(OPERATOR),
+				report_id,
+				WasteStatus::Transported,
+				None
+			),
+			Error::<Test>::InsufficientOperators
+		);
+		MinOperators::set(1);
+	});
+}
+
+#[test]
+fn only_operators_can_update_status() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+
+		assert_noop!(
+			WasteManagement::update_waste_status(
+				RuntimeOrigin::signed(1),
+				report_id,
+				WasteStatus::Collected,
+				None
+			),
+			Error::<Test>::NotAuthorizedForStatus
+		);
+
+		assign_collector(report_id);
+		assert_ok!(WasteManagement::add_operator(RuntimeOrigin::root(), 1));
+		System::assert_last_event(
+			Event::OperatorAdded { operator: 1, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_ok!(WasteManagement::update_waste_status(
+			RuntimeOrigin::signed(1),
+			report_id,
+			WasteStatus::Collected,
+			None
+		));
+
+		assert_ok!(WasteManagement::remove_operator(RuntimeOrigin::root(), 1));
+		System::assert_last_event(
+			Event::OperatorRemoved { operator: 1, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_noop!(
+			WasteManagement::update_waste_status(
+				RuntimeOrigin::signed(1),
+				report_id,
+				WasteStatus::Transported,
+				None
+			),
+			Error::<Test>::NotAuthorizedForStatus
+		);
+	});
+}
+
+#[test]
+fn operator_management_requires_root() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			WasteManagement::add_operator(RuntimeOrigin::signed(1), 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			WasteManagement::remove_operator(RuntimeOrigin::signed(1), OPERATOR),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn storage_is_bounded() {
+	use frame_support::traits::StorageInfoTrait;
+
+	let info = crate::WasteDataMap::<Test>::storage_info();
+	assert!(!info.is_empty());
+	assert!(info.iter().all(|i| i.max_size.is_some()));
+
+	let info = crate::WasteDataByStatus::<Test>::storage_info();
+	assert!(info.iter().all(|i| i.max_size.is_some()));
+}
+
+#[test]
+fn reports_with_status_returns_only_matching_reports() {
+	new_test_ext().execute_with(|| {
+		let first = create_report(1, GLASS, 100);
+		let second = create_report(1, GLASS, 200);
+		let third = create_report(1, GLASS, 300);
+		assert_ok!(collect(second));
+
+		let mut reported: Vec<ReportId> =
+			WasteManagement::reports_with_status(WasteStatus::Reported)
+				.into_iter()
+				.map(|report| report.report_id)
+				.collect();
+		reported.sort();
+		assert_eq!(reported, vec![first, third]);
+
+		let collected = WasteManagement::reports_with_status(WasteStatus::Collected);
+		assert_eq!(collected.len(), 1);
+		assert_eq!(collected[0].report_id, second);
+		assert!(WasteManagement::reports_with_status(WasteStatus::Utilized).is_empty());
+	});
+}
+
+#[test]
+fn report_iterators_can_be_filtered_and_capped() {
+	new_test_ext().execute_with(|| {
+		for amount in 1..=6 {
+			create_report(1, if amount % 2 == 0 { GLASS } else { PLASTIC }, amount);
+		}
+		assert_ok!(collect(2));
+		assert_ok!(collect(3));
+
+		let glass: Vec<(ReportId, crate::WasteData<Test>)> = WasteManagement::iter_reports()
+			.filter(|(_, report)| report.waste_type == GLASS)
+			.take(2)
+			.collect();
+		assert_eq!(glass.len(), 2);
+		for (report_id, report) in glass {
+			assert_eq!(report.waste_type, GLASS);
+			assert_eq!(WasteManagement::get_report(report_id), Some(report));
+		}
+
+		let mut reported: Vec<ReportId> =
+			WasteManagement::iter_reports_by_status(WasteStatus::Reported)
+				.filter(|(_, report)| report.waste_type == GLASS)
+				.map(|(report_id, _)| report_id)
+				.take(2)
+				.collect();
+		reported.sort();
+		assert_eq!(reported, vec![4, 6]);
+		assert_eq!(WasteManagement::iter_reports_by_status(WasteStatus::Collected).count(), 2);
+	});
+}
+
+#[test]
+fn list_reports_pages_in_id_order() {
+	new_test_ext().execute_with(|| {
+		for amount in 1..=10 {
+			create_report(1, GLASS, amount);
+		}
+
+		let mut seen = Vec::new();
+		let mut cursor = None;
+		let mut page_sizes = Vec::new();
+		loop {
+			let (page, next) = WasteManagement::list_reports(cursor, 3);
+			page_sizes.push(page.len());
+			seen.extend(page.into_iter().map(|report| report.report_id));
+			if next.is_none() {
+				break
+			}
+			cursor = next;
+		}
+
+		assert_eq!(page_sizes, vec![3, 3, 3, 1]);
+		assert_eq!(seen, (1..=10).collect::<Vec<ReportId>>());
+	});
+}
+
+#[test]
+fn list_reports_stops_probing_at_the_cap() {
+	new_test_ext().execute_with(|| {
+		let first = create_report(1, GLASS, 1);
+		// Every id in between belonged to a report since deleted.
+		crate::NextReportId::<Test>::put(2 * crate::MAX_ID_PROBES as ReportId);
+		let far = create_report(1, GLASS, 2);
+
+		let (page, cursor) = WasteManagement::list_reports(None, 10);
+		assert_eq!(page.iter().map(|report| report.report_id).collect::<Vec<_>>(), vec![first]);
+		assert_eq!(cursor, Some(crate::MAX_ID_PROBES as ReportId));
+		let (page, cursor) = WasteManagement::list_reports(cursor, 10);
+		assert_eq!(page.iter().map(|report| report.report_id).collect::<Vec<_>>(), vec![far]);
+		assert_eq!(cursor, None);
+	});
+}
+
+#[test]
+fn report_chunk_advances_the_cursor_and_hashes_the_page() {
+	new_test_ext().execute_with(|| {
+		for amount in 1..=5 {
+			create_report(1, GLASS, amount);
+		}
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), 3));
+		let ids = |chunk: &[crate::WasteData<Test>]| {
+			chunk.iter().map(|report| report.report_id).collect::<Vec<_>>()
+		};
+
+		let (first, cursor, first_hash) = WasteManagement::report_chunk(None, 2, None);
+		assert_eq!((ids(&first), cursor), (vec![1, 2], Some(2)));
+		let (second, cursor, second_hash) =
+			WasteManagement::report_chunk(cursor, 2, Some(first_hash));
+		assert_eq!((ids(&second), cursor), (vec![4, 5], None));
+		assert_ne!(first_hash, second_hash);
+		// A short page is the last one.
+		let (_, cursor, _) = WasteManagement::report_chunk(Some(1), 4, None);
+		assert_eq!(cursor, None);
+
+		// The same reports give the same hash, but any change to one of them doesn't.
+		assert_eq!(WasteManagement::report_chunk(Some(2), 2, Some(first_hash)).2, second_hash);
+		crate::WasteDataMap::<Test>::mutate(5, |report| report.as_mut().unwrap().volume += 1);
+		assert_ne!(WasteManagement::report_chunk(Some(2), 2, Some(first_hash)).2, second_hash);
+		crate::WasteDataMap::<Test>::mutate(5, |report| report.as_mut().unwrap().volume -= 1);
+
+		// Altering a report in the first page changes the hash of the second too.
+		crate::WasteDataMap::<Test>::mutate(1, |report| report.as_mut().unwrap().volume += 1);
+		let (_, _, altered_first) = WasteManagement::report_chunk(None, 2, None);
+		assert_ne!(altered_first, first_hash);
+		let (page, _, altered_second) =
+			WasteManagement::report_chunk(Some(2), 2, Some(altered_first));
+		assert_eq!(page, second);
+		assert_ne!(altered_second, second_hash);
+
+		// Pages are capped at `MaxQueryBatch`.
+		for amount in 6..=12 {
+			create_report(3, GLASS, amount);
+		}
+		let (page, cursor, _) = WasteManagement::report_chunk(None, 100, None);
+		assert_eq!((page.len(), cursor), (8, Some(9)));
+	});
+}
+
+#[test]
+fn list_reports_skips_deleted_ids() {
+	new_test_ext().execute_with(|| {
+		for amount in 1..=4 {
+			create_report(1, GLASS, amount);
+		}
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), 2));
+
+		let ids: Vec<ReportId> = WasteManagement::list_reports(None, 10)
+			.0
+			.into_iter()
+			.map(|r| r.report_id)
+			.collect();
+		assert_eq!(ids, vec![1, 3, 4]);
+		assert_eq!(WasteManagement::list_reports(Some(1), 1).0[0].report_id, 3);
+	});
+}
+
+#[test]
+fn reports_are_indexed_by_reporter() {
+	new_test_ext().execute_with(|| {
+		let first = create_report(1, GLASS, 100);
+		let other = create_report(3, GLASS, 100);
+		let second = create_report(1, GLASS, 100);
+
+		assert_eq!(WasteManagement::reports_of(&1), vec![first, second]);
+		assert_eq!(WasteManagement::reports_of(&3), vec![other]);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), first));
+		assert_eq!(WasteManagement::reports_of(&1), vec![second]);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(3), other));
+		assert!(!crate::ReportsByReporter::<Test>::contains_key(3));
+	});
+}
+
+#[test]
+fn reporter_index_is_bounded() {
+	new_test_ext().execute_with(|| {
+		// MaxReportsPerAccount is 10 in the mock runtime.
+		for _ in 0..10 {
+			create_report(1, GLASS, 100);
+		}
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 100)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::TooManyReports
+		);
+
+		// Freeing a slot allows filing again.
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), 1));
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(GLASS, 100)]),
+			0,
+			GeoPoint { x: 10, y: 20 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+	});
+}
+
+#[test]
+fn reports_can_be_filed_without_a_location() {
+	new_test_ext().execute_with(|| {
+		let zone = (GeoPoint { x: 0, y: 0 }, GeoPoint { x: 50, y: 50 });
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 1, Some(zone)));
+		let located = create_report(1, GLASS, 100);
+		assert!(emitted!(
+			Event::WasteDataCreated { report_id, has_location: true, .. } if report_id == located
+		));
+
+		assert_ok!(WasteManagement::create_waste_data_no_location(
+			RuntimeOrigin::signed(1),
+			components(&[(PLASTIC, 3)]),
+			0,
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		let indoor = WasteManagement::next_report_id() - 1;
+		assert!(emitted!(
+			Event::WasteDataCreated { report_id, has_location: false, .. } if report_id == indoor
+		));
+
+		let report = WasteManagement::waste_data(indoor).unwrap();
+		assert_eq!((report.location, report.zone_id), (None, None));
+		assert!(crate::ReportsByCell::<Test>::iter_values().all(|ids| !ids.contains(&indoor)));
+		assert_eq!(WasteManagement::reports_in_cell((0, 0)), vec![located]);
+		assert_eq!(crate::TotalAmountByZone::<Test>::get(1), 100);
+		assert!(crate::LocationHistory::<Test>::get(indoor).is_none());
+		assert_eq!(WasteManagement::reports_of(&1), vec![located, indoor]);
+		assert_ok!(WasteManagement::do_try_state());
+
+		// A location can be given later, which puts the report on the map.
+		assert_ok!(WasteManagement::update_location(
+			RuntimeOrigin::signed(1),
+			indoor,
+			GeoPoint { x: 20, y: 20 }
+		));
+		assert_eq!(WasteManagement::reports_in_cell((0, 0)), vec![located, indoor]);
+		assert_eq!(crate::TotalAmountByZone::<Test>::get(1), 103);
+	});
+}
+
+#[test]
+fn location_on_the_boundary_is_accepted() {
+	new_test_ext().execute_with(|| {
+		// The mock service area is 1_000 x 500.
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(GLASS, 100)]),
+			0,
+			GeoPoint { x: 1_000, y: 500 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(WasteManagement::waste_data_count(), 1);
+	});
+}
+
+#[test]
+fn location_out_of_bounds_is_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 100)]),
+				0,
+				GeoPoint { x: 1_001, y: 500 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::LocationOutOfBounds
+		);
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 100)]),
+				0,
+				GeoPoint { x: 1_000, y: 501 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::LocationOutOfBounds
+		);
+		assert_eq!(WasteManagement::waste_data_count(), 0);
+	});
+}
+
+#[test]
+fn register_waste_type_works() {
+	new_test_ext().execute_with(|| {
+		let name: frame_support::BoundedVec<u8, _> = b"textiles".to_vec().try_into().unwrap();
+		assert_ok!(WasteManagement::register_waste_type(
+			RuntimeOrigin::root(),
+			WasteCategory::Other(7),
+			name.clone()
+		));
+		assert_eq!(
+			crate::WasteTypeRegistry::<Test>::get(WasteCategory::Other(7)),
+			Some(name.clone())
+		);
+		System::assert_last_event(
+			Event::WasteTypeRegistered {
+				waste_type: WasteCategory::Other(7),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			WasteManagement::register_waste_type(
+				RuntimeOrigin::signed(1),
+				WasteCategory::Other(8),
+				name
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn waste_types_can_be_registered_in_a_batch() {
+	new_test_ext().execute_with(|| {
+		let entry = |id, name: &[u8]| (WasteCategory::Other(id), name.to_vec().try_into().unwrap());
+		let batch: frame_support::BoundedVec<_, _> =
+			vec![entry(7, b"textiles"), entry(8, b"tyres"), entry(9, b"batteries")]
+				.try_into()
+				.unwrap();
+		assert_noop!(
+			WasteManagement::register_waste_types_batch(RuntimeOrigin::signed(1), batch.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(WasteManagement::register_waste_types_batch(RuntimeOrigin::root(), batch));
+		System::assert_last_event(
+			Event::WasteTypesBatchRegistered { count: 3, seq: WasteManagement::event_seq() }.into(),
+		);
+		for id in 7..=9 {
+			assert!(crate::WasteTypeRegistry::<Test>::contains_key(WasteCategory::Other(id)));
+		}
+	});
+}
+
+#[test]
+fn waste_type_batch_with_a_duplicate_registers_nothing() {
+	new_test_ext().execute_with(|| {
+		let entry = |id, name: &[u8]| (WasteCategory::Other(id), name.to_vec().try_into().unwrap());
+		let clashing: frame_support::BoundedVec<_, _> =
+			vec![entry(7, b"textiles"), (PLASTIC, b"plastics".to_vec().try_into().unwrap())]
+				.try_into()
+				.unwrap();
+		assert_noop!(
+			WasteManagement::register_waste_types_batch(RuntimeOrigin::root(), clashing),
+			Error::<Test>::DuplicateWasteType
+		);
+		let repeated: frame_support::BoundedVec<_, _> =
+			vec![entry(7, b"textiles"), entry(7, b"cloth")].try_into().unwrap();
+		assert_noop!(
+			WasteManagement::register_waste_types_batch(RuntimeOrigin::root(), repeated),
+			Error::<Test>::DuplicateWasteType
+		);
+
+		assert!(!crate::WasteTypeRegistry::<Test>::contains_key(WasteCategory::Other(7)));
+		assert_eq!(
+			crate::WasteTypeRegistry::<Test>::get(PLASTIC),
+			Some(b"plastic".to_vec().try_into().unwrap())
+		);
+	});
+}
+
+#[test]
+fn reports_require_a_registered_waste_type() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(WasteCategory::Other(7), 100)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::UnknownWasteType
+		);
+
+		let name = b"textiles".to_vec().try_into().unwrap();
+		assert_ok!(WasteManagement::register_waste_type(
+			RuntimeOrigin::root(),
+			WasteCategory::Other(7),
+			name
+		));
+		let report_id = create_report(1, WasteCategory::Other(7), 100);
+		assert_eq!(
+			crate::WasteDataMap::<Test>::get(report_id).unwrap().waste_type,
+			WasteCategory::Other(7)
+		);
+	});
+}
+
+#[test]
+fn every_waste_category_can_be_reported() {
+	use codec::{Decode, Encode};
+	use WasteCategory::*;
+
+	let categories = [Plastic, Organic, Glass, Metal, Ewaste, Hazardous, Other(42)];
+	new_test_ext_with_types(categories.iter().map(|category| (*category, b"x".to_vec())).collect())
+		.execute_with(|| {
+			for (amount, category) in (1..).zip(categories) {
+				let report_id = create_report(1, category, amount * 10);
+				assert_eq!(
+					WasteManagement::waste_data(report_id).unwrap().components.into_inner(),
+					vec![(category, amount * 10)]
+				);
+				assert_eq!(WasteManagement::total_amount_of_type(category), amount * 10);
+			}
+			// Custom codes are told apart from each other and from the named categories.
+			assert_eq!(WasteManagement::total_amount_of_type(Other(43)), 0);
+			assert_ne!(Other(42).encode(), Other(43).encode());
+			assert_eq!(WasteCategory::decode(&mut &Other(42).encode()[..]), Ok(Other(42)));
+		});
+}
+
+#[test]
+fn legacy_waste_types_map_onto_categories() {
+	use WasteCategory::*;
+
+	let mapped: Vec<_> = (0..=6).map(WasteCategory::from_legacy).collect();
+	assert_eq!(mapped, vec![Plastic, Organic, Glass, Metal, Ewaste, Hazardous, Other(6)]);
+	assert_eq!(WasteCategory::from_legacy(u32::MAX), Other(u32::MAX));
+}
+
+#[test]
+fn batch_creation_assigns_contiguous_ids() {
+	new_test_ext().execute_with(|| {
+		create_report(1, GLASS, 100);
+
+		let reports = vec![
+			(PLASTIC, 10, 0, GeoPoint { x: 1, y: 1 }, Severity::Low),
+			(ORGANIC, 20, 0, GeoPoint { x: 2, y: 2 }, Severity::Low),
+			(GLASS, 30, 0, GeoPoint { x: 3, y: 3 }, Severity::Low),
+		]
+		.try_into()
+		.unwrap();
+		assert_ok!(WasteManagement::create_waste_data_batch(RuntimeOrigin::signed(1), reports));
+
+		assert_eq!(WasteManagement::waste_data_count(), 4);
+		for (report_id, amount) in [(2, 10), (3, 20), (4, 30)] {
+			let report = crate::WasteDataMap::<Test>::get(report_id).unwrap();
+			assert_eq!(report.waste_amount, amount);
+			assert_eq!(report.reporter, 1);
+		}
+		assert_eq!(WasteManagement::reports_of(&1), vec![1, 2, 3, 4]);
+		System::assert_last_event(
+			Event::WasteDataBatchCreated {
+				first_id: 2,
+				count: 3,
+				reporter: 1,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn failing_batch_item_rolls_back_the_whole_batch() {
+	new_test_ext().execute_with(|| {
+		let reports = vec![
+			(PLASTIC, 10, 0, GeoPoint { x: 1, y: 1 }, Severity::Low),
+			(WasteCategory::Other(99), 20, 0, GeoPoint { x: 2, y: 2 }, Severity::Low),
+			(GLASS, 30, 0, GeoPoint { x: 3, y: 3 }, Severity::Low),
+		]
+		.try_into()
+		.unwrap();
+		assert_noop!(
+			WasteManagement::create_waste_data_batch(RuntimeOrigin::signed(1), reports),
+			Error::<Test>::UnknownWasteType
+		);
+		assert_eq!(WasteManagement::waste_data_count(), 0);
+		assert!(crate::WasteDataMap::<Test>::get(1).is_none());
+
+		assert_noop!(
+			WasteManagement::create_waste_data_batch(RuntimeOrigin::signed(1), Default::default()),
+			Error::<Test>::EmptyBatch
+		);
+	});
+}
+
+#[test]
+fn update_waste_amount_updates_both_maps() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_ok!(collect(report_id));
+
+		assert_ok!(WasteManagement::update_waste_amount(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			120
+		));
+
+		assert_eq!(crate::WasteDataMap::<Test>::get(report_id).unwrap().waste_amount, 120);
+		let mirrored =
+			crate::WasteDataByStatus::<Test>::get(WasteStatus::Collected, report_id).unwrap();
+		assert_eq!(mirrored.waste_amount, 120);
+		assert_eq!(mirrored.components, components(&[(GLASS, 120)]));
+		System::assert_last_event(
+			Event::WasteAmountUpdated {
+				report_id,
+				old_amount: 100,
+				new_amount: 120,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn update_waste_amount_checks_report_and_operator() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+
+		assert_noop!(
+			WasteManagement::update_waste_amount(RuntimeOrigin::signed(OPERATOR), 42, 120),
+			Error::<Test>::ReportNotFound
+		);
+		assert_noop!(
+			WasteManagement::update_waste_amount(RuntimeOrigin::signed(1), report_id, 120),
+			Error::<Test>::NotAuthorizedOperator
+		);
+	});
+}
+
+#[test]
+fn totals_by_status_follow_reports() {
+	new_test_ext().execute_with(|| {
+		let first = create_report(1, GLASS, 100);
+		let second = create_report(1, GLASS, 50);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 150);
+
+		assert_ok!(collect(first));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 50);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), 100);
+
+		assert_ok!(WasteManagement::update_waste_amount(
+			RuntimeOrigin::signed(OPERATOR),
+			first,
+			80
+		));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), 80);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), second));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 0);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), first));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), 0);
+	});
+}
+
+fn reason(text: &[u8]) -> frame_support::BoundedVec<u8, <Test as crate::Config>::MaxReasonLen> {
+	text.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn reporter_or_operator_can_cancel() {
+	new_test_ext().execute_with(|| {
+		let by_reporter = create_report(1, GLASS, 100);
+		let by_operator = create_report(1, GLASS, 50);
+
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			by_reporter,
+			reason(b"duplicate")
+		));
+		System::assert_last_event(
+			Event::ReportCancelled {
+				report_id: by_reporter,
+				reason_len: 9,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_ok!(collect(by_operator));
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(OPERATOR),
+			by_operator,
+			reason(b"empty site")
+		));
+
+		for report_id in [by_reporter, by_operator] {
+			let report = crate::WasteDataMap::<Test>::get(report_id).unwrap();
+			assert_eq!(report.status, WasteStatus::Cancelled);
+			assert!(crate::WasteDataByStatus::<Test>::contains_key(
+				WasteStatus::Cancelled,
+				report_id
+			));
+		}
+		assert_eq!(
+			crate::CancellationReasons::<Test>::get(by_reporter),
+			Some(reason(b"duplicate"))
+		);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Cancelled), 150);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 0);
+	});
+}
+
+#[test]
+fn cancel_requires_reporter_or_operator() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_noop!(
+			WasteManagement::cancel_report(RuntimeOrigin::signed(3), report_id, reason(b"no")),
+			Error::<Test>::NotReportOwner
+		);
+	});
+}
+
+#[test]
+fn cancelled_reports_cannot_transition() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			report_id,
+			reason(b"mistake")
+		));
+
+		assert_noop!(
+			set_status(report_id, WasteStatus::Collected),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_noop!(
+			WasteManagement::cancel_report(RuntimeOrigin::signed(1), report_id, reason(b"again")),
+			Error::<Test>::StatusUnchanged
+		);
+
+		// Utilized reports can't be cancelled either, and cancelling needs cancel_report.
+		let done = create_report(1, GLASS, 100);
+		assign_collector(done);
+		assert_noop!(
+			set_status(done, WasteStatus::Cancelled),
+			Error::<Test>::InvalidStatusTransition
+		);
+		for status in [WasteStatus::Collected, WasteStatus::Transported, WasteStatus::Utilized] {
+			assert_ok!(set_status(done, status));
+		}
+		assert_noop!(
+			WasteManagement::cancel_report(RuntimeOrigin::signed(1), done, reason(b"late")),
+			Error::<Test>::InvalidStatusTransition
+		);
+	});
+}
+
+#[test]
+fn assigned_collector_allows_collection() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_eq!(WasteManagement::collector_of(report_id), None);
+
+		assign_collector(report_id);
+		System::assert_last_event(
+			Event::CollectorAssigned {
+				report_id,
+				collector: COLLECTOR,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_eq!(WasteManagement::collector_of(report_id), Some(COLLECTOR));
+		assert_ok!(set_status(report_id, WasteStatus::Collected));
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+		assert_eq!(WasteManagement::collector_of(report_id), None);
+	});
+}
+
+#[test]
+fn collection_without_assignment_fails() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_noop!(
+			set_status(report_id, WasteStatus::Collected),
+			Error::<Test>::NoCollectorAssigned
+		);
+
+		assert_noop!(
+			WasteManagement::assign_collector(RuntimeOrigin::signed(1), report_id, COLLECTOR),
+			Error::<Test>::NotAuthorizedOperator
+		);
+		assert_noop!(
+			WasteManagement::assign_collector(RuntimeOrigin::signed(OPERATOR), 42, COLLECTOR),
+			Error::<Test>::ReportNotFound
+		);
+	});
+}
+
+#[test]
+fn flags_are_set_and_cleared_without_touching_other_bits() {
+	use crate::{FLAG_HAZARDOUS, FLAG_RECYCLABLE, FLAG_REQUIRES_PERMIT, FLAG_WET};
+
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		let flags = || WasteManagement::waste_data(report_id).unwrap().flags;
+		assert_eq!(flags(), 0);
+		assert_noop!(
+			WasteManagement::update_flags(RuntimeOrigin::signed(1), report_id, FLAG_WET, 0),
+			Error::<Test>::NotAuthorizedOperator
+		);
+		assert_noop!(
+			WasteManagement::update_flags(RuntimeOrigin::signed(OPERATOR), 42, FLAG_WET, 0),
+			Error::<Test>::ReportNotFound
+		);
+
+		assert_ok!(WasteManagement::update_flags(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			FLAG_HAZARDOUS | FLAG_WET,
+			0
+		));
+		System::assert_last_event(
+			Event::FlagsUpdated {
+				report_id,
+				flags: FLAG_HAZARDOUS | FLAG_WET,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert!(WasteManagement::is_hazardous(report_id));
+		assert!(WasteManagement::is_wet(report_id));
+		assert!(!WasteManagement::is_recyclable(report_id));
+
+		// Setting one bit and clearing another leaves the rest alone.
+		assert_ok!(WasteManagement::update_flags(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			FLAG_REQUIRES_PERMIT,
+			FLAG_WET | FLAG_RECYCLABLE
+		));
+		assert_eq!(flags(), FLAG_HAZARDOUS | FLAG_REQUIRES_PERMIT);
+		assert!(WasteManagement::requires_permit(report_id));
+		assert!(!WasteManagement::is_wet(report_id));
+
+		assert_ok!(WasteManagement::update_flags(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			0,
+			FLAG_HAZARDOUS
+		));
+		assert_eq!(flags(), FLAG_REQUIRES_PERMIT);
+		assert!(!WasteManagement::is_hazardous(report_id));
+		assert!(!WasteManagement::is_hazardous(42));
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn collecting_keeps_a_preassigned_collector() {
+	new_test_ext().execute_with(|| {
+		AutoStampCollector::set(true);
+		let report_id = create_report(1, GLASS, 100);
+		assign_collector(report_id);
+
+		assert_ok!(set_status(report_id, WasteStatus::Collected));
+		assert_eq!(WasteManagement::collector_of(report_id), Some(COLLECTOR));
+		assert_eq!(
+			crate::WasteDataByStatus::<Test>::get(WasteStatus::Collected, report_id)
+				.unwrap()
+				.collector,
+			Some(COLLECTOR)
+		);
+		System::assert_last_event(
+			Event::WasteStatusUpdated {
+				report_id,
+				operator: OPERATOR,
+				old_status: WasteStatus::Reported,
+				new_status: WasteStatus::Collected,
+				collector: Some(COLLECTOR),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn collecting_stamps_the_operator_as_collector_if_enabled() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_noop!(
+			set_status(report_id, WasteStatus::Collected),
+			Error::<Test>::NoCollectorAssigned
+		);
+
+		AutoStampCollector::set(true);
+		assert_ok!(set_status(report_id, WasteStatus::Collected));
+		assert_eq!(WasteManagement::collector_of(report_id), Some(OPERATOR));
+		System::assert_last_event(
+			Event::WasteStatusUpdated {
+				report_id,
+				operator: OPERATOR,
+				old_status: WasteStatus::Reported,
+				new_status: WasteStatus::Collected,
+				collector: Some(OPERATOR),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn status_update_event_carries_the_transition() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_ok!(collect(report_id));
+		System::assert_last_event(
+			Event::WasteStatusUpdated {
+				report_id,
+				operator: OPERATOR,
+				old_status: WasteStatus::Reported,
+				new_status: WasteStatus::Collected,
+				collector: Some(COLLECTOR),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn operator_actions_are_counted_per_operator() {
+	new_test_ext().execute_with(|| {
+		let first = create_report(1, GLASS, 100);
+		let second = create_report(1, GLASS, 100);
+		assert_ok!(collect(first));
+		assert_ok!(set_status(first, WasteStatus::Transported));
+		assert_eq!(WasteManagement::actions_of(&OPERATOR), 3);
+
+		assert_ok!(WasteManagement::add_operator(RuntimeOrigin::root(), 3));
+		assert_ok!(WasteManagement::assign_collector(RuntimeOrigin::signed(3), second, COLLECTOR));
+		assert_ok!(WasteManagement::update_waste_status(
+			RuntimeOrigin::signed(3),
+			second,
+			WasteStatus::Collected,
+			None
+		));
+		assert_ok!(WasteManagement::update_waste_amount(RuntimeOrigin::signed(3), second, 90));
+		assert_eq!(WasteManagement::actions_of(&3), 3);
+		assert_eq!(WasteManagement::actions_of(&OPERATOR), 3);
+
+		// Failed calls don't count, and reporters aren't operators.
+		assert_noop!(
+			set_status(first, WasteStatus::Reported),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_eq!(WasteManagement::actions_of(&OPERATOR), 3);
+		assert_eq!(WasteManagement::actions_of(&1), 0);
+	});
+}
+
+#[test]
+fn minimal_events_carry_only_the_report_id() {
+	new_test_ext().execute_with(|| {
+		VerboseEvents::set(false);
+		let report_id = create_report(1, GLASS, 100);
+		System::assert_last_event(
+			Event::WasteDataCreatedMinimal { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_ok!(collect(report_id));
+		System::assert_last_event(
+			Event::WasteStatusUpdatedMinimal { report_id, seq: WasteManagement::event_seq() }
+				.into(),
+		);
+		assert!(!emitted!(Event::WasteDataCreated { .. }));
+		assert!(!emitted!(Event::WasteStatusUpdated { .. }));
+
+		// The details are still on file, and verbose mode brings the full events back.
+		assert_eq!(WasteManagement::waste_data(report_id).unwrap().status, WasteStatus::Collected);
+		VerboseEvents::set(true);
+		assert_ok!(set_status(report_id, WasteStatus::Transported));
+		System::assert_last_event(
+			Event::WasteStatusUpdated {
+				report_id,
+				operator: OPERATOR,
+				old_status: WasteStatus::Collected,
+				new_status: WasteStatus::Transported,
+				collector: Some(COLLECTOR),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert!(!emitted!(Event::WasteDataCreated { .. }));
+	});
+}
+
+#[test]
+fn no_op_status_update_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		System::reset_events();
+
+		assert_noop!(
+			set_status(report_id, WasteStatus::Reported).map_err(|e| e.error),
+			Error::<Test>::StatusUnchanged
+		);
+		assert!(System::events().is_empty());
+
+		assert_ok!(collect(report_id));
+		assert_noop!(
+			set_status(report_id, WasteStatus::Collected).map_err(|e| e.error),
+			Error::<Test>::StatusUnchanged
+		);
+	});
+}
+
+#[test]
+fn transfer_report_moves_ownership() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		let kept = create_report(1, GLASS, 100);
+
+		assert_ok!(WasteManagement::transfer_report(RuntimeOrigin::signed(1), report_id, 3));
+		System::assert_last_event(
+			Event::ReportTransferred {
+				report_id,
+				from: 1,
+				to: 3,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		assert_eq!(crate::WasteDataMap::<Test>::get(report_id).unwrap().reporter, 3);
+		let mirrored =
+			crate::WasteDataByStatus::<Test>::get(WasteStatus::Reported, report_id).unwrap();
+		assert_eq!(mirrored.reporter, 3);
+		assert_eq!(WasteManagement::reports_of(&1), vec![kept]);
+		assert_eq!(WasteManagement::reports_of(&3), vec![report_id]);
+
+		// The new owner now controls the report.
+		assert_noop!(
+			WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id),
+			Error::<Test>::NotReportOwner
+		);
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(3), report_id));
+	});
+}
+
+#[test]
+fn only_reporter_can_transfer() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_noop!(
+			WasteManagement::transfer_report(RuntimeOrigin::signed(3), report_id, 3),
+			Error::<Test>::NotReportOwner
+		);
+		assert_noop!(
+			WasteManagement::transfer_report(RuntimeOrigin::signed(1), 42, 3),
+			Error::<Test>::ReportNotFound
+		);
+	});
+}
+
+#[test]
+fn genesis_registers_waste_types() {
+	new_test_ext_with_types(vec![
+		(WasteCategory::Other(10), b"paper".to_vec()),
+		(WasteCategory::Metal, b"metal".to_vec()),
+	])
+	.execute_with(|| {
+		assert_eq!(
+			crate::WasteTypeRegistry::<Test>::get(WasteCategory::Other(10))
+				.unwrap()
+				.into_inner(),
+			b"paper"
+		);
+		assert_eq!(
+			crate::WasteTypeRegistry::<Test>::get(WasteCategory::Metal)
+				.unwrap()
+				.into_inner(),
+			b"metal"
+		);
+		assert!(crate::WasteTypeRegistry::<Test>::get(PLASTIC).is_none());
+	});
+	new_test_ext_with_types(Vec::new()).execute_with(|| {
+		assert_eq!(crate::WasteTypeRegistry::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn all_waste_types_lists_the_registry_in_order() {
+	new_test_ext_with_types(Vec::new()).execute_with(|| {
+		assert!(WasteManagement::all_waste_types().is_empty());
+		for (waste_type, name) in [
+			(WasteCategory::Other(9), &b"textiles"[..]),
+			(WasteCategory::Hazardous, b"hazardous"),
+			(WasteCategory::Glass, b"glass"),
+		] {
+			assert_ok!(WasteManagement::register_waste_type(
+				RuntimeOrigin::root(),
+				waste_type,
+				name.to_vec().try_into().unwrap()
+			));
+		}
+
+		assert_eq!(
+			WasteManagement::all_waste_types(),
+			vec![
+				(WasteCategory::Glass, b"glass".to_vec()),
+				(WasteCategory::Hazardous, b"hazardous".to_vec()),
+				(WasteCategory::Other(9), b"textiles".to_vec()),
+			]
+		);
+	});
+}
+
+#[test]
+fn deposit_is_reserved_on_create_and_returned_on_delete() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_eq!(Balances::reserved_balance(1), REPORT_DEPOSIT);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - REPORT_DEPOSIT);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn deposit_is_returned_on_utilization() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		// The deposit changes hands with the report.
+		assert_ok!(WasteManagement::transfer_report(RuntimeOrigin::signed(1), report_id, 3));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+
+		assert_ok!(collect(report_id));
+		assert_ok!(set_status(report_id, WasteStatus::Transported));
+		assert_eq!(Balances::reserved_balance(3), REPORT_DEPOSIT);
+		assert_ok!(set_status(report_id, WasteStatus::Utilized));
+
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE);
+		// The reward is credited to the current reporter.
+		assert_eq!(crate::PendingRewards::<Test>::get(3), CompletionReward::get());
+		assert_eq!(crate::PendingRewards::<Test>::get(1), 0);
+	});
+}
+
+#[test]
+fn report_without_deposit_funds_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(9),
+				components(&[(GLASS, 100)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::InsufficientBalanceForDeposit
+		);
+	});
+}
+
+#[test]
+fn bulk_update_status_is_bounded_by_max() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..8 {
+			let report_id = create_report(1, GLASS, 10);
+			advance_to(report_id, WasteStatus::Transported);
+		}
+
+		assert_ok!(WasteManagement::bulk_update_status(
+			RuntimeOrigin::signed(OPERATOR),
+			WasteStatus::Transported,
+			WasteStatus::Utilized,
+			5
+		));
+		System::assert_last_event(
+			Event::BulkStatusUpdated {
+				count: 5,
+				truncated: true,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		assert_eq!(WasteManagement::reports_with_status(WasteStatus::Utilized).len(), 5);
+		assert_eq!(WasteManagement::reports_with_status(WasteStatus::Transported).len(), 3);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Transported), 30);
+	});
+}
+
+#[test]
+fn bulk_update_status_validates_the_transition() {
+	new_test_ext().execute_with(|| {
+		create_report(1, GLASS, 10);
+		assert_noop!(
+			WasteManagement::bulk_update_status(
+				RuntimeOrigin::signed(OPERATOR),
+				WasteStatus::Reported,
+				WasteStatus::Utilized,
+				5
+			),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_noop!(
+			WasteManagement::bulk_update_status(
+				RuntimeOrigin::signed(1),
+				WasteStatus::Transported,
+				WasteStatus::Utilized,
+				5
+			),
+			Error::<Test>::NotAuthorizedForStatus
+		);
+	});
+}
+
+#[test]
+fn bulk_update_by_type_moves_only_that_type() {
+	new_test_ext().execute_with(|| {
+		let mut plastic = Vec::new();
+		let mut others = Vec::new();
+		for waste_type in [PLASTIC, GLASS, PLASTIC, ORGANIC, PLASTIC, GLASS] {
+			let report_id = create_report(1, waste_type, 10);
+			advance_to(report_id, WasteStatus::Transported);
+			if waste_type == PLASTIC {
+				plastic.push(report_id);
+			} else {
+				others.push(report_id);
+			}
+		}
+		let status = |report_id: &ReportId| WasteManagement::waste_data(report_id).unwrap().status;
+
+		assert_noop!(
+			WasteManagement::bulk_update_status_by_type(
+				RuntimeOrigin::signed(OPERATOR),
+				PLASTIC,
+				WasteStatus::Transported,
+				WasteStatus::Reported,
+				10
+			),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_noop!(
+			WasteManagement::bulk_update_status_by_type(
+				RuntimeOrigin::signed(1),
+				PLASTIC,
+				WasteStatus::Transported,
+				WasteStatus::Utilized,
+				10
+			),
+			Error::<Test>::NotAuthorizedForStatus
+		);
+
+		assert_ok!(WasteManagement::bulk_update_status_by_type(
+			RuntimeOrigin::signed(OPERATOR),
+			PLASTIC,
+			WasteStatus::Transported,
+			WasteStatus::Utilized,
+			10
+		));
+		System::assert_last_event(
+			Event::BulkTypedStatusUpdated {
+				waste_type: PLASTIC,
+				count: 3,
+				truncated: false,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert!(plastic.iter().all(|id| status(id) == WasteStatus::Utilized));
+		assert!(others.iter().all(|id| status(id) == WasteStatus::Transported));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Transported), 30);
+
+		// Only `max` reports are examined, matching or not, so the organic report may use up
+		// part of the budget.
+		let bulk_glass = |max| {
+			assert_ok!(WasteManagement::bulk_update_status_by_type(
+				RuntimeOrigin::signed(OPERATOR),
+				GLASS,
+				WasteStatus::Transported,
+				WasteStatus::Utilized,
+				max
+			));
+		};
+		bulk_glass(2);
+		assert!(emitted!(Event::BulkTypedStatusUpdated { waste_type: GLASS, truncated: true, .. }));
+		bulk_glass(2);
+		assert!(others.iter().all(|id| {
+			status(id) ==
+				if WasteManagement::waste_data(id).unwrap().waste_type == GLASS {
+					WasteStatus::Utilized
+				} else {
+					WasteStatus::Transported
+				}
+		}));
+	});
+}
+
+#[test]
+fn reports_are_indexed_by_grid_cell() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(PLASTIC, 5)]),
+			0,
+			GeoPoint { x: 110, y: 120 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(2),
+			components(&[(GLASS, 5)]),
+			0,
+			GeoPoint { x: 190, y: 101 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(PLASTIC, 5)]),
+			0,
+			GeoPoint { x: 950, y: 480 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_eq!(WasteManagement::cell_of(&GeoPoint { x: 110, y: 120 }), (1, 1));
+		assert_eq!(WasteManagement::reports_in_cell((1, 1)), vec![1, 2]);
+		assert_eq!(WasteManagement::reports_in_cell((9, 4)), vec![3]);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), 1));
+		assert_eq!(WasteManagement::reports_in_cell((1, 1)), vec![2]);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), 3));
+		assert!(!crate::ReportsByCell::<Test>::contains_key((9, 4)));
+	});
+}
+
+#[test]
+fn reporter_can_attach_evidence_once() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, 10);
+
+		assert_ok!(WasteManagement::attach_evidence(RuntimeOrigin::signed(1), report_id, [7; 32]));
+		System::assert_last_event(
+			Event::EvidenceAttached { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_eq!(WasteManagement::evidence_of(report_id), Some([7; 32]));
+
+		assert_noop!(
+			WasteManagement::attach_evidence(RuntimeOrigin::signed(1), report_id, [8; 32]),
+			Error::<Test>::EvidenceAlreadyAttached
+		);
+	});
+}
+
+#[test]
+fn only_reporter_can_attach_evidence() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, 10);
+
+		assert_noop!(
+			WasteManagement::attach_evidence(RuntimeOrigin::signed(OPERATOR), report_id, [7; 32]),
+			Error::<Test>::NotReportOwner
+		);
+		assert_noop!(
+			WasteManagement::attach_evidence(RuntimeOrigin::signed(1), 99, [7; 32]),
+			Error::<Test>::ReportNotFound
+		);
+	});
+}
+
+#[test]
+fn ids_keep_climbing_while_report_count_follows_deletes() {
+	new_test_ext().execute_with(|| {
+		let first = create_report(1, PLASTIC, 10);
+		let second = create_report(1, GLASS, 10);
+		assert_eq!((first, second), (1, 2));
+		assert_eq!(WasteManagement::waste_data_count(), 2);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), second));
+		assert_eq!(WasteManagement::waste_data_count(), 1);
+
+		assert_eq!(create_report(1, GLASS, 10), 3);
+		assert_eq!(WasteManagement::next_report_id(), 4);
+		assert_eq!(WasteManagement::waste_data_count(), 2);
+
+		assert_ok!(WasteManagement::cancel_report(RuntimeOrigin::signed(1), first, reason(b"dup")));
+		assert_eq!(WasteManagement::waste_data_count(), 1);
+		// Deleting a cancelled report must not count it twice.
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), first));
+		assert_eq!(WasteManagement::waste_data_count(), 1);
+	});
+}
+
+#[test]
+fn amount_histogram_bins_reports_of_a_type() {
+	new_test_ext().execute_with(|| {
+		let unit = WasteManagement::amount_unit();
+		for amount in [1, 9 * unit, 10 * unit, 99 * unit, 500 * unit, 1_000 * unit, 5_000 * unit] {
+			create_report(1, PLASTIC, amount);
+		}
+		create_report(3, GLASS, 50 * unit);
+		let cancelled = create_report(3, PLASTIC, 50 * unit);
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(3),
+			cancelled,
+			reason(b"dup")
+		));
+
+		// A mixed report counts for each of its types, by the amount of that component.
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(4),
+			components(&[(PLASTIC, 2_000 * unit), (GLASS, 5 * unit)]),
+			0,
+			GeoPoint { x: 10, y: 20 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_eq!(WasteManagement::amount_histogram(PLASTIC, None), [2, 2, 1, 3]);
+		assert_eq!(WasteManagement::amount_histogram(GLASS, None), [1, 1, 0, 0]);
+		assert_eq!(WasteManagement::amount_histogram(ORGANIC, None), [0; 4]);
+		// A capped scan only counts what it examined.
+		assert!(WasteManagement::amount_histogram(PLASTIC, Some(3)).iter().sum::<u64>() <= 3);
+	});
+}
+
+#[test]
+fn count_by_type_and_status_works() {
+	new_test_ext().execute_with(|| {
+		for waste_type in [PLASTIC, PLASTIC, GLASS, PLASTIC, ORGANIC] {
+			create_report(1, waste_type, 10);
+		}
+		collect(1);
+		collect(3);
+		// A mixed report counts for each of its types.
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(ORGANIC, 5), (GLASS, 5)]),
+			0,
+			GeoPoint { x: 10, y: 20 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		collect(6);
+
+		assert_eq!(
+			WasteManagement::count_by_type_and_status(PLASTIC, WasteStatus::Reported, None),
+			2
+		);
+		assert_eq!(
+			WasteManagement::count_by_type_and_status(PLASTIC, WasteStatus::Collected, None),
+			1
+		);
+		assert_eq!(
+			WasteManagement::count_by_type_and_status(GLASS, WasteStatus::Collected, None),
+			2
+		);
+		assert_eq!(
+			WasteManagement::count_by_type_and_status(ORGANIC, WasteStatus::Collected, None),
+			1
+		);
+		assert_eq!(
+			WasteManagement::count_by_type_and_status(GLASS, WasteStatus::Reported, None),
+			0
+		);
+		assert_eq!(
+			WasteManagement::count_by_type_and_status(ORGANIC, WasteStatus::Reported, None),
+			1
+		);
+
+		// The scan limit applies to the reports in the status, before filtering on type.
+		let count = |limit| {
+			WasteManagement::count_by_type_and_status(PLASTIC, WasteStatus::Reported, Some(limit))
+		};
+		assert_eq!(count(0), 0);
+		assert!(count(1) <= 1);
+		assert_eq!(count(3), 2);
+	});
+}
+
+#[test]
+fn status_permissions_are_per_target_status() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assign_collector(report_id);
+
+		assert_ok!(WasteManagement::grant_status_permission(
+			RuntimeOrigin::root(),
+			COLLECTOR,
+			WasteStatus::Collected
+		));
+		System::assert_last_event(
+			Event::StatusPermissionGranted {
+				account: COLLECTOR,
+				status: WasteStatus::Collected,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_ok!(WasteManagement::update_waste_status(
+			RuntimeOrigin::signed(COLLECTOR),
+			report_id,
+			WasteStatus::Collected,
+			None
+		));
+
+		assert_ok!(set_status(report_id, WasteStatus::Transported));
+		assert_noop!(
+			WasteManagement::update_waste_status(
+				RuntimeOrigin::signed(COLLECTOR),
+				report_id,
+				WasteStatus::Utilized,
+				None
+			),
+			Error::<Test>::NotAuthorizedForStatus
+		);
+	});
+}
+
+#[test]
+fn status_permissions_can_be_revoked() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assign_collector(report_id);
+
+		assert_noop!(
+			WasteManagement::grant_status_permission(
+				RuntimeOrigin::signed(OPERATOR),
+				COLLECTOR,
+				WasteStatus::Collected
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::grant_status_permission(
+			RuntimeOrigin::root(),
+			COLLECTOR,
+			WasteStatus::Collected
+		));
+		assert_ok!(WasteManagement::revoke_status_permission(
+			RuntimeOrigin::root(),
+			COLLECTOR,
+			WasteStatus::Collected
+		));
+		System::assert_last_event(
+			Event::StatusPermissionRevoked {
+				account: COLLECTOR,
+				status: WasteStatus::Collected,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			WasteManagement::update_waste_status(
+				RuntimeOrigin::signed(COLLECTOR),
+				report_id,
+				WasteStatus::Collected,
+				None
+			),
+			Error::<Test>::NotAuthorizedForStatus
+		);
+	});
+}
+
+#[test]
+fn report_hook_can_veto_reports() {
+	new_test_ext().execute_with(|| {
+		let name = b"unsorted".to_vec().try_into().unwrap();
+		assert_ok!(WasteManagement::register_waste_type(
+			RuntimeOrigin::root(),
+			WasteCategory::Other(0),
+			name
+		));
+
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(WasteCategory::Other(0), 100)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			sp_runtime::DispatchError::Other("waste type 0 is not accepted")
+		);
+		assert_eq!(WasteManagement::next_report_id(), 1);
+
+		create_report(1, PLASTIC, 100);
+		assert_eq!(WasteManagement::waste_data_count(), 1);
+	});
+}
+
+#[test]
+fn status_hook_is_told_when_a_report_is_transported() {
+	new_test_ext().execute_with(|| {
+		let first = create_report(1, GLASS, 100);
+		let second = create_report(1, GLASS, 100);
+		assert_ok!(collect(first));
+		assert_ok!(collect(second));
+		assert!(ReachedTransported::get().is_empty());
+
+		assert_ok!(set_status(second, WasteStatus::Transported));
+		assert_eq!(ReachedTransported::get(), vec![second]);
+		assert_noop!(
+			set_status(first, WasteStatus::Utilized),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_ok!(set_status(first, WasteStatus::Transported));
+		assert_ok!(set_status(first, WasteStatus::Utilized));
+		assert_eq!(ReachedTransported::get(), vec![second, first]);
+	});
+}
+
+#[test]
+fn sealed_reports_cannot_be_changed() {
+	new_test_ext().execute_with(|| {
+		let open = create_report(1, GLASS, 100);
+		assert_noop!(
+			WasteManagement::seal_report(RuntimeOrigin::root(), open),
+			Error::<Test>::NotUtilized
+		);
+		let report_id = create_report(1, GLASS, 100);
+		advance_to(report_id, WasteStatus::Utilized);
+		assert_noop!(
+			WasteManagement::seal_report(RuntimeOrigin::signed(OPERATOR), report_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::seal_report(RuntimeOrigin::root(), report_id));
+		System::assert_last_event(
+			Event::ReportSealed { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_noop!(
+			WasteManagement::seal_report(RuntimeOrigin::root(), report_id),
+			Error::<Test>::ReportSealed
+		);
+
+		let operator = || RuntimeOrigin::signed(OPERATOR);
+		let reporter = || RuntimeOrigin::signed(1);
+		assert_ok!(WasteManagement::trust_weighbridge(RuntimeOrigin::root(), 5));
+		for call in [
+			WasteManagement::update_waste_amount(operator(), report_id, 50),
+			WasteManagement::assign_collector(operator(), report_id, COLLECTOR),
+			WasteManagement::transfer_report(reporter(), report_id, 3),
+			WasteManagement::attach_evidence(reporter(), report_id, [7; 32]),
+			WasteManagement::update_location(reporter(), report_id, GeoPoint { x: 11, y: 20 }),
+			WasteManagement::add_note(operator(), report_id, note(b"late")),
+			WasteManagement::update_contact(reporter(), report_id, None),
+			WasteManagement::dispute_status(reporter(), report_id, reason(b"wrong")),
+			WasteManagement::attest_weight(operator(), report_id, 5, Default::default()),
+			WasteManagement::update_waste_status(
+				operator(),
+				report_id,
+				WasteStatus::Transported,
+				None,
+			),
+			WasteManagement::force_update_status(
+				RuntimeOrigin::root(),
+				report_id,
+				WasteStatus::Collected,
+			),
+			WasteManagement::slash_report(RuntimeOrigin::root(), report_id),
+			WasteManagement::delete_waste_data(reporter(), report_id),
+		] {
+			assert_eq!(call.map_err(|e| e.error), Err(Error::<Test>::ReportSealed.into()));
+		}
+
+		// Archiving and purges pass it over.
+		assert_ok!(WasteManagement::archive_completed(RuntimeOrigin::root(), 100, 10));
+		assert_ok!(WasteManagement::delete_reports_of(RuntimeOrigin::root(), 1, 10, true));
+		assert!(!crate::ArchivedReports::<Test>::contains_key(report_id));
+		assert_eq!(WasteManagement::waste_data(report_id).unwrap().status, WasteStatus::Utilized);
+		assert!(WasteManagement::waste_data(open).is_none());
+
+		assert_ok!(WasteManagement::unseal_report(RuntimeOrigin::root(), report_id));
+		System::assert_last_event(
+			Event::ReportUnsealed { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_noop!(
+			WasteManagement::unseal_report(RuntimeOrigin::root(), report_id),
+			Error::<Test>::NotSealed
+		);
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+	});
+}
+
+#[test]
+fn hooks_cannot_reenter_the_pallet() {
+	new_test_ext().execute_with(|| {
+		let name = b"loop".to_vec().try_into().unwrap();
+		assert_ok!(WasteManagement::register_waste_type(
+			RuntimeOrigin::root(),
+			REENTRANT_TYPE,
+			name
+		));
+
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(REENTRANT_TYPE, 100)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::Reentrancy
+		);
+
+		// Outside a transactional call the flag is still cleared on the error path, so the
+		// pallet keeps working.
+		assert_eq!(
+			WasteManagement::do_create_waste_data(
+				&1,
+				components(&[(REENTRANT_TYPE, 100)]),
+				0,
+				Some(GeoPoint { x: 10, y: 20 }),
+				Severity::Low,
+				None,
+				None,
+				None,
+				None
+			),
+			Err(Error::<Test>::Reentrancy.into())
+		);
+		assert!(!crate::Mutating::<Test>::get());
+		let report_id = create_report(1, PLASTIC, 100);
+		assert_ok!(collect(report_id));
+	});
+}
+
+#[test]
+fn block_summary_is_emitted_every_interval() {
+	new_test_ext().execute_with(|| {
+		create_report(1, PLASTIC, 10);
+		let report_id = create_report(1, GLASS, 30);
+		assert_ok!(collect(report_id));
+
+		let summaries = || {
+			System::events()
+				.into_iter()
+				.filter(|record| {
+					matches!(
+						record.event,
+						RuntimeEvent::WasteManagement(Event::BlockSummary {
+							total_reports: 2,
+							reported: 10,
+							collected: 30,
+							transported: 0,
+							utilized: 0,
+							..
+						})
+					)
+				})
+				.count()
+		};
+
+		for n in 1..SUMMARY_INTERVAL * 2 {
+			System::set_block_number(n);
+			WasteManagement::on_finalize(n);
+			let expected = if n < SUMMARY_INTERVAL { 0 } else { 1 };
+			assert_eq!(summaries(), expected, "block {}", n);
+		}
+		System::set_block_number(SUMMARY_INTERVAL * 2);
+		WasteManagement::on_finalize(SUMMARY_INTERVAL * 2);
+		assert_eq!(summaries(), 2);
+	});
+}
+
+#[test]
+fn amounts_are_fixed_point() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(WasteManagement::amount_unit(), 1_000_000);
+		assert_eq!(WasteManagement::amount_from_units(3), Some(3_000_000));
+		assert_eq!(WasteManagement::amount_from_units(u64::MAX), None);
+		assert_eq!(WasteManagement::amount_parts(2_500_001), (2, 500_001));
+	});
+}
+
+#[test]
+fn large_amounts_aggregate_without_overflow() {
+	new_test_ext().execute_with(|| {
+		let quarter = MAX_WASTE_AMOUNT;
+		for _ in 0..4 {
+			create_report(1, PLASTIC, quarter);
+		}
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), quarter * 4);
+
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(PLASTIC, quarter)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::AmountOverflow
+		);
+
+		for report_id in 1..=4 {
+			assert_ok!(collect(report_id));
+		}
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 0);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), quarter * 4);
+
+		// With the reported total drained, new reports fit again while the other totals
+		// saturate rather than wrap.
+		create_report(1, PLASTIC, quarter);
+		assert_ok!(collect(5));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), u64::MAX);
+	});
+}
+
+#[test]
+fn getters_expose_reports() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(WasteManagement::waste_data_count(), 0);
+		assert_eq!(WasteManagement::get_report(1), None);
+
+		let report_id = create_report(1, PLASTIC, 100);
+
+		assert_eq!(WasteManagement::waste_data_count(), 1);
+		assert_eq!(WasteManagement::next_report_id(), report_id + 1);
+		let report = WasteManagement::waste_data(report_id).unwrap();
+		assert_eq!(report.waste_amount, 100);
+		assert_eq!(WasteManagement::get_report(report_id), Some(report));
+	});
+}
+
+#[test]
+fn reports_are_rate_limited_per_block() {
+	new_test_ext().execute_with(|| {
+		MaxReportsPerBlock::set(2);
+		create_report(1, PLASTIC, 10);
+		create_report(1, PLASTIC, 10);
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(PLASTIC, 10)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::RateLimited
+		);
+		// The limit is per account.
+		create_report(3, PLASTIC, 10);
+
+		System::set_block_number(2);
+		create_report(1, PLASTIC, 10);
+		assert_eq!(crate::ReportsThisBlock::<Test>::get(1), (2, 1));
+	});
+}
+
+#[test]
+fn duplicate_location_reports_are_rejected_within_the_window() {
+	new_test_ext().execute_with(|| {
+		DedupWindow::set(3);
+		create_report(1, PLASTIC, 10);
+
+		System::set_block_number(3);
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(3),
+				components(&[(PLASTIC, 10)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::DuplicateLocationReport
+		);
+		// Another waste type, or another location, is a different pile.
+		create_report(3, GLASS, 10);
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(3),
+			components(&[(PLASTIC, 10)]),
+			0,
+			GeoPoint { x: 11, y: 20 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		System::set_block_number(4);
+		let report_id = create_report(3, PLASTIC, 10);
+		assert_eq!(crate::RecentReports::<Test>::get((10, 20, PLASTIC)), Some((report_id, 4)));
+	});
+}
+
+#[test]
+fn a_deleted_report_no_longer_blocks_its_location() {
+	new_test_ext().execute_with(|| {
+		DedupWindow::set(3);
+		let report_id = create_report(1, PLASTIC, 10);
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+		assert_eq!(crate::RecentReports::<Test>::get((10, 20, PLASTIC)), None);
+
+		let refiled = create_report(3, PLASTIC, 10);
+		assert_eq!(crate::RecentReports::<Test>::get((10, 20, PLASTIC)), Some((refiled, 1)));
+		// An entry left behind by a report removed some other way is skipped too.
+		crate::WasteDataMap::<Test>::remove(refiled);
+		create_report(1, PLASTIC, 10);
+	});
+}
+
+#[test]
+fn update_location_moves_the_report() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, 10);
+		assert_eq!(WasteManagement::reports_in_cell((0, 0)), vec![report_id]);
+
+		System::set_block_number(2);
+		assert_ok!(WasteManagement::update_location(
+			RuntimeOrigin::signed(1),
+			report_id,
+			GeoPoint { x: 420, y: 310 }
+		));
+		System::assert_last_event(
+			Event::LocationUpdated { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+
+		let report = crate::WasteDataMap::<Test>::get(report_id).unwrap();
+		assert_eq!((report.location, report.updated_at), (Some(GeoPoint { x: 420, y: 310 }), 2));
+		assert_eq!(
+			crate::WasteDataByStatus::<Test>::get(WasteStatus::Reported, report_id),
+			Some(report)
+		);
+
+		assert!(WasteManagement::reports_in_cell((0, 0)).is_empty());
+		assert_eq!(WasteManagement::reports_in_cell((4, 3)), vec![report_id]);
+	});
+}
+
+#[test]
+fn update_location_checks_owner_and_bounds() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, 10);
+
+		assert_noop!(
+			WasteManagement::update_location(
+				RuntimeOrigin::signed(3),
+				report_id,
+				GeoPoint { x: 420, y: 310 }
+			),
+			Error::<Test>::NotReportOwner
+		);
+		assert_noop!(
+			WasteManagement::update_location(
+				RuntimeOrigin::signed(1),
+				report_id,
+				GeoPoint { x: 420, y: 501 }
+			),
+			Error::<Test>::LocationOutOfBounds
+		);
+	});
+}
+
+#[test]
+fn migration_to_v1_re_encodes_reports() {
+	use crate::migrations::v1::{v0, MigrateToV1, OldWasteData, WasteDataCount};
+	use frame_support::{
+		storage::unhashed,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<WasteManagement>();
+		let old = |report_id, status| OldWasteData {
+			report_id,
+			waste_type: 0,
+			waste_amount: 100,
+			status,
+			location_x: 10,
+			location_y: 20,
+			reporter: 1u64,
+		};
+		for (report_id, status) in
+			[(1, WasteStatus::Reported), (2, WasteStatus::Collected), (3, WasteStatus::Utilized)]
+		{
+			let report = old(report_id, status.clone());
+			unhashed::put(&crate::WasteDataMap::<Test>::hashed_key_for(report_id), &report);
+			unhashed::put(
+				&v0::WasteDataByStatus::<Test>::hashed_key_for((status, report_id)),
+				&report,
+			);
+		}
+		WasteDataCount::<Test>::put(3);
+
+		System::set_block_number(7);
+		#[cfg(feature = "try-runtime")]
+		let state = MigrateToV1::<Test>::pre_upgrade().unwrap();
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		MigrateToV1::<Test>::post_upgrade(state).unwrap();
+
+		assert_eq!(WasteManagement::on_chain_storage_version(), 1);
+		let report = crate::WasteDataMap::<Test>::get(2).unwrap();
+		assert_eq!(
+			(report.status.clone(), report.created_at, report.updated_at),
+			(WasteStatus::Collected, 7, 7)
+		);
+		assert_eq!(report.waste_type, PLASTIC);
+		assert_eq!(crate::WasteDataByStatus::<Test>::get(WasteStatus::Collected, 2), Some(report));
+		assert_eq!(crate::WasteDataMap::<Test>::iter_values().count(), 3);
+		assert!(!WasteDataCount::<Test>::exists());
+		assert_eq!(WasteManagement::next_report_id(), 4);
+		assert_eq!(WasteManagement::waste_data_count(), 3);
+
+		// The indices and totals that didn't exist at version 0 are built from the reports.
+		let sorted = |mut ids: Vec<ReportId>| {
+			ids.sort();
+			ids
+		};
+		assert_eq!(sorted(WasteManagement::reports_of(&1)), vec![1, 2, 3]);
+		assert_eq!(sorted(WasteManagement::reports_in_cell((0, 0))), vec![1, 2, 3]);
+		assert_eq!(sorted(WasteManagement::reports_created_between(7, 7, 10)), vec![1, 2, 3]);
+		assert_eq!(sorted(WasteManagement::reports_by_severity(Severity::Low)), vec![1, 2]);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), 100);
+		assert_eq!(WasteManagement::total_amount_of_type(PLASTIC), 300);
+		assert_eq!(WasteManagement::history_of(3), vec![(WasteStatus::Utilized, 7)]);
+		assert_eq!(WasteManagement::statuses_seen_list(3), vec![WasteStatus::Utilized]);
+		assert_ok!(WasteManagement::do_try_state());
+
+		// Running it again is a no-op.
+		System::set_block_number(8);
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(crate::WasteDataMap::<Test>::get(1).unwrap().created_at, 7);
+	});
+}
+
+#[test]
+fn reports_are_queued_by_severity() {
+	new_test_ext().execute_with(|| {
+		let low = create_report(1, PLASTIC, 10);
+		let mut critical = Vec::new();
+		for _ in 0..2 {
+			assert_ok!(WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 10)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Critical,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+			critical.push(WasteManagement::next_report_id() - 1);
+		}
+		System::assert_last_event(
+			Event::WasteDataCreated {
+				report_id: 3,
+				reporter: 1,
+				severity: Severity::Critical,
+				waste_type: GLASS,
+				waste_amount: 10,
+				volume: 0,
+				origin_tag_len: 0,
+				status: WasteStatus::Reported,
+				has_location: true,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_eq!(crate::WasteDataMap::<Test>::get(3).unwrap().severity, Severity::Critical);
+
+		let mut queue = WasteManagement::reports_by_severity(Severity::Critical);
+		queue.sort();
+		assert_eq!(queue, critical);
+		assert_eq!(WasteManagement::reports_by_severity(Severity::Low), vec![low]);
+		assert!(WasteManagement::reports_by_severity(Severity::High).is_empty());
+
+		// Closed reports leave the queue.
+		assert_ok!(WasteManagement::cancel_report(RuntimeOrigin::signed(1), 2, reason(b"dup")));
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), low));
+		assert_eq!(WasteManagement::reports_by_severity(Severity::Critical), vec![3]);
+		assert!(WasteManagement::reports_by_severity(Severity::Low).is_empty());
+	});
+}
+
+#[test]
+fn staleness_predicate() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, 10);
+		let report = crate::WasteDataMap::<Test>::get(report_id).unwrap();
+
+		assert!(!WasteManagement::is_stale(&report, STALE_THRESHOLD));
+		assert!(WasteManagement::is_stale(&report, STALE_THRESHOLD + 1));
+
+		assert_ok!(collect(report_id));
+		let report = crate::WasteDataMap::<Test>::get(report_id).unwrap();
+		assert!(!WasteManagement::is_stale(&report, STALE_THRESHOLD + 1));
+	});
+}
+
+#[test]
+fn flag_stale_is_idempotent() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, 10);
+
+		assert_noop!(
+			WasteManagement::flag_stale(RuntimeOrigin::signed(3), report_id),
+			Error::<Test>::NotStale
+		);
+
+		System::set_block_number(1 + STALE_THRESHOLD);
+		assert_ok!(WasteManagement::flag_stale(RuntimeOrigin::signed(3), report_id));
+		System::assert_last_event(
+			Event::ReportFlaggedStale { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert!(crate::StaleFlags::<Test>::contains_key(report_id));
+
+		let events = System::events().len();
+		assert_ok!(WasteManagement::flag_stale(RuntimeOrigin::signed(3), report_id));
+		assert_eq!(System::events().len(), events);
+
+		// Moving on clears the flag.
+		assert_ok!(collect(report_id));
+		assert!(!crate::StaleFlags::<Test>::contains_key(report_id));
+	});
+}
+
+#[test]
+fn reports_of_by_status_filters_one_reporter() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..4 {
+			create_report(1, PLASTIC, 10);
+		}
+		create_report(3, PLASTIC, 10);
+		assert_ok!(collect(2));
+		assert_ok!(collect(4));
+		assert_ok!(collect(5));
+		// A dangling id in the index is skipped rather than failing the query.
+		crate::WasteDataMap::<Test>::remove(1);
+
+		let ids = |status| {
+			WasteManagement::reports_of_by_status(&1, status)
+				.into_iter()
+				.map(|report| report.report_id)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(ids(WasteStatus::Collected), vec![2, 4]);
+		assert_eq!(ids(WasteStatus::Reported), vec![3]);
+		assert!(ids(WasteStatus::Utilized).is_empty());
+	});
+}
+
+#[test]
+fn deleting_a_report_clears_every_index() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, 10);
+		assign_collector(report_id);
+		assert_ok!(WasteManagement::attach_evidence(RuntimeOrigin::signed(1), report_id, [7; 32]));
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			report_id,
+			reason(b"dup")
+		));
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+
+		assert_eq!(WasteManagement::get_report(report_id), None);
+		for status in [WasteStatus::Reported, WasteStatus::Cancelled] {
+			assert!(!crate::WasteDataByStatus::<Test>::contains_key(status, report_id));
+		}
+		assert!(WasteManagement::reports_of(&1).is_empty());
+		assert!(WasteManagement::reports_in_cell(WasteManagement::cell_of(&GeoPoint {
+			x: 10,
+			y: 20
+		}))
+		.is_empty());
+		assert!(WasteManagement::reports_by_severity(Severity::Low).is_empty());
+		assert_eq!(WasteManagement::collector_of(report_id), None);
+		assert_eq!(WasteManagement::evidence_of(report_id), None);
+		assert!(!crate::CancellationReasons::<Test>::contains_key(report_id));
+		assert!(!crate::ReportDeposits::<Test>::contains_key(report_id));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Cancelled), 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn amounts_above_the_maximum_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, MAX_WASTE_AMOUNT);
+		assert_eq!(WasteManagement::get_report(report_id).unwrap().waste_amount, MAX_WASTE_AMOUNT);
+
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(PLASTIC, MAX_WASTE_AMOUNT + 1)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::AmountTooLarge
+		);
+		assert_noop!(
+			WasteManagement::update_waste_amount(
+				RuntimeOrigin::signed(OPERATOR),
+				report_id,
+				MAX_WASTE_AMOUNT + 1
+			),
+			Error::<Test>::AmountTooLarge
+		);
+		assert_eq!(WasteManagement::next_report_id(), report_id + 1);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), MAX_WASTE_AMOUNT);
+	});
+}
+
+#[test]
+fn amounts_are_converted_from_each_unit() {
+	use crate::WasteUnit::*;
+
+	new_test_ext().execute_with(|| {
+		// One whole `WasteAmount` is a tonne at 6 decimals, so the resolution is a gram.
+		assert_eq!(WasteManagement::to_canonical(250, Canonical), Ok(250));
+		assert_eq!(WasteManagement::to_canonical(3, Tonnes), Ok(3_000_000));
+		assert_eq!(WasteManagement::to_canonical(250, Kilograms), Ok(250_000));
+		assert_eq!(WasteManagement::to_canonical(1, Pounds), Ok(453));
+		assert_eq!(WasteManagement::to_canonical(1_000, Pounds), Ok(453_592));
+
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(PLASTIC, 250), (GLASS, 2)]),
+			0,
+			GeoPoint { x: 10, y: 20 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			Some(Kilograms)
+		));
+		let report = WasteManagement::waste_data(WasteManagement::next_report_id() - 1).unwrap();
+		assert_eq!(report.components.into_inner(), vec![(PLASTIC, 250_000), (GLASS, 2_000)]);
+		assert_eq!(report.waste_amount, 252_000);
+	});
+}
+
+#[test]
+fn unit_conversions_that_overflow_are_rejected() {
+	use crate::WasteUnit::*;
+
+	new_test_ext().execute_with(|| {
+		// The largest whole number of tonnes that still fits.
+		let max_tonnes = u64::MAX / 1_000_000;
+		assert_eq!(WasteManagement::to_canonical(max_tonnes, Tonnes), Ok(max_tonnes * 1_000_000));
+		assert_eq!(
+			WasteManagement::to_canonical(max_tonnes + 1, Tonnes),
+			Err(Error::<Test>::AmountTooLarge)
+		);
+		assert_eq!(
+			WasteManagement::to_canonical(u64::MAX, Kilograms),
+			Err(Error::<Test>::AmountTooLarge)
+		);
+		assert_eq!(
+			WasteManagement::to_canonical(u64::MAX, Pounds),
+			Err(Error::<Test>::AmountTooLarge)
+		);
+		assert_eq!(WasteManagement::to_canonical(u64::MAX, Canonical), Ok(u64::MAX));
+
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(PLASTIC, u64::MAX)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				Some(Tonnes)
+			),
+			Error::<Test>::AmountTooLarge
+		);
+	});
+}
+
+#[test]
+fn report_commitment_follows_the_contents_only() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		let other = create_report(1, GLASS, 100);
+		let commitment = WasteManagement::report_commitment(report_id).unwrap();
+		assert_ne!(WasteManagement::report_commitment(other), Some(commitment));
+		assert_eq!(WasteManagement::report_commitment(99), None);
+
+		// Moving through the lifecycle or adding notes leaves it as it is.
+		System::set_block_number(3);
+		assert_ok!(collect(report_id));
+		assert_ok!(WasteManagement::add_note(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			note(b"wet")
+		));
+		assert_ok!(WasteManagement::update_waste_amount(RuntimeOrigin::signed(OPERATOR), other, 1));
+		assert_eq!(WasteManagement::report_commitment(report_id), Some(commitment));
+
+		assert_ok!(WasteManagement::update_waste_amount(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			90
+		));
+		let corrected = WasteManagement::report_commitment(report_id).unwrap();
+		assert_ne!(corrected, commitment);
+		assert_eq!(WasteManagement::report_commitment(report_id), Some(corrected));
+	});
+}
+
+#[test]
+fn completion_event_fires_once_on_utilization() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, 70);
+		let completions = || {
+			System::events()
+				.into_iter()
+				.filter(|record| {
+					matches!(
+						record.event,
+						RuntimeEvent::WasteManagement(Event::ReportCompleted {
+							report_id: id,
+							reporter: 1,
+							total_amount: 70,
+							..
+						}) if id == report_id
+					)
+				})
+				.count()
+		};
+
+		advance_to(report_id, WasteStatus::Transported);
+		assert_eq!(completions(), 0);
+
+		assert_ok!(set_status(report_id, WasteStatus::Utilized));
+		assert_eq!(completions(), 1);
+		System::assert_has_event(
+			Event::WasteStatusUpdated {
+				report_id,
+				operator: OPERATOR,
+				old_status: WasteStatus::Transported,
+				new_status: WasteStatus::Utilized,
+				collector: Some(COLLECTOR),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			set_status(report_id, WasteStatus::Utilized).map_err(|e| e.error),
+			Error::<Test>::StatusUnchanged
+		);
+		assert_eq!(completions(), 1);
+	});
+}
+
+#[test]
+fn reports_can_mix_waste_types() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(ORGANIC, 30), (PLASTIC, 12)]),
+			0,
+			GeoPoint { x: 10, y: 20 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		let report = WasteManagement::get_report(1).unwrap();
+		assert_eq!(report.components, components(&[(ORGANIC, 30), (PLASTIC, 12)]));
+		assert_eq!((report.waste_type, report.waste_amount), (ORGANIC, 42));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 42);
+
+		assert_noop!(
+			WasteManagement::update_waste_amount(RuntimeOrigin::signed(OPERATOR), 1, 50),
+			Error::<Test>::MultipleComponents
+		);
+	});
+}
+
+#[test]
+fn waste_type_can_be_corrected() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, 100);
+		create_report(1, GLASS, 40);
+
+		assert_ok!(WasteManagement::update_waste_type(RuntimeOrigin::signed(1), report_id, GLASS));
+		System::assert_last_event(
+			Event::WasteTypeUpdated {
+				report_id,
+				old_type: PLASTIC,
+				new_type: GLASS,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		let report = WasteManagement::get_report(report_id).unwrap();
+		assert_eq!(report.waste_type, GLASS);
+		assert_eq!(report.components, components(&[(GLASS, 100)]));
+		assert_eq!(WasteManagement::total_amount_of_type(PLASTIC), 0);
+		assert_eq!(WasteManagement::total_amount_of_type(GLASS), 140);
+
+		// An operator may correct it too.
+		assert_ok!(WasteManagement::update_waste_type(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			ORGANIC
+		));
+		assert_eq!(WasteManagement::total_amount_of_type(GLASS), 40);
+		assert_eq!(WasteManagement::total_amount_of_type(ORGANIC), 100);
+		assert_ok!(WasteManagement::do_try_state());
+
+		assert_noop!(
+			WasteManagement::update_waste_type(RuntimeOrigin::signed(3), report_id, GLASS),
+			Error::<Test>::NotReportOwner
+		);
+		assert_noop!(
+			WasteManagement::update_waste_type(
+				RuntimeOrigin::signed(1),
+				report_id,
+				WasteCategory::Metal
+			),
+			Error::<Test>::UnknownWasteType
+		);
+		assert_noop!(
+			WasteManagement::update_waste_type(RuntimeOrigin::signed(1), 99, GLASS),
+			Error::<Test>::ReportNotFound
+		);
+	});
+}
+
+#[test]
+fn waste_type_of_mixed_or_cancelled_reports() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(ORGANIC, 30), (PLASTIC, 12)]),
+			0,
+			GeoPoint { x: 10, y: 20 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			WasteManagement::update_waste_type(RuntimeOrigin::signed(1), 1, GLASS),
+			Error::<Test>::MultipleComponents
+		);
+
+		// Cancelled reports aren't in the type totals, so there is nothing to move.
+		let report_id = create_report(1, PLASTIC, 100);
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			report_id,
+			reason(b"dup")
+		));
+		assert_ok!(WasteManagement::update_waste_type(RuntimeOrigin::signed(1), report_id, GLASS));
+		assert_eq!(WasteManagement::get_report(report_id).unwrap().waste_type, GLASS);
+		assert_eq!(WasteManagement::total_amount_of_type(PLASTIC), 12);
+		assert_eq!(WasteManagement::total_amount_of_type(GLASS), 0);
+	});
+}
+
+#[test]
+fn reports_need_known_components() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::EmptyComponents
+		);
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(PLASTIC, 10), (WasteCategory::Other(99), 10)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::UnknownWasteType
+		);
+	});
+}
+
+#[test]
+fn try_state_detects_index_desync() {
+	new_test_ext().execute_with(|| {
+		create_report(1, PLASTIC, 10);
+		let report_id = create_report(1, GLASS, 20);
+		assert_ok!(collect(report_id));
+		assert_ok!(WasteManagement::cancel_report(RuntimeOrigin::signed(1), 1, reason(b"dup")));
+		assert_ok!(WasteManagement::do_try_state());
+
+		crate::WasteDataByStatus::<Test>::remove(WasteStatus::Collected, report_id);
+		assert_eq!(
+			WasteManagement::do_try_state(),
+			Err("report is missing from the status index under its current status")
+		);
+	});
+}
+
+#[test]
+fn try_state_detects_aggregate_drift() {
+	new_test_ext().execute_with(|| {
+		create_report(1, PLASTIC, 10);
+		crate::TotalAmountByStatus::<Test>::insert(WasteStatus::Reported, 11);
+		assert_eq!(
+			WasteManagement::do_try_state(),
+			Err("TotalAmountByStatus doesn't match the reports in that status")
+		);
+
+		crate::TotalAmountByStatus::<Test>::insert(WasteStatus::Reported, 10);
+		crate::ActiveReportCount::<Test>::put(2);
+		assert_eq!(
+			WasteManagement::do_try_state(),
+			Err("ActiveReportCount doesn't match the reports that are not cancelled")
+		);
+
+		crate::ActiveReportCount::<Test>::put(1);
+		crate::OpenReportCount::<Test>::put(0);
+		assert_eq!(
+			WasteManagement::do_try_state(),
+			Err("OpenReportCount doesn't match the reports that are not closed")
+		);
+	});
+}
+
+#[test]
+fn aggregates_can_be_recomputed_from_the_reports() {
+	new_test_ext().execute_with(|| {
+		let zone = (GeoPoint { x: 0, y: 0 }, GeoPoint { x: 50, y: 50 });
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 1, Some(zone)));
+		create_report(1, PLASTIC, 10);
+		let collected = create_report(1, GLASS, 20);
+		assert_ok!(collect(collected));
+		let cancelled = create_report(1, ORGANIC, 30);
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			cancelled,
+			reason(b"dup")
+		));
+		let deleted = create_report(3, PLASTIC, 40);
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(3), deleted));
+		create_report(3, PLASTIC, 5);
+
+		crate::TotalAmountByStatus::<Test>::insert(WasteStatus::Reported, 999);
+		crate::TotalVolumeByStatus::<Test>::insert(WasteStatus::Utilized, 7);
+		crate::TotalAmountByType::<Test>::remove(PLASTIC);
+		crate::TotalAmountByType::<Test>::insert(ORGANIC, 30);
+		crate::TotalAmountByZone::<Test>::insert(1, 1);
+		crate::AggregatesDrifted::<Test>::put(true);
+		assert!(WasteManagement::do_try_state().is_err());
+
+		// Ids 1 to 5 in batches of two; the totals only change once the last one is visited.
+		assert_noop!(
+			WasteManagement::recompute_aggregates(RuntimeOrigin::signed(OPERATOR), 2),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::recompute_aggregates(RuntimeOrigin::root(), 2));
+		assert_ok!(WasteManagement::recompute_aggregates(RuntimeOrigin::root(), 2));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 999);
+		assert!(!emitted!(Event::AggregatesRecomputed { .. }));
+		assert_ok!(WasteManagement::recompute_aggregates(RuntimeOrigin::root(), 2));
+		System::assert_last_event(
+			Event::AggregatesRecomputed { reports: 4, seq: WasteManagement::event_seq() }.into(),
+		);
+
+		assert_ok!(WasteManagement::do_try_state());
+		assert!(!WasteManagement::aggregates_drifted());
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 15);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), 20);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Cancelled), 30);
+		assert_eq!(WasteManagement::total_amount_of_type(PLASTIC), 15);
+		assert_eq!(WasteManagement::total_amount_of_type(ORGANIC), 0);
+		assert_eq!(crate::TotalAmountByZone::<Test>::get(1), 65);
+		assert!(crate::BatchCursors::<Test>::get(crate::BatchKind::RecomputeAggregates).is_none());
+		assert_eq!(crate::RecomputedTypeTotals::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn prune_orphans_removes_index_entries_without_a_report() {
+	new_test_ext().execute_with(|| {
+		for amount in [10, 20, 30] {
+			create_report(1, PLASTIC, amount);
+		}
+		let collected = create_report(1, GLASS, 40);
+		collect(collected);
+		// A leaked entry: the report is gone but its status mirror, and a stale one under
+		// another status, were left behind. So was one past `NextReportId`, as if the counter
+		// had fallen behind.
+		let orphan = crate::WasteDataMap::<Test>::take(2).unwrap();
+		crate::WasteDataByStatus::<Test>::insert(WasteStatus::Collected, 2, orphan.clone());
+		crate::WasteDataByStatus::<Test>::insert(WasteStatus::Reported, 50, orphan);
+
+		assert_noop!(
+			WasteManagement::prune_orphans(RuntimeOrigin::signed(OPERATOR), 2),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		// Six entries in batches of four.
+		assert_ok!(WasteManagement::prune_orphans(RuntimeOrigin::root(), 4));
+		assert!(!emitted!(Event::OrphansPruned { .. }));
+		assert!(crate::PruneOrphansFrom::<Test>::get().is_some());
+		assert_eq!(
+			crate::BatchCursors::<Test>::get(crate::BatchKind::PruneOrphans).map(|(n, _)| n),
+			Some(4)
+		);
+		assert_ok!(WasteManagement::prune_orphans(RuntimeOrigin::root(), 4));
+		System::assert_last_event(
+			Event::OrphansPruned { count: 3, seq: WasteManagement::event_seq() }.into(),
+		);
+
+		assert!(crate::BatchCursors::<Test>::get(crate::BatchKind::PruneOrphans).is_none());
+		assert!(crate::PruneOrphansFrom::<Test>::get().is_none());
+		assert_eq!(
+			crate::WasteDataByStatus::<Test>::iter_key_prefix(WasteStatus::Reported)
+				.collect::<std::collections::BTreeSet<_>>(),
+			[1, 3].into()
+		);
+		assert!(crate::WasteDataByStatus::<Test>::contains_key(WasteStatus::Collected, collected));
+		assert!(!crate::WasteDataByStatus::<Test>::contains_key(WasteStatus::Collected, 2));
+		assert_eq!(WasteManagement::waste_data(1).unwrap().waste_amount, 10);
+	});
+}
+
+fn note(text: &[u8]) -> frame_support::BoundedVec<u8, <Test as crate::Config>::MaxNoteLen> {
+	text.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn operators_can_append_notes() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, 10);
+
+		assert_ok!(WasteManagement::add_note(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			note(b"wet")
+		));
+		System::assert_last_event(
+			Event::NoteAdded { report_id, note_index: 0, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_ok!(WasteManagement::add_note(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			note(b"mixed")
+		));
+		System::assert_last_event(
+			Event::NoteAdded { report_id, note_index: 1, seq: WasteManagement::event_seq() }.into(),
+		);
+
+		assert_eq!(WasteManagement::notes_of(report_id), vec![b"wet".to_vec(), b"mixed".to_vec()]);
+
+		assert_noop!(
+			WasteManagement::add_note(RuntimeOrigin::signed(1), report_id, note(b"mine")),
+			Error::<Test>::NotAuthorizedOperator
+		);
+		assert_noop!(
+			WasteManagement::add_note(RuntimeOrigin::signed(OPERATOR), 99, note(b"lost")),
+			Error::<Test>::ReportNotFound
+		);
+	});
+}
+
+#[test]
+fn notes_are_capped() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, PLASTIC, 10);
+		for _ in 0..3 {
+			assert_ok!(WasteManagement::add_note(
+				RuntimeOrigin::signed(OPERATOR),
+				report_id,
+				note(b"ok")
+			));
+		}
+
+		assert_noop!(
+			WasteManagement::add_note(RuntimeOrigin::signed(OPERATOR), report_id, note(b"more")),
+			Error::<Test>::TooManyNotes
+		);
+	});
+}
+
+#[test]
+fn lifecycle_can_skip_a_stage() {
+	new_test_ext().execute_with(|| {
+		// On-site composting: collected waste is utilized without being transported.
+		let targets = vec![WasteStatus::Utilized, WasteStatus::Cancelled].try_into().unwrap();
+		assert_noop!(
+			WasteManagement::set_allowed_transitions(
+				RuntimeOrigin::signed(OPERATOR),
+				WasteStatus::Collected,
+				targets
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		let targets = vec![WasteStatus::Utilized, WasteStatus::Cancelled].try_into().unwrap();
+		assert_ok!(WasteManagement::set_allowed_transitions(
+			RuntimeOrigin::root(),
+			WasteStatus::Collected,
+			targets
+		));
+		System::assert_last_event(
+			Event::AllowedTransitionsSet {
+				from: WasteStatus::Collected,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		let reopen = vec![WasteStatus::Reported].try_into().unwrap();
+		assert_noop!(
+			WasteManagement::set_allowed_transitions(
+				RuntimeOrigin::root(),
+				WasteStatus::Cancelled,
+				reopen
+			),
+			Error::<Test>::InvalidStatusTransition
+		);
+
+		let composted = create_report(1, ORGANIC, 10);
+		assert_ok!(collect(composted));
+		assert_ok!(set_status(composted, WasteStatus::Utilized));
+
+		let other = create_report(1, ORGANIC, 10);
+		assert_ok!(collect(other));
+		assert_noop!(
+			set_status(other, WasteStatus::Transported),
+			Error::<Test>::InvalidStatusTransition
+		);
+	});
+}
+
+#[test]
+fn missing_transition_entries_fall_back_to_the_default_table() {
+	new_test_ext_with_types(vec![(PLASTIC, b"plastic".to_vec())]).execute_with(|| {
+		crate::AllowedTransitions::<Test>::remove(WasteStatus::Reported);
+		assert!(WasteManagement::is_valid_transition(
+			&WasteStatus::Reported,
+			&WasteStatus::Collected
+		));
+	});
+}
+
+#[test]
+fn waste_types_can_have_their_own_workflow() {
+	new_test_ext().execute_with(|| {
+		const E_WASTE: WasteType = WasteCategory::Ewaste;
+		assert_ok!(WasteManagement::register_waste_type(
+			RuntimeOrigin::root(),
+			E_WASTE,
+			b"e-waste".to_vec().try_into().unwrap()
+		));
+		// Plastic goes straight to the recycler, while e-waste keeps the transport to a
+		// certified destruction site.
+		let targets = vec![WasteStatus::Utilized, WasteStatus::Cancelled].try_into().unwrap();
+		assert_noop!(
+			WasteManagement::set_type_workflow(
+				RuntimeOrigin::signed(OPERATOR),
+				PLASTIC,
+				WasteStatus::Collected,
+				Some(targets)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		let targets = vec![WasteStatus::Utilized, WasteStatus::Cancelled].try_into().unwrap();
+		assert_ok!(WasteManagement::set_type_workflow(
+			RuntimeOrigin::root(),
+			PLASTIC,
+			WasteStatus::Collected,
+			Some(targets)
+		));
+		System::assert_last_event(
+			Event::TypeWorkflowSet {
+				waste_type: PLASTIC,
+				from: WasteStatus::Collected,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		let plastic = create_report(1, PLASTIC, 10);
+		assert_ok!(collect(plastic));
+		assert_ok!(set_status(plastic, WasteStatus::Utilized));
+
+		let e_waste = create_report(1, E_WASTE, 10);
+		assert_ok!(collect(e_waste));
+		assert_noop!(
+			set_status(e_waste, WasteStatus::Utilized),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_ok!(set_status(e_waste, WasteStatus::Transported));
+		assert_ok!(set_status(e_waste, WasteStatus::Utilized));
+
+		// Clearing the entry returns plastic to the default workflow.
+		assert_ok!(WasteManagement::set_type_workflow(
+			RuntimeOrigin::root(),
+			PLASTIC,
+			WasteStatus::Collected,
+			None
+		));
+		let plastic = create_report(1, PLASTIC, 20);
+		assert_ok!(collect(plastic));
+		assert_noop!(
+			set_status(plastic, WasteStatus::Utilized),
+			Error::<Test>::InvalidStatusTransition
+		);
+	});
+}
+
+#[test]
+fn type_workflows_need_a_registered_type() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			WasteManagement::set_type_workflow(
+				RuntimeOrigin::root(),
+				WasteCategory::Other(99),
+				WasteStatus::Collected,
+				None
+			),
+			Error::<Test>::UnknownWasteType
+		);
+		let reopen = vec![WasteStatus::Reported].try_into().unwrap();
+		assert_noop!(
+			WasteManagement::set_type_workflow(
+				RuntimeOrigin::root(),
+				GLASS,
+				WasteStatus::Cancelled,
+				Some(reopen)
+			),
+			Error::<Test>::InvalidStatusTransition
+		);
+	});
+}
+
+#[test]
+fn unchanged_status_is_charged_the_noop_weight() {
+	use crate::weights::WeightInfo;
+	use frame_support::dispatch::GetDispatchInfo;
+
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assign_collector(report_id);
+		let info = RuntimeCall::WasteManagement(crate::Call::update_waste_status {
+			report_id,
+			new_status: WasteStatus::Collected,
+			on_behalf_of: None,
+		})
+		.get_dispatch_info();
+
+		let changed = set_status(report_id, WasteStatus::Collected).unwrap();
+		let unchanged = set_status(report_id, WasteStatus::Collected).unwrap_err();
+
+		assert_eq!(
+			changed.calc_actual_weight(&info),
+			<() as WeightInfo>::update_waste_status_to_collected()
+		);
+		assert_eq!(
+			unchanged.post_info.calc_actual_weight(&info),
+			<() as WeightInfo>::update_waste_status_noop()
+		);
+		assert!(<() as WeightInfo>::update_waste_status_noop()
+			.all_lt(<() as WeightInfo>::update_waste_status_to_collected()));
+	});
+}
+
+#[test]
+fn status_update_weight_depends_on_the_target() {
+	use crate::weights::WeightInfo;
+	use frame_support::dispatch::GetDispatchInfo;
+
+	let declared = |new_status| {
+		RuntimeCall::WasteManagement(crate::Call::update_waste_status {
+			report_id: 1,
+			new_status,
+			on_behalf_of: None,
+		})
+		.get_dispatch_info()
+		.weight
+	};
+	assert!(declared(WasteStatus::Utilized).all_gt(declared(WasteStatus::Collected)));
+	assert_eq!(
+		declared(WasteStatus::Transported),
+		<() as WeightInfo>::update_waste_status_to_transported()
+	);
+	let by_index = RuntimeCall::WasteManagement(crate::Call::update_waste_status_by_index {
+		report_id: 1,
+		status_index: WasteStatus::Utilized.as_index(),
+	})
+	.get_dispatch_info()
+	.weight;
+	assert_eq!(by_index, declared(WasteStatus::Utilized));
+}
+
+#[test]
+fn delete_weight_scales_with_the_reporter_list() {
+	use crate::weights::WeightInfo;
+	use frame_support::dispatch::GetDispatchInfo;
+
+	let weight = <() as WeightInfo>::delete_waste_data;
+	assert!(weight(1).ref_time() > <() as WeightInfo>::update_waste_status().ref_time());
+	assert!(weight(10).ref_time() > weight(1).ref_time());
+
+	new_test_ext().execute_with(|| {
+		for amount in 1..=3 {
+			create_report(1, GLASS, amount);
+		}
+		let info = RuntimeCall::WasteManagement(crate::Call::delete_waste_data { report_id: 2 })
+			.get_dispatch_info();
+		assert_eq!(info.weight, weight(10));
+
+		let post_info = WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), 2).unwrap();
+		assert_eq!(post_info.calc_actual_weight(&info), weight(3));
+	});
+}
+
+#[test]
+fn reporter_is_rewarded_once_on_completion() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		let issuance = Balances::total_issuance();
+		advance_to(report_id, WasteStatus::Transported);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - REPORT_DEPOSIT);
+
+		assert_ok!(set_status(report_id, WasteStatus::Utilized));
+		assert_eq!(crate::PendingRewards::<Test>::get(1), CompletionReward::get());
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+		assert_eq!(Balances::total_issuance(), issuance);
+		assert!(emitted!(
+			Event::RewardCredited { report_id: id, beneficiary: 1, amount, .. }
+				if id == report_id && amount == CompletionReward::get()
+		));
+
+		assert_noop!(
+			set_status(report_id, WasteStatus::Utilized).map_err(|e| e.error),
+			Error::<Test>::StatusUnchanged
+		);
+		assert_eq!(crate::PendingRewards::<Test>::get(1), CompletionReward::get());
+	});
+}
+
+#[test]
+fn completion_without_a_reward_still_succeeds() {
+	new_test_ext().execute_with(|| {
+		CompletionReward::set(0);
+		let report_id = create_report(1, GLASS, 100);
+		advance_to(report_id, WasteStatus::Utilized);
+
+		assert_eq!(WasteManagement::waste_data(report_id).unwrap().status, WasteStatus::Utilized);
+		assert_eq!(crate::PendingRewards::<Test>::get(1), 0);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::WasteManagement(Event::RewardCredited { .. })
+		)));
+	});
+}
+
+#[test]
+fn status_history_records_every_step() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		System::set_block_number(3);
+		assert_ok!(collect(report_id));
+		System::set_block_number(7);
+		assert_ok!(set_status(report_id, WasteStatus::Transported));
+		System::set_block_number(9);
+		assert_ok!(set_status(report_id, WasteStatus::Utilized));
+
+		assert_eq!(
+			WasteManagement::history_of(report_id),
+			vec![
+				(WasteStatus::Reported, 1),
+				(WasteStatus::Collected, 3),
+				(WasteStatus::Transported, 7),
+				(WasteStatus::Utilized, 9),
+			]
+		);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+		assert!(WasteManagement::history_of(report_id).is_empty());
+	});
+}
+
+#[test]
+fn progress_follows_the_lifecycle() {
+	let expected = [
+		(WasteStatus::Reported, 0),
+		(WasteStatus::Collected, 333),
+		(WasteStatus::Transported, 666),
+		(WasteStatus::Utilized, 1000),
+		(WasteStatus::Cancelled, WasteStatus::NO_PROGRESS),
+		(WasteStatus::Rejected, WasteStatus::NO_PROGRESS),
+	];
+	assert_eq!(expected.len(), WasteStatus::COUNT);
+	for (status, permill) in expected {
+		assert_eq!(status.progress_permill(), permill, "{:?}", status);
+	}
+
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_eq!(WasteManagement::report_progress(report_id), Some(0));
+		assert_ok!(collect(report_id));
+		assert_eq!(WasteManagement::report_progress(report_id), Some(333));
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			report_id,
+			reason(b"dup")
+		));
+		assert_eq!(WasteManagement::report_progress(report_id), Some(WasteStatus::NO_PROGRESS));
+		assert_eq!(WasteManagement::report_progress(report_id + 1), None);
+	});
+}
+
+#[test]
+fn statuses_of_looks_up_many_reports_in_order() {
+	new_test_ext().execute_with(|| {
+		let first = create_report(1, GLASS, 100);
+		let second = create_report(1, PLASTIC, 10);
+		let deleted = create_report(3, GLASS, 100);
+		assert_ok!(collect(second));
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(3), deleted));
+
+		let ids = vec![second, 42, first, deleted, second].try_into().unwrap();
+		assert_eq!(
+			WasteManagement::statuses_of(ids),
+			vec![
+				(second, Some(WasteStatus::Collected)),
+				(42, None),
+				(first, Some(WasteStatus::Reported)),
+				(deleted, None),
+				(second, Some(WasteStatus::Collected)),
+			]
+		);
+		assert!(WasteManagement::statuses_of(Default::default()).is_empty());
+	});
+}
+
+#[test]
+fn status_bitmap_marks_every_status_seen() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_eq!(WasteManagement::statuses_seen(report_id), 0b0001);
+		assert_eq!(WasteManagement::statuses_seen(report_id + 1), 0);
+
+		assert_ok!(collect(report_id));
+		assert_eq!(WasteManagement::statuses_seen(report_id), 0b0011);
+		assert_ok!(set_status(report_id, WasteStatus::Transported));
+		assert_ok!(set_status(report_id, WasteStatus::Utilized));
+		assert_eq!(WasteManagement::statuses_seen(report_id), 0b1111);
+		assert_eq!(
+			WasteManagement::statuses_seen_list(report_id),
+			vec![
+				WasteStatus::Reported,
+				WasteStatus::Collected,
+				WasteStatus::Transported,
+				WasteStatus::Utilized,
+			]
+		);
+		assert_eq!(
+			WasteStatus::from_bitmap(u8::MAX),
+			WasteStatus::all().to_vec(),
+			"bits past the last status are ignored"
+		);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+		assert_eq!(WasteManagement::statuses_seen(report_id), 0);
+	});
+}
+
+#[test]
+fn reports_can_be_listed_by_filing_block() {
+	new_test_ext().execute_with(|| {
+		let mut filed = Vec::new();
+		for block in 1..=5 {
+			System::set_block_number(block);
+			for who in [1, 3] {
+				filed.push((block, create_report(who, PLASTIC, 10)));
+			}
+		}
+		let between = |from: u64, to: u64| -> Vec<ReportId> {
+			filed
+				.iter()
+				.filter(|(b, _)| (from..=to).contains(b))
+				.map(|(_, id)| *id)
+				.collect()
+		};
+
+		assert_eq!(WasteManagement::reports_created_between(2, 4, 10), between(2, 4));
+		assert_eq!(WasteManagement::reports_created_between(2, 4, 3), between(2, 4)[..3]);
+		assert_eq!(WasteManagement::reports_created_between(5, 5, 10), between(5, 5));
+		assert!(WasteManagement::reports_created_between(6, 100, 10).is_empty());
+		assert!(WasteManagement::reports_created_between(4, 2, 10).is_empty());
+		assert!(WasteManagement::reports_created_between(1, 5, 0).is_empty());
+
+		// Deleted reports drop out of the index.
+		let (_, deleted) = filed[2];
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), deleted));
+		let mut expected = between(2, 4);
+		expected.retain(|id| *id != deleted);
+		assert_eq!(WasteManagement::reports_created_between(2, 4, 10), expected);
+	});
+}
+
+/// Runs `op` in `block` and returns the pallet events it deposited the way `ReportEventLog`
+/// records them, as their SCALE variant index and block.
+fn logged_by(block: u64, op: impl FnOnce()) -> Vec<(u8, u64)> {
+	use codec::Encode;
+
+	System::set_block_number(block);
+	System::reset_events();
+	op();
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::WasteManagement(event) => Some((event.encode()[0], block)),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn event_log_follows_a_report_in_order() {
+	new_test_ext().execute_with(|| {
+		let mut report_id = 0;
+		let mut expected = logged_by(1, || report_id = create_report(1, GLASS, 100));
+		expected.extend(logged_by(2, || {
+			assert_ok!(collect(report_id));
+		}));
+		expected.extend(logged_by(3, || {
+			assert_ok!(WasteManagement::add_note(
+				RuntimeOrigin::signed(OPERATOR),
+				report_id,
+				note(b"wet")
+			));
+		}));
+		// Neither another report nor an event about no report ends up in the log.
+		let other = create_report(3, PLASTIC, 10);
+		assert_ok!(WasteManagement::add_operator(RuntimeOrigin::root(), 5));
+		assert_eq!(expected.len(), 4);
+		assert_eq!(WasteManagement::event_log_of(report_id), expected);
+		assert_eq!(WasteManagement::event_log_of(other).len(), 1);
+
+		// Utilization is approved, credits a reward and completes the report on top of the
+		// status change, which overflows the mock bound of six entries, so the oldest go.
+		expected.extend(logged_by(4, || {
+			assert_ok!(set_status(report_id, WasteStatus::Transported));
+			assert_ok!(set_status(report_id, WasteStatus::Utilized));
+		}));
+		assert!(expected.len() > 6);
+		assert_eq!(WasteManagement::event_log_of(report_id), expected[expected.len() - 6..]);
+	});
+}
+
+#[test]
+fn recent_activity_keeps_the_latest_events_about_any_report() {
+	new_test_ext().execute_with(|| {
+		let first = create_report(1, GLASS, 100);
+		let second = create_report(3, PLASTIC, 10);
+		assert_ok!(WasteManagement::add_operator(RuntimeOrigin::root(), 5));
+		let recent = WasteManagement::recent_activity();
+		assert_eq!(recent.iter().map(|(id, ..)| *id).collect::<Vec<_>>(), vec![first, second]);
+
+		let mut expected = logged_by(2, || {
+			assert_ok!(collect(first));
+		});
+		expected.extend(logged_by(3, || {
+			assert_ok!(collect(second));
+			assert_ok!(WasteManagement::add_note(
+				RuntimeOrigin::signed(OPERATOR),
+				second,
+				note(b"wet")
+			));
+		}));
+		// The mock keeps four entries, so the oldest are dropped.
+		assert!(expected.len() > 4);
+		let recent = WasteManagement::recent_activity();
+		assert_eq!(
+			recent.iter().map(|(_, code, block)| (*code, *block)).collect::<Vec<_>>(),
+			expected[expected.len() - 4..]
+		);
+		assert_eq!(recent.last().map(|(id, ..)| *id), Some(second));
+	});
+}
+
+#[test]
+fn event_log_is_dropped_with_its_report() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_ok!(WasteManagement::add_note(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			note(b"wet")
+		));
+		assert_eq!(WasteManagement::event_log_of(report_id).len(), 2);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+		assert!(WasteManagement::event_log_of(report_id).is_empty());
+		assert!(!crate::ReportEventLog::<Test>::contains_key(report_id));
+	});
+}
+
+#[test]
+fn full_history_blocks_status_changes() {
+	new_test_ext().execute_with(|| {
+		MaxHistoryLen::set(2);
+		let report_id = create_report(1, GLASS, 100);
+		assert_ok!(collect(report_id));
+
+		assert_noop!(set_status(report_id, WasteStatus::Transported), Error::<Test>::HistoryFull);
+		assert_noop!(
+			WasteManagement::cancel_report(RuntimeOrigin::signed(1), report_id, reason(b"gone")),
+			Error::<Test>::HistoryFull
+		);
+	});
+}
+
+#[test]
+fn distance_sq_does_not_overflow() {
+	use codec::Encode;
+
+	let origin = GeoPoint { x: 0, y: 0 };
+	assert_eq!(origin.distance_sq(&GeoPoint { x: 3, y: 4 }), 25);
+	assert_eq!(GeoPoint { x: 3, y: 4 }.distance_sq(&origin), 25);
+	assert_eq!(origin.distance_sq(&origin), 0);
+
+	let max = u32::MAX as u64;
+	assert_eq!(origin.distance_sq(&GeoPoint { x: u32::MAX, y: 0 }), max * max);
+	assert_eq!(origin.distance_sq(&GeoPoint { x: u32::MAX, y: u32::MAX }), u64::MAX);
+
+	// Encodes like the two bare coordinates it replaced.
+	assert_eq!(GeoPoint { x: 7, y: 9 }.encode(), (7u32, 9u32).encode());
+}
+
+#[test]
+fn force_update_status_bypasses_the_lifecycle() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+
+		// Reported -> Utilized skips two stages and there is no collector.
+		assert_noop!(
+			set_status(report_id, WasteStatus::Utilized),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_noop!(
+			WasteManagement::force_update_status(
+				RuntimeOrigin::signed(OPERATOR),
+				report_id,
+				WasteStatus::Utilized
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(WasteManagement::force_update_status(
+			RuntimeOrigin::root(),
+			report_id,
+			WasteStatus::Utilized
+		));
+		System::assert_last_event(
+			Event::StatusForceUpdated {
+				report_id,
+				new_status: WasteStatus::Utilized,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_eq!(WasteManagement::waste_data(report_id).unwrap().status, WasteStatus::Utilized);
+		assert_eq!(WasteManagement::reports_with_status(WasteStatus::Utilized).len(), 1);
+		assert!(WasteManagement::reports_with_status(WasteStatus::Reported).is_empty());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn forcing_a_cancelled_report_back_keeps_counts_consistent() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			report_id,
+			reason(b"oops")
+		));
+		assert_eq!(WasteManagement::waste_data_count(), 0);
+
+		assert_ok!(WasteManagement::force_update_status(
+			RuntimeOrigin::root(),
+			report_id,
+			WasteStatus::Reported
+		));
+		assert_eq!(WasteManagement::waste_data_count(), 1);
+		assert_eq!(WasteManagement::reports_by_severity(Severity::Low), vec![report_id]);
+		assert_ok!(WasteManagement::do_try_state());
+
+		assert_noop!(
+			WasteManagement::force_update_status(
+				RuntimeOrigin::root(),
+				report_id,
+				WasteStatus::Reported
+			),
+			Error::<Test>::StatusUnchanged
+		);
+	});
+}
+
+#[test]
+fn pausing_stops_creation_only() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_noop!(
+			WasteManagement::set_paused(RuntimeOrigin::signed(OPERATOR), true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::set_paused(RuntimeOrigin::root(), true));
+		System::assert_last_event(
+			Event::PauseToggled { paused: true, seq: WasteManagement::event_seq() }.into(),
+		);
+
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 100)]),
+				0,
+				GeoPoint { x: 30, y: 40 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::CreationPaused
+		);
+		let batch = vec![(GLASS, 10, 0, GeoPoint { x: 30, y: 40 }, Severity::Low)]
+			.try_into()
+			.unwrap();
+		assert_noop!(
+			WasteManagement::create_waste_data_batch(RuntimeOrigin::signed(1), batch),
+			Error::<Test>::CreationPaused
+		);
+
+		// Existing reports can still move and be removed.
+		assert_ok!(collect(report_id));
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+
+		assert_ok!(WasteManagement::set_paused(RuntimeOrigin::root(), false));
+		System::assert_last_event(
+			Event::PauseToggled { paused: false, seq: WasteManagement::event_seq() }.into(),
+		);
+		create_report(1, GLASS, 100);
+	});
+}
+
+#[test]
+fn totals_are_kept_per_waste_type() {
+	new_test_ext().execute_with(|| {
+		let plastic = create_report(1, PLASTIC, 100);
+		create_report(1, GLASS, 40);
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(PLASTIC, 5), (ORGANIC, 7)]),
+			0,
+			GeoPoint { x: 30, y: 40 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(WasteManagement::total_amount_of_type(PLASTIC), 105);
+		assert_eq!(WasteManagement::total_amount_of_type(ORGANIC), 7);
+		assert_eq!(WasteManagement::total_amount_of_type(GLASS), 40);
+
+		// Moving through the lifecycle doesn't change the type totals.
+		advance_to(plastic, WasteStatus::Transported);
+		assert_eq!(WasteManagement::total_amount_of_type(PLASTIC), 105);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), plastic));
+		assert_eq!(WasteManagement::total_amount_of_type(PLASTIC), 5);
+		assert_ok!(WasteManagement::cancel_report(RuntimeOrigin::signed(1), 3, reason(b"dup")));
+		assert_eq!(WasteManagement::total_amount_of_type(PLASTIC), 0);
+		assert_eq!(WasteManagement::total_amount_of_type(ORGANIC), 0);
+
+		// Deleting a cancelled report doesn't subtract twice.
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), 3));
+		assert_eq!(WasteManagement::total_amount_of_type(GLASS), 40);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn do_create_waste_data_returns_the_new_id() {
+	new_test_ext().execute_with(|| {
+		create_report(1, GLASS, 100);
+
+		let report_id = WasteManagement::do_create_waste_data(
+			&3,
+			components(&[(PLASTIC, 25)]),
+			0,
+			Some(GeoPoint { x: 30, y: 40 }),
+			Severity::High,
+			None,
+			None,
+			None,
+			None,
+		)
+		.unwrap();
+		assert_eq!(report_id, 2);
+		let report = WasteManagement::waste_data(report_id).unwrap();
+		assert_eq!((report.report_id, report.reporter, report.waste_amount), (2, 3, 25));
+		System::assert_last_event(
+			Event::WasteDataCreated {
+				report_id,
+				reporter: 3,
+				severity: Severity::High,
+				waste_type: PLASTIC,
+				waste_amount: 25,
+				volume: 0,
+				origin_tag_len: 0,
+				status: WasteStatus::Reported,
+				has_location: true,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn reporter_score_follows_outcomes() {
+	new_test_ext().execute_with(|| {
+		let done = create_report(1, GLASS, 100);
+		advance_to(done, WasteStatus::Utilized);
+		assert_eq!(WasteManagement::score_of(&1), 1);
+
+		for _ in 0..3 {
+			let report_id = create_report(1, GLASS, 100);
+			assert_ok!(WasteManagement::cancel_report(
+				RuntimeOrigin::signed(1),
+				report_id,
+				reason(b"bogus")
+			));
+		}
+		assert_eq!(WasteManagement::score_of(&1), -2);
+		assert_eq!(WasteManagement::score_of(&3), 0);
+
+		MinScoreToReport::set(-1);
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 100)]),
+				0,
+				GeoPoint { x: 30, y: 40 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ReputationTooLow
+		);
+		create_report(3, GLASS, 100);
+	});
+}
+
+#[test]
+fn reports_in_box_filters_on_exact_coordinates() {
+	new_test_ext().execute_with(|| {
+		let file = |x, y| {
+			assert_ok!(WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 10)]),
+				0,
+				GeoPoint { x, y },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+			WasteManagement::next_report_id() - 1
+		};
+		let inside = [file(150, 150), file(250, 299), file(300, 200)];
+		// Same cells as the box, but outside it.
+		file(120, 150);
+		file(301, 250);
+		// A cell the box doesn't touch.
+		file(900, 450);
+		let cancelled = file(200, 200);
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			cancelled,
+			reason(b"dup")
+		));
+
+		let min = GeoPoint { x: 150, y: 150 };
+		let max = GeoPoint { x: 300, y: 300 };
+		let mut found: Vec<ReportId> = WasteManagement::reports_in_box(min, max, 10)
+			.into_iter()
+			.map(|report| report.report_id)
+			.collect();
+		found.sort();
+		assert_eq!(found, inside.to_vec());
+
+		assert_eq!(WasteManagement::reports_in_box(min, max, 2).len(), 2);
+		assert!(WasteManagement::reports_in_box(max, min, 10).is_empty());
+	});
+}
+
+#[test]
+fn old_unclaimed_reports_expire_automatically() {
+	new_test_ext().execute_with(|| {
+		AutoExpiryBlocks::set(10);
+		let waiting = create_report(1, GLASS, 100);
+		let collected = create_report(1, GLASS, 100);
+		assert_ok!(collect(collected));
+		let done = create_report(1, GLASS, 100);
+		advance_to(done, WasteStatus::Transported);
+
+		System::set_block_number(5);
+		let young = create_report(1, GLASS, 100);
+
+		for n in 2..=10 {
+			WasteManagement::on_initialize(n);
+		}
+		assert_eq!(WasteManagement::waste_data(waiting).unwrap().status, WasteStatus::Reported);
+
+		System::set_block_number(11);
+		WasteManagement::on_initialize(11);
+		for report_id in [waiting, collected] {
+			assert_eq!(
+				WasteManagement::waste_data(report_id).unwrap().status,
+				WasteStatus::Cancelled
+			);
+			assert!(emitted!(Event::ReportAutoExpired { report_id: id, .. } if id == report_id));
+		}
+		assert_eq!(WasteManagement::waste_data(done).unwrap().status, WasteStatus::Transported);
+		assert_eq!(WasteManagement::waste_data(young).unwrap().status, WasteStatus::Reported);
+		assert_eq!(WasteManagement::waste_data_count(), 2);
+
+		for n in 12..=15 {
+			WasteManagement::on_initialize(n);
+		}
+		assert_eq!(WasteManagement::waste_data(young).unwrap().status, WasteStatus::Cancelled);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn expiry_work_per_block_is_capped() {
+	new_test_ext().execute_with(|| {
+		AutoExpiryBlocks::set(10);
+		let reports: Vec<ReportId> = (0..5).map(|_| create_report(1, GLASS, 100)).collect();
+		WasteManagement::on_initialize(11);
+		let expired = |ids: &[ReportId]| {
+			ids.iter()
+				.filter(|id| {
+					WasteManagement::waste_data(**id).unwrap().status == WasteStatus::Cancelled
+				})
+				.count()
+		};
+
+		// One of the three steps moved the cursor past the empty block 0.
+		assert_eq!(expired(&reports), 2);
+		WasteManagement::on_initialize(12);
+		assert_eq!(expired(&reports), 5);
+	});
+}
+
+#[test]
+fn reports_left_uncollected_past_their_deadline_breach_the_sla() {
+	new_test_ext().execute_with(|| {
+		let zone = (GeoPoint { x: 0, y: 0 }, GeoPoint { x: 50, y: 50 });
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 1, Some(zone)));
+		let before_sla = create_report(1, GLASS, 100);
+		assert_noop!(
+			WasteManagement::set_zone_sla(RuntimeOrigin::signed(OPERATOR), 1, Some(5)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::set_zone_sla(RuntimeOrigin::root(), 1, Some(5)));
+		System::assert_last_event(
+			Event::ZoneSlaSet {
+				zone_id: 1,
+				sla_blocks: Some(5),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		let on_time = create_report(1, GLASS, 100);
+		let late = create_report(1, GLASS, 100);
+		assert_eq!(WasteManagement::waste_data(late).unwrap().deadline, Some(6));
+		assert_eq!(WasteManagement::waste_data(before_sla).unwrap().deadline, None);
+		System::set_block_number(4);
+		assert_ok!(collect(on_time));
+
+		for n in 2..=5 {
+			WasteManagement::on_initialize(n);
+		}
+		assert!(!WasteManagement::sla_breached(late));
+		System::set_block_number(6);
+		WasteManagement::on_initialize(6);
+		System::assert_last_event(
+			Event::SlaBreached { report_id: late, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert!(WasteManagement::sla_breached(late));
+		assert!(!WasteManagement::sla_breached(on_time));
+		assert!(!WasteManagement::sla_breached(before_sla));
+
+		// Flagged once only, and the flag goes with the report.
+		for n in 7..=20 {
+			WasteManagement::on_initialize(n);
+		}
+		let breaches = System::events()
+			.iter()
+			.filter(|record| {
+				matches!(record.event, RuntimeEvent::WasteManagement(Event::SlaBreached { .. }))
+			})
+			.count();
+		assert_eq!(breaches, 1);
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), late));
+		assert!(!WasteManagement::sla_breached(late));
+	});
+}
+
+#[test]
+fn any_number_of_reports_can_share_a_deadline() {
+	new_test_ext().execute_with(|| {
+		MaxReportsPerBlock::set(20);
+		let zone = (GeoPoint { x: 0, y: 0 }, GeoPoint { x: 50, y: 50 });
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 1, Some(zone)));
+		assert_ok!(WasteManagement::set_zone_sla(RuntimeOrigin::root(), 1, Some(5)));
+
+		// More reports than a block of deadlines used to hold are all filed and indexed.
+		let reports: Vec<_> = (0..15).map(|n| create_report(1 + n % 3, GLASS, 100)).collect();
+		for report_id in &reports {
+			assert!(crate::SlaDeadlines::<Test>::contains_key(6, report_id));
+		}
+
+		// Collected and deleted reports leave the index before their deadline.
+		assert_ok!(collect(reports[0]));
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(2), reports[1]));
+		assert!(!crate::SlaDeadlines::<Test>::contains_key(6, reports[0]));
+		assert!(!crate::SlaDeadlines::<Test>::contains_key(6, reports[1]));
+
+		System::set_block_number(6);
+		WasteManagement::on_initialize(6);
+		assert!(!WasteManagement::sla_breached(reports[0]));
+		assert!(reports[2..].iter().all(|report_id| WasteManagement::sla_breached(*report_id)));
+		assert_eq!(crate::SlaDeadlines::<Test>::iter_prefix(6).count(), 0);
+	});
+}
+
+#[test]
+fn reporter_can_set_and_clear_a_contact() {
+	use codec::Encode;
+
+	new_test_ext().execute_with(|| {
+		let contact = |text: &[u8]| Some(text.to_vec().try_into().unwrap());
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(GLASS, 100)]),
+			0,
+			GeoPoint { x: 10, y: 20 },
+			Severity::Low,
+			contact(b"+41 79 000 00 00"),
+			None,
+			None,
+			None,
+			None
+		));
+		let report_id = WasteManagement::next_report_id() - 1;
+		assert_eq!(
+			WasteManagement::waste_data(report_id).unwrap().contact,
+			contact(b"+41 79 000 00 00")
+		);
+
+		assert_noop!(
+			WasteManagement::update_contact(RuntimeOrigin::signed(3), report_id, None),
+			Error::<Test>::NotReportOwner
+		);
+		assert_ok!(WasteManagement::update_contact(
+			RuntimeOrigin::signed(1),
+			report_id,
+			contact(b"@reporter")
+		));
+		System::assert_last_event(
+			Event::ContactUpdated { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_eq!(WasteManagement::waste_data(report_id).unwrap().contact, contact(b"@reporter"));
+
+		assert_ok!(WasteManagement::update_contact(RuntimeOrigin::signed(1), report_id, None));
+		let report = WasteManagement::waste_data(report_id).unwrap();
+		assert_eq!(report.contact, None);
+		// A missing contact costs a single byte.
+		assert_eq!(report.contact.encode().len(), 1);
+		assert_eq!(
+			crate::WasteDataByStatus::<Test>::get(WasteStatus::Reported, report_id),
+			Some(report)
+		);
+	});
+}
+
+#[test]
+fn status_index_can_be_read_by_prefix() {
+	new_test_ext().execute_with(|| {
+		let collected = [create_report(1, GLASS, 100), create_report(1, PLASTIC, 50)];
+		let reported = create_report(1, GLASS, 10);
+		for report_id in collected {
+			assert_ok!(collect(report_id));
+		}
+
+		let mut ids: Vec<ReportId> =
+			crate::WasteDataByStatus::<Test>::iter_key_prefix(WasteStatus::Collected).collect();
+		ids.sort();
+		assert_eq!(ids, collected.to_vec());
+		assert_eq!(
+			crate::WasteDataByStatus::<Test>::iter_key_prefix(WasteStatus::Reported)
+				.collect::<Vec<_>>(),
+			vec![reported]
+		);
+		assert_eq!(crate::WasteDataByStatus::<Test>::iter_prefix(WasteStatus::Utilized).count(), 0);
+	});
+}
+
+#[test]
+fn creation_event_carries_type_and_total_amount() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(ORGANIC, 30), (GLASS, 12)]),
+			0,
+			GeoPoint { x: 10, y: 20 },
+			Severity::Medium,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		System::assert_last_event(
+			Event::WasteDataCreated {
+				report_id: 1,
+				reporter: 1,
+				severity: Severity::Medium,
+				waste_type: ORGANIC,
+				waste_amount: 42,
+				volume: 0,
+				origin_tag_len: 0,
+				status: WasteStatus::Reported,
+				has_location: true,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn disputed_reports_are_frozen_until_resolved() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		advance_to(report_id, WasteStatus::Collected);
+
+		assert_noop!(
+			WasteManagement::dispute_status(RuntimeOrigin::signed(3), report_id, reason(b"no")),
+			Error::<Test>::NotReportOwner
+		);
+		assert_ok!(WasteManagement::dispute_status(
+			RuntimeOrigin::signed(1),
+			report_id,
+			reason(b"still on the street")
+		));
+		System::assert_last_event(
+			Event::StatusDisputed { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_noop!(
+			WasteManagement::dispute_status(RuntimeOrigin::signed(1), report_id, reason(b"again")),
+			Error::<Test>::ReportDisputed
+		);
+
+		assert_noop!(
+			set_status(report_id, WasteStatus::Transported),
+			Error::<Test>::ReportDisputed
+		);
+		assert_noop!(
+			WasteManagement::cancel_report(RuntimeOrigin::signed(1), report_id, reason(b"x")),
+			Error::<Test>::ReportDisputed
+		);
+		// The operator's correction goes through the force origin.
+		assert_ok!(WasteManagement::force_update_status(
+			RuntimeOrigin::root(),
+			report_id,
+			WasteStatus::Reported
+		));
+
+		assert_noop!(
+			WasteManagement::resolve_dispute(RuntimeOrigin::signed(1), report_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::resolve_dispute(RuntimeOrigin::root(), report_id));
+		System::assert_last_event(
+			Event::DisputeResolved { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_noop!(
+			WasteManagement::resolve_dispute(RuntimeOrigin::root(), report_id),
+			Error::<Test>::NotDisputed
+		);
+		assert_ok!(collect(report_id));
+	});
+}
+
+#[test]
+fn route_distance_sums_the_legs() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_eq!(WasteManagement::route_distance_sq(report_id), 0);
+
+		// (10, 20) -> (13, 24) -> (13, 30): legs of 3-4-5 and 0-6-6.
+		assert_ok!(WasteManagement::update_location(
+			RuntimeOrigin::signed(1),
+			report_id,
+			GeoPoint { x: 13, y: 24 }
+		));
+		assert_ok!(WasteManagement::update_location(
+			RuntimeOrigin::signed(1),
+			report_id,
+			GeoPoint { x: 13, y: 30 }
+		));
+		assert_eq!(WasteManagement::route_distance_sq(report_id), 25 + 36);
+
+		assert_ok!(WasteManagement::update_location(
+			RuntimeOrigin::signed(1),
+			report_id,
+			GeoPoint { x: 10, y: 20 }
+		));
+		assert_noop!(
+			WasteManagement::update_location(
+				RuntimeOrigin::signed(1),
+				report_id,
+				GeoPoint { x: 11, y: 20 }
+			),
+			Error::<Test>::LocationHistoryFull
+		);
+	});
+}
+
+#[test]
+fn full_route_drops_the_oldest_location_when_configured() {
+	new_test_ext().execute_with(|| {
+		let rejected = create_report(1, GLASS, 100);
+		let dropped = create_report(1, GLASS, 200);
+		for y in 21..=23 {
+			for report_id in [rejected, dropped] {
+				assert_ok!(WasteManagement::update_location(
+					RuntimeOrigin::signed(1),
+					report_id,
+					GeoPoint { x: 10, y }
+				));
+			}
+		}
+		assert_noop!(
+			WasteManagement::update_location(
+				RuntimeOrigin::signed(1),
+				rejected,
+				GeoPoint { x: 10, y: 24 }
+			),
+			Error::<Test>::LocationHistoryFull
+		);
+
+		RouteHistoryMode::set(crate::RouteHistoryMode::DropOldest);
+		for y in 24..=25 {
+			assert_ok!(WasteManagement::update_location(
+				RuntimeOrigin::signed(1),
+				dropped,
+				GeoPoint { x: 10, y }
+			));
+		}
+		let route: Vec<u32> = crate::LocationHistory::<Test>::get(dropped)
+			.unwrap()
+			.into_iter()
+			.map(|location| location.y)
+			.collect();
+		assert_eq!(route, vec![22, 23, 24, 25]);
+		assert_eq!(WasteManagement::route_distance_sq(dropped), 3);
+	});
+}
+
+#[test]
+fn delete_reports_of_purges_up_to_max() {
+	new_test_ext().execute_with(|| {
+		let utilized = create_report(1, GLASS, 100);
+		advance_to(utilized, WasteStatus::Utilized);
+		let open: Vec<_> = (0..4).map(|_| create_report(1, PLASTIC, 10)).collect();
+		let other = create_report(2, PLASTIC, 10);
+
+		assert_noop!(
+			WasteManagement::delete_reports_of(RuntimeOrigin::signed(1), 1, 3, false),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::delete_reports_of(RuntimeOrigin::root(), 1, 3, false));
+		System::assert_last_event(
+			Event::ReportsPurged {
+				account: 1,
+				count: 3,
+				truncated: true,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		assert_eq!(WasteManagement::reports_of(&1), vec![utilized, open[3]]);
+		assert_eq!(WasteManagement::reports_of(&2), vec![other]);
+		for report_id in &open[..3] {
+			assert!(crate::WasteDataMap::<Test>::get(report_id).is_none());
+		}
+		assert_eq!(crate::ActiveReportCount::<Test>::get(), 3);
+		assert_ok!(WasteManagement::do_try_state());
+
+		assert_ok!(WasteManagement::delete_reports_of(RuntimeOrigin::root(), 1, 5, true));
+		System::assert_last_event(
+			Event::ReportsPurged {
+				account: 1,
+				count: 2,
+				truncated: false,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert!(WasteManagement::reports_of(&1).is_empty());
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn utilized_needs_two_operators_when_guarded() {
+	new_test_ext().execute_with(|| {
+		RequiredApprovals::set((WasteStatus::Utilized, 2));
+		let second_operator = 7;
+		assert_ok!(WasteManagement::add_operator(RuntimeOrigin::root(), second_operator));
+		let report_id = create_report(1, GLASS, 100);
+		advance_to(report_id, WasteStatus::Transported);
+
+		assert_ok!(set_status(report_id, WasteStatus::Utilized));
+		System::assert_last_event(
+			Event::ApprovalRecorded {
+				report_id,
+				status: WasteStatus::Utilized,
+				approver: OPERATOR,
+				approvals: 1,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		let report = crate::WasteDataMap::<Test>::get(report_id).unwrap();
+		assert_eq!(report.status, WasteStatus::Transported);
+
+		assert_noop!(
+			set_status(report_id, WasteStatus::Utilized).map_err(|e| e.error),
+			Error::<Test>::DuplicateApproval
+		);
+
+		assert_ok!(WasteManagement::update_waste_status(
+			RuntimeOrigin::signed(second_operator),
+			report_id,
+			WasteStatus::Utilized,
+			None
+		));
+		assert!(emitted!(
+			Event::ApprovalRecorded {
+				report_id: id,
+				status: WasteStatus::Utilized,
+				approver,
+				approvals: 2,
+				..
+			} if id == report_id && approver == second_operator
+		));
+		System::assert_has_event(
+			Event::WasteStatusUpdated {
+				report_id,
+				operator: second_operator,
+				old_status: WasteStatus::Transported,
+				new_status: WasteStatus::Utilized,
+				collector: Some(COLLECTOR),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		let report = crate::WasteDataMap::<Test>::get(report_id).unwrap();
+		assert_eq!(report.status, WasteStatus::Utilized);
+		assert!(!crate::PendingApprovals::<Test>::contains_key((report_id, WasteStatus::Utilized)));
+	});
+}
+
+#[test]
+fn guarded_status_cant_be_set_in_bulk() {
+	new_test_ext().execute_with(|| {
+		RequiredApprovals::set((WasteStatus::Utilized, 2));
+		let report_id = create_report(1, GLASS, 100);
+		advance_to(report_id, WasteStatus::Transported);
+
+		assert_noop!(
+			WasteManagement::bulk_update_status(
+				RuntimeOrigin::signed(OPERATOR),
+				WasteStatus::Transported,
+				WasteStatus::Utilized,
+				5
+			),
+			Error::<Test>::ApprovalRequired
+		);
+	});
+}
+
+#[test]
+fn views_wrap_storage() {
+	new_test_ext().execute_with(|| {
+		let first = create_report(1, GLASS, 100);
+		let second = create_report(1, PLASTIC, 10);
+		let third = create_report(3, PLASTIC, 10);
+		advance_to(second, WasteStatus::Collected);
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(3), third));
+
+		assert_eq!(WasteManagement::active_count(), 2);
+		assert!(WasteManagement::exists(first));
+		assert!(!WasteManagement::exists(third));
+		assert_eq!(WasteManagement::status_of(second), Some(WasteStatus::Collected));
+		assert_eq!(WasteManagement::status_of(third), None);
+		assert_eq!(WasteManagement::all_report_ids(None, 10), (vec![first, second], None));
+		assert_eq!(WasteManagement::all_report_ids(None, 1), (vec![first], Some(first)));
+		assert_eq!(WasteManagement::all_report_ids(Some(first), 1), (vec![second], Some(second)));
+	});
+}
+
+#[test]
+fn drifted_aggregate_saturates_and_raises_the_flag() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_ok!(collect(report_id));
+		assert!(!WasteManagement::aggregates_drifted());
+
+		// Simulate an index bug that lost track of part of the amount.
+		crate::TotalAmountByStatus::<Test>::insert(WasteStatus::Collected, 40);
+		assert_ok!(set_status(report_id, WasteStatus::Transported));
+
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), 0);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Transported), 100);
+		assert!(WasteManagement::aggregates_drifted());
+	});
+}
+
+#[test]
+fn transported_load_can_be_rejected_without_reward() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		advance_to(report_id, WasteStatus::Transported);
+		let balance = Balances::free_balance(1);
+
+		assert_ok!(WasteManagement::reject_report(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			reason(b"contaminated")
+		));
+		System::assert_last_event(
+			Event::ReportRejected { report_id, reason_len: 12, seq: WasteManagement::event_seq() }
+				.into(),
+		);
+
+		assert_eq!(WasteManagement::status_of(report_id), Some(WasteStatus::Rejected));
+		assert_eq!(
+			WasteManagement::reports_with_status(WasteStatus::Rejected)
+				.into_iter()
+				.map(|report| report.report_id)
+				.collect::<Vec<_>>(),
+			vec![report_id]
+		);
+		assert_eq!(crate::RejectionReasons::<Test>::get(report_id), Some(reason(b"contaminated")));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Rejected), 100);
+		assert_eq!(Balances::free_balance(1), balance);
+		assert_eq!(crate::PendingRewards::<Test>::get(1), 0);
+		assert_noop!(
+			set_status(report_id, WasteStatus::Utilized).map_err(|e| e.error),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn only_transported_loads_can_be_rejected() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+
+		assert_noop!(
+			WasteManagement::reject_report(
+				RuntimeOrigin::signed(OPERATOR),
+				report_id,
+				reason(b"contaminated")
+			),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_noop!(
+			WasteManagement::reject_report(
+				RuntimeOrigin::signed(1),
+				report_id,
+				reason(b"contaminated")
+			),
+			Error::<Test>::NotAuthorizedForStatus
+		);
+
+		// Rejection always carries a reason, so it can't be set as a plain status.
+		advance_to(report_id, WasteStatus::Transported);
+		assert_noop!(
+			set_status(report_id, WasteStatus::Rejected).map_err(|e| e.error),
+			Error::<Test>::InvalidStatusTransition
+		);
+	});
+}
+
+#[test]
+fn report_events_carry_report_and_reporter_topics() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		let topics =
+			vec![WasteManagement::report_topic(report_id), WasteManagement::reporter_topic(&1)];
+		assert_ne!(topics[0], topics[1]);
+		assert_eq!(System::events().last().unwrap().topics, topics);
+
+		assign_collector(report_id);
+		assert_ok!(set_status(report_id, WasteStatus::Collected));
+		let record = System::events().pop().unwrap();
+		assert!(matches!(
+			record.event,
+			RuntimeEvent::WasteManagement(Event::WasteStatusUpdated { .. })
+		));
+		assert_eq!(record.topics, topics);
+	});
+}
+
+#[test]
+fn bounded_scan_reports_truncation_past_max() {
+	let scan = |items: u32, max: u32| {
+		let mut seen = Vec::new();
+		let truncated = WasteManagement::bounded_scan(0..items, max, |item| seen.push(item));
+		(seen.len() as u32, truncated)
+	};
+
+	assert_eq!(scan(3, 3), (3, false));
+	assert_eq!(scan(4, 3), (3, true));
+	assert_eq!(scan(0, 0), (0, false));
+	assert_eq!(scan(1, 0), (0, true));
+}
+
+#[test]
+fn bulk_update_status_reports_truncation() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..4 {
+			let report_id = create_report(1, GLASS, 10);
+			assign_collector(report_id);
+		}
+
+		assert_ok!(WasteManagement::bulk_update_status(
+			RuntimeOrigin::signed(OPERATOR),
+			WasteStatus::Reported,
+			WasteStatus::Collected,
+			3
+		));
+		System::assert_last_event(
+			Event::BulkStatusUpdated {
+				count: 3,
+				truncated: true,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		assert_ok!(WasteManagement::bulk_update_status(
+			RuntimeOrigin::signed(OPERATOR),
+			WasteStatus::Reported,
+			WasteStatus::Collected,
+			1
+		));
+		System::assert_last_event(
+			Event::BulkStatusUpdated {
+				count: 1,
+				truncated: false,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn proxy_updates_status_on_behalf_of_operator() {
+	new_test_ext().execute_with(|| {
+		let proxy = 8;
+		let report_id = create_report(1, GLASS, 100);
+		assign_collector(report_id);
+
+		assert_noop!(
+			WasteManagement::add_proxy(RuntimeOrigin::signed(proxy), OPERATOR, proxy),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::add_proxy(RuntimeOrigin::signed(OPERATOR), OPERATOR, proxy));
+		System::assert_last_event(
+			Event::ProxyAdded { principal: OPERATOR, proxy, seq: WasteManagement::event_seq() }
+				.into(),
+		);
+
+		assert_ok!(WasteManagement::update_waste_status(
+			RuntimeOrigin::signed(proxy),
+			report_id,
+			WasteStatus::Collected,
+			Some(OPERATOR)
+		));
+		System::assert_last_event(
+			Event::WasteStatusUpdated {
+				report_id,
+				operator: OPERATOR,
+				old_status: WasteStatus::Reported,
+				new_status: WasteStatus::Collected,
+				collector: Some(COLLECTOR),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		// The proxy has no permissions of its own.
+		assert_noop!(
+			WasteManagement::update_waste_status(
+				RuntimeOrigin::signed(proxy),
+				report_id,
+				WasteStatus::Transported,
+				None
+			),
+			Error::<Test>::NotAuthorizedForStatus
+		);
+
+		assert_ok!(WasteManagement::remove_proxy(RuntimeOrigin::root(), OPERATOR, proxy));
+		System::assert_last_event(
+			Event::ProxyRemoved { principal: OPERATOR, proxy, seq: WasteManagement::event_seq() }
+				.into(),
+		);
+		assert_noop!(
+			WasteManagement::update_waste_status(
+				RuntimeOrigin::signed(proxy),
+				report_id,
+				WasteStatus::Transported,
+				Some(OPERATOR)
+			),
+			Error::<Test>::NotAProxy
+		);
+	});
+}
+
+#[test]
+fn unapproved_account_cant_act_for_an_operator() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assign_collector(report_id);
+
+		assert_noop!(
+			WasteManagement::update_waste_status(
+				RuntimeOrigin::signed(3),
+				report_id,
+				WasteStatus::Collected,
+				Some(OPERATOR)
+			),
+			Error::<Test>::NotAProxy
+		);
+	});
+}
+
+#[test]
+fn status_index_round_trips_and_matches_encoding() {
+	use codec::Encode;
+
+	assert_eq!(WasteStatus::all().len(), 6);
+	for status in WasteStatus::all() {
+		assert_eq!(WasteStatus::from_index(status.as_index()).as_ref(), Some(status));
+		assert_eq!(status.encode(), vec![status.as_index()]);
+	}
+	assert_eq!(WasteStatus::from_index(6), None);
+}
+
+#[test]
+fn min_amount_is_enforced_per_waste_type() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(WasteManagement::set_min_amount(RuntimeOrigin::root(), GLASS, Some(50)));
+		System::assert_last_event(
+			Event::MinAmountSet {
+				waste_type: GLASS,
+				min_amount: Some(50),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 49)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::AmountBelowMinimum
+		);
+		create_report(1, PLASTIC, 49);
+		create_report(1, GLASS, 50);
+
+		// Other types fall back to the default minimum of 1.
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(PLASTIC, 10), (ORGANIC, 0)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::AmountBelowMinimum
+		);
+
+		assert_ok!(WasteManagement::set_min_amount(RuntimeOrigin::root(), GLASS, None));
+		create_report(2, GLASS, 1);
+	});
+}
+
+fn amounts(
+	items: &[WasteAmount],
+) -> frame_support::BoundedVec<WasteAmount, <Test as crate::Config>::MaxSplit> {
+	items.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn split_report_files_children_for_part_of_the_pile() {
+	new_test_ext().execute_with(|| {
+		let parent = create_report(1, GLASS, 100);
+
+		assert_ok!(WasteManagement::split_report(
+			RuntimeOrigin::signed(OPERATOR),
+			parent,
+			amounts(&[30, 50])
+		));
+		let children = vec![parent + 1, parent + 2];
+		System::assert_last_event(
+			Event::ReportSplit {
+				parent,
+				children: children.clone().try_into().unwrap(),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		for (child, amount) in children.iter().zip([30, 50]) {
+			let report = crate::WasteDataMap::<Test>::get(child).unwrap();
+			assert_eq!(report.parent, Some(parent));
+			assert_eq!((report.waste_type, report.waste_amount), (GLASS, amount));
+			assert_eq!((report.reporter, report.status), (1, WasteStatus::Reported));
+		}
+		let report = crate::WasteDataMap::<Test>::get(parent).unwrap();
+		assert_eq!((report.waste_amount, report.parent), (20, None));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 100);
+		assert_eq!(WasteManagement::reports_of(&1), vec![parent, parent + 1, parent + 2]);
+		assert_ok!(WasteManagement::do_try_state());
+
+		assert_noop!(
+			WasteManagement::split_report(RuntimeOrigin::signed(1), parent, amounts(&[10])),
+			Error::<Test>::CannotSplit
+		);
+	});
+}
+
+#[test]
+fn split_report_rejects_over_allocation() {
+	new_test_ext().execute_with(|| {
+		let parent = create_report(1, GLASS, 100);
+
+		assert_noop!(
+			WasteManagement::split_report(RuntimeOrigin::signed(1), parent, amounts(&[60, 50])),
+			Error::<Test>::SplitExceedsAmount
+		);
+		assert_noop!(
+			WasteManagement::split_report(RuntimeOrigin::signed(3), parent, amounts(&[10])),
+			Error::<Test>::NotReportOwner
+		);
+		assert_ok!(WasteManagement::split_report(
+			RuntimeOrigin::signed(1),
+			parent,
+			amounts(&[60, 40])
+		));
+		assert_eq!(crate::WasteDataMap::<Test>::get(parent).unwrap().waste_amount, 0);
+	});
+}
+
+#[test]
+fn allowlist_gates_creation_when_required() {
+	new_test_ext().execute_with(|| {
+		RequireAllowlist::set(true);
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 100)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ReporterNotAllowed
+		);
+
+		assert_noop!(
+			WasteManagement::allow_reporter(RuntimeOrigin::signed(1), 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::allow_reporter(RuntimeOrigin::root(), 1));
+		System::assert_last_event(
+			Event::ReporterAllowed { account: 1, seq: WasteManagement::event_seq() }.into(),
+		);
+		create_report(1, GLASS, 100);
+
+		assert_ok!(WasteManagement::disallow_reporter(RuntimeOrigin::root(), 1));
+		System::assert_last_event(
+			Event::ReporterDisallowed { account: 1, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 100)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ReporterNotAllowed
+		);
+	});
+}
+
+#[test]
+fn allowlist_is_ignored_when_not_required() {
+	new_test_ext().execute_with(|| {
+		assert!(!crate::AllowedReporters::<Test>::contains_key(1));
+		create_report(1, GLASS, 100);
+	});
+}
+
+#[test]
+fn failed_insert_does_not_advance_the_next_id() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		// A stray entry under the next id, as if `NextReportId` had fallen behind the map.
+		let stray = crate::WasteDataMap::<Test>::get(report_id).unwrap();
+		crate::WasteDataMap::<Test>::insert(report_id + 1, stray);
+
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 100)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::DuplicateReport
+		);
+		assert_eq!(WasteManagement::next_report_id(), report_id + 1);
+	});
+}
+
+#[test]
+fn vetoed_report_does_not_advance_the_next_id() {
+	new_test_ext().execute_with(|| {
+		let name = b"unsorted".to_vec().try_into().unwrap();
+		assert_ok!(WasteManagement::register_waste_type(
+			RuntimeOrigin::root(),
+			WasteCategory::Other(0),
+			name
+		));
+		let report_id = create_report(1, GLASS, 100);
+
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(WasteCategory::Other(0), 100)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			sp_runtime::DispatchError::Other("waste type 0 is not accepted")
+		);
+		assert_eq!(WasteManagement::next_report_id(), report_id + 1);
+		assert_eq!(create_report(1, GLASS, 100), report_id + 1);
+	});
+}
+
+#[test]
+fn nearest_reports_expands_rings_until_the_closest_are_found() {
+	new_test_ext().execute_with(|| {
+		let report_at = |x, y| {
+			assert_ok!(WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 10)]),
+				0,
+				GeoPoint { x, y },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+			WasteManagement::next_report_id() - 1
+		};
+		let same_cell = report_at(160, 150);
+		let corner = report_at(100, 100);
+		let next_cell = report_at(150, 250);
+		let far = report_at(420, 150);
+		let point = GeoPoint { x: 150, y: 150 };
+
+		assert_eq!(
+			WasteManagement::nearest_reports(point, 3, None),
+			vec![(same_cell, 100), (corner, 5_000), (next_cell, 10_000)]
+		);
+
+		assert_ok!(collect(corner));
+		assert_eq!(
+			WasteManagement::nearest_reports(point, 3, Some(WasteStatus::Reported)),
+			vec![(same_cell, 100), (next_cell, 10_000), (far, 72_900)]
+		);
+		assert_eq!(
+			WasteManagement::nearest_reports(point, 5, Some(WasteStatus::Collected)),
+			vec![(corner, 5_000)]
+		);
+		assert!(WasteManagement::nearest_reports(point, 0, None).is_empty());
+	});
+}
+
+#[test]
+fn runtime_upgrade_clears_a_half_finished_batch() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	new_test_ext().execute_with(|| {
+		for amount in [10, 20, 30] {
+			create_report(1, PLASTIC, amount);
+		}
+		assert_ok!(WasteManagement::recompute_aggregates(RuntimeOrigin::root(), 2));
+		assert!(crate::BatchCursors::<Test>::get(crate::BatchKind::RecomputeAggregates).is_some());
+
+		<WasteManagement as OnRuntimeUpgrade>::on_runtime_upgrade();
+		assert!(emitted!(Event::PendingBatchCleared {
+			kind: crate::BatchKind::RecomputeAggregates,
+			..
+		}));
+		assert_eq!(crate::BatchCursors::<Test>::iter().count(), 0);
+		assert_eq!(crate::RecomputedStatusTotals::<Test>::iter().count(), 0);
+		assert_eq!(crate::RecomputedTypeTotals::<Test>::iter().count(), 0);
+		assert_eq!(WasteManagement::total_amount_of_type(PLASTIC), 60);
+		assert_ok!(WasteManagement::do_try_state());
+
+		// The job starts over from the first report.
+		assert_ok!(WasteManagement::recompute_aggregates(RuntimeOrigin::root(), 10));
+		System::assert_last_event(
+			Event::AggregatesRecomputed { reports: 3, seq: WasteManagement::event_seq() }.into(),
+		);
+	});
+}
+
+#[test]
+fn runtime_upgrade_reconciles_the_counters() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	new_test_ext().execute_with(|| {
+		let kept = create_report(1, GLASS, 100);
+		let deleted = create_report(1, GLASS, 100);
+		let cancelled = create_report(1, GLASS, 100);
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), deleted));
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			cancelled,
+			reason(b"duplicate")
+		));
+
+		<WasteManagement as OnRuntimeUpgrade>::on_runtime_upgrade();
+		System::assert_last_event(
+			Event::StorageReconciled {
+				map_entries: 2,
+				active_count: 1,
+				next_id: kept + 3,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn batch_weight_grows_with_the_batch() {
+	use frame_support::dispatch::GetDispatchInfo;
+
+	let batch_weight = |n: u32| {
+		let reports = (0..n)
+			.map(|i| (GLASS, 10, 0, GeoPoint { x: i, y: 20 }, Severity::Low))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		RuntimeCall::WasteManagement(crate::Call::create_waste_data_batch { reports })
+			.get_dispatch_info()
+			.weight
+	};
+	let bulk_weight = |max: u32| {
+		RuntimeCall::WasteManagement(crate::Call::bulk_update_status {
+			from_status: WasteStatus::Reported,
+			to_status: WasteStatus::Collected,
+			max,
+		})
+		.get_dispatch_info()
+		.weight
+	};
+
+	let per_report = batch_weight(2) - batch_weight(1);
+	assert!(per_report.any_gt(frame_support::weights::Weight::zero()));
+	assert_eq!(batch_weight(5) - batch_weight(1), per_report * 4);
+
+	let per_move = bulk_weight(2) - bulk_weight(1);
+	assert!(per_move.any_gt(frame_support::weights::Weight::zero()));
+	assert_eq!(bulk_weight(10) - bulk_weight(1), per_move * 9);
+}
+
+#[test]
+fn rewards_accrue_until_claimed() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			let report_id = create_report(1, GLASS, 100);
+			advance_to(report_id, WasteStatus::Utilized);
+		}
+		let accrued = CompletionReward::get() * 3;
+		assert_eq!(crate::PendingRewards::<Test>::get(1), accrued);
+		let issuance = Balances::total_issuance();
+
+		assert_ok!(WasteManagement::claim_rewards(RuntimeOrigin::signed(1)));
+		System::assert_last_event(
+			Event::RewardsClaimed { who: 1, amount: accrued, seq: WasteManagement::event_seq() }
+				.into(),
+		);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE + accrued);
+		assert_eq!(Balances::total_issuance(), issuance + accrued);
+		assert!(!crate::PendingRewards::<Test>::contains_key(1));
+
+		assert_noop!(
+			WasteManagement::claim_rewards(RuntimeOrigin::signed(1)),
+			Error::<Test>::NoPendingRewards
+		);
+	});
+}
+
+#[test]
+fn treasury_cut_is_split_off_completion_rewards() {
+	use sp_runtime::Permill;
+
+	new_test_ext().execute_with(|| {
+		// (reward, cut, treasury share), with the reporter getting whatever the treasury doesn't.
+		let cases = [
+			(5, Permill::from_percent(33), 1),
+			(7, Permill::from_percent(50), 3),
+			(1_000, Permill::from_parts(333_333), 333),
+			(100, Permill::one(), 100),
+			(1, Permill::from_parts(999_999), 0),
+		];
+		for (reward, cut, treasury_amount) in cases {
+			CompletionReward::set(reward);
+			TreasuryCut::set(cut);
+			let reporter_before = crate::PendingRewards::<Test>::get(1);
+			let treasury_before = crate::PendingRewards::<Test>::get(TREASURY);
+			let report_id = create_report(1, GLASS, 100);
+			advance_to(report_id, WasteStatus::Utilized);
+
+			let beneficiary_amount = crate::PendingRewards::<Test>::get(1) - reporter_before;
+			assert_eq!(
+				crate::PendingRewards::<Test>::get(TREASURY) - treasury_before,
+				treasury_amount
+			);
+			assert_eq!(beneficiary_amount + treasury_amount, reward);
+			assert_eq!(
+				emitted!(
+					Event::RewardSplit {
+						report_id: id,
+						beneficiary_amount: to_reporter,
+						treasury_amount: to_treasury,
+						..
+					} if id == report_id &&
+						to_reporter == beneficiary_amount &&
+						to_treasury == treasury_amount
+				),
+				treasury_amount > 0
+			);
+		}
+
+		// The treasury claims its cut like any reporter.
+		assert_ok!(WasteManagement::claim_rewards(RuntimeOrigin::signed(TREASURY)));
+		assert_eq!(Balances::free_balance(TREASURY), INITIAL_BALANCE + 1 + 3 + 333 + 100);
+	});
+}
+
+fn create_report_with_volume(who: u64, waste_amount: WasteAmount, volume: u64) -> ReportId {
+	assert_ok!(WasteManagement::create_waste_data(
+		RuntimeOrigin::signed(who),
+		components(&[(GLASS, waste_amount)]),
+		volume,
+		GeoPoint { x: 10, y: 20 },
+		Severity::Low,
+		None,
+		None,
+		None,
+		None,
+		None
+	));
+	WasteManagement::next_report_id() - 1
+}
+
+#[test]
+fn volume_totals_follow_mass_totals() {
+	new_test_ext().execute_with(|| {
+		let first = create_report_with_volume(1, 100, 40);
+		System::assert_last_event(
+			Event::WasteDataCreated {
+				report_id: first,
+				reporter: 1,
+				severity: Severity::Low,
+				waste_type: GLASS,
+				waste_amount: 100,
+				volume: 40,
+				origin_tag_len: 0,
+				status: WasteStatus::Reported,
+				has_location: true,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		let second = create_report_with_volume(1, 30, 25);
+		assert_eq!(WasteManagement::total_volume_in(WasteStatus::Reported), 65);
+
+		assert_ok!(collect(first));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 30);
+		assert_eq!(WasteManagement::total_volume_in(WasteStatus::Reported), 25);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), 100);
+		assert_eq!(WasteManagement::total_volume_in(WasteStatus::Collected), 40);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), second));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 0);
+		assert_eq!(WasteManagement::total_volume_in(WasteStatus::Reported), 0);
+		assert_ok!(WasteManagement::do_try_state());
+
+		crate::TotalVolumeByStatus::<Test>::insert(WasteStatus::Collected, 41);
+		assert_eq!(
+			WasteManagement::do_try_state(),
+			Err("TotalVolumeByStatus doesn't match the reports in that status")
+		);
+	});
+}
+
+#[test]
+fn volume_above_the_maximum_is_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 100)]),
+				MAX_VOLUME + 1,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::VolumeTooLarge
+		);
+		create_report_with_volume(1, 100, MAX_VOLUME);
+	});
+}
+
+#[test]
+fn split_report_shares_the_volume_by_amount() {
+	new_test_ext().execute_with(|| {
+		let parent = create_report_with_volume(1, 100, 50);
+
+		assert_ok!(WasteManagement::split_report(
+			RuntimeOrigin::signed(1),
+			parent,
+			amounts(&[30, 45])
+		));
+		let volumes = [parent, parent + 1, parent + 2]
+			.map(|report_id| crate::WasteDataMap::<Test>::get(report_id).unwrap().volume);
+		// 15 and 22.5, rounded down, with the parent keeping the rest.
+		assert_eq!(volumes, [13, 15, 22]);
+		assert_eq!(WasteManagement::total_volume_in(WasteStatus::Reported), 50);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+fn create_report_with_key(who: u64, key: crate::IdempotencyKey) -> ReportId {
+	assert_ok!(WasteManagement::create_waste_data(
+		RuntimeOrigin::signed(who),
+		components(&[(GLASS, 100)]),
+		0,
+		GeoPoint { x: 10, y: 20 },
+		Severity::Low,
+		None,
+		None,
+		Some(key),
+		None,
+		None
+	));
+	WasteManagement::next_report_id() - 1
+}
+
+#[test]
+fn first_submit_under_a_key_files_a_report() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report_with_key(1, [7; 16]);
+
+		assert!(WasteManagement::exists(report_id));
+		assert_eq!(crate::SeenKeys::<Test>::get(1, [7; 16]), Some(report_id));
+	});
+}
+
+#[test]
+fn duplicate_submit_returns_the_existing_report() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report_with_key(1, [7; 16]);
+		let balance = Balances::free_balance(1);
+
+		assert_eq!(create_report_with_key(1, [7; 16]), report_id);
+		System::assert_last_event(
+			Event::KeyReused {
+				reporter: 1,
+				key: [7; 16],
+				report_id,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_eq!(WasteManagement::waste_data_count(), 1);
+		assert_eq!(WasteManagement::reports_of(&1), vec![report_id]);
+		assert_eq!(Balances::free_balance(1), balance);
+		assert_eq!(
+			WasteManagement::do_create_waste_data(
+				&1,
+				components(&[(PLASTIC, 5)]),
+				0,
+				Some(GeoPoint { x: 30, y: 40 }),
+				Severity::High,
+				None,
+				None,
+				Some([7; 16]),
+				None,
+			),
+			Ok(report_id)
+		);
+		assert_eq!(WasteManagement::waste_data_count(), 1);
+	});
+}
+
+#[test]
+fn a_key_is_free_again_once_its_report_is_deleted() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report_with_key(1, [7; 16]);
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+
+		let resubmitted = create_report_with_key(1, [7; 16]);
+		assert_ne!(resubmitted, report_id);
+		assert!(WasteManagement::exists(resubmitted));
+		assert!(!emitted!(Event::KeyReused { .. }));
+		assert_eq!(crate::SeenKeys::<Test>::get(1, [7; 16]), Some(resubmitted));
+		// Nothing is logged against the deleted report.
+		assert!(!crate::ReportEventLog::<Test>::contains_key(report_id));
+	});
+}
+
+#[test]
+fn a_different_key_or_reporter_files_a_new_report() {
+	new_test_ext().execute_with(|| {
+		let first = create_report_with_key(1, [7; 16]);
+
+		let second = create_report_with_key(1, [8; 16]);
+		assert_ne!(second, first);
+		// Keys are scoped to the reporter.
+		let third = create_report_with_key(3, [7; 16]);
+		assert_ne!(third, first);
+		assert_eq!(crate::SeenKeys::<Test>::get(3, [7; 16]), Some(third));
+		assert_eq!(WasteManagement::waste_data_count(), 3);
+	});
+}
+
+#[test]
+fn migrate_status_moves_reports_in_batches() {
+	new_test_ext().execute_with(|| {
+		let reports: Vec<_> = (0..3).map(|_| create_report(1, GLASS, 10)).collect();
+		let collected = create_report(1, GLASS, 5);
+		assert_ok!(collect(collected));
+
+		assert_noop!(
+			WasteManagement::migrate_status(
+				RuntimeOrigin::signed(OPERATOR),
+				WasteStatus::Reported,
+				WasteStatus::Collected,
+				10
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			WasteManagement::migrate_status(
+				RuntimeOrigin::root(),
+				WasteStatus::Reported,
+				WasteStatus::Reported,
+				10
+			),
+			Error::<Test>::StatusUnchanged
+		);
+
+		assert_ok!(WasteManagement::migrate_status(
+			RuntimeOrigin::root(),
+			WasteStatus::Reported,
+			WasteStatus::Collected,
+			2
+		));
+		System::assert_last_event(
+			Event::StatusMigrated {
+				from: WasteStatus::Reported,
+				to: WasteStatus::Collected,
+				count: 2,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_ok!(WasteManagement::migrate_status(
+			RuntimeOrigin::root(),
+			WasteStatus::Reported,
+			WasteStatus::Collected,
+			2
+		));
+		System::assert_last_event(
+			Event::StatusMigrated {
+				from: WasteStatus::Reported,
+				to: WasteStatus::Collected,
+				count: 1,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		assert_eq!(
+			crate::WasteDataByStatus::<Test>::iter_key_prefix(WasteStatus::Reported).count(),
+			0
+		);
+		for report_id in reports {
+			assert_eq!(WasteManagement::status_of(report_id), Some(WasteStatus::Collected));
+			assert_eq!(
+				crate::StatusHistory::<Test>::get(report_id).unwrap().last().unwrap().0,
+				WasteStatus::Collected
+			);
+		}
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 0);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), 35);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn complete_metadata_lowers_the_charged_weight() {
+	new_test_ext().execute_with(|| {
+		let file = |severity, contact, x| {
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(GLASS, 100)]),
+				0,
+				GeoPoint { x, y: 20 },
+				severity,
+				contact,
+				None,
+				None,
+				None,
+				None,
+			)
+			.unwrap()
+			.actual_weight
+			.unwrap()
+		};
+		let declared = <() as crate::WeightInfo>::create_waste_data();
+
+		let bare = file(Severity::Low, None, 10);
+		assert_eq!(bare, declared);
+		let triaged = file(Severity::High, None, 20);
+		let complete = file(Severity::High, Some(b"+41 79".to_vec().try_into().unwrap()), 30);
+		assert!(complete.ref_time() < triaged.ref_time() && triaged.ref_time() < bare.ref_time());
+		assert_eq!(complete, declared - sp_runtime::Perbill::from_percent(20) * declared);
+	});
+}
+
+#[test]
+fn merge_reports_folds_a_duplicate_into_the_kept_report() {
+	new_test_ext().execute_with(|| {
+		let keep = create_report(1, GLASS, 100);
+		let absorb = WasteManagement::do_create_waste_data(
+			&3,
+			components(&[(GLASS, 20), (PLASTIC, 5)]),
+			10,
+			Some(GeoPoint { x: 12, y: 20 }),
+			Severity::Low,
+			None,
+			None,
+			None,
+			None,
+		)
+		.unwrap();
+
+		assert_noop!(
+			WasteManagement::merge_reports(RuntimeOrigin::signed(1), keep, absorb),
+			Error::<Test>::NotAuthorizedOperator
+		);
+		assert_ok!(WasteManagement::merge_reports(RuntimeOrigin::signed(OPERATOR), keep, absorb));
+		System::assert_last_event(
+			Event::ReportsMerged { keep, absorb, seq: WasteManagement::event_seq() }.into(),
+		);
+
+		let report = WasteManagement::waste_data(keep).unwrap();
+		assert_eq!((report.waste_amount, report.volume), (125, 10));
+		assert_eq!(report.components.into_inner(), vec![(GLASS, 120), (PLASTIC, 5)]);
+		assert!(!WasteManagement::exists(absorb));
+		assert_eq!(crate::MergedInto::<Test>::get(absorb), Some(keep));
+		assert_eq!(WasteManagement::reports_of(&3), Vec::<ReportId>::new());
+		assert_eq!(Balances::reserved_balance(3), 0);
+
+		// Nothing is counted twice.
+		assert_eq!(WasteManagement::active_count(), 1);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 125);
+		assert_eq!(WasteManagement::total_amount_of_type(GLASS), 120);
+		assert_eq!(WasteManagement::total_amount_of_type(PLASTIC), 5);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn merge_reports_rejects_self_merge_and_missing_reports() {
+	new_test_ext().execute_with(|| {
+		let keep = create_report(1, GLASS, 100);
+
+		assert_noop!(
+			WasteManagement::merge_reports(RuntimeOrigin::signed(OPERATOR), keep, keep),
+			Error::<Test>::MergeWithSelf
+		);
+		assert_noop!(
+			WasteManagement::merge_reports(RuntimeOrigin::signed(OPERATOR), keep, 99),
+			Error::<Test>::ReportNotFound
+		);
+
+		let utilized = create_report(1, GLASS, 10);
+		advance_to(utilized, WasteStatus::Utilized);
+		assert_noop!(
+			WasteManagement::merge_reports(RuntimeOrigin::signed(OPERATOR), keep, utilized),
+			Error::<Test>::CannotMerge
+		);
+	});
+}
+
+#[test]
+fn settling_reports_cannot_be_merged() {
+	new_test_ext().execute_with(|| {
+		let settling = create_report(1, GLASS, 100);
+		advance_to(settling, WasteStatus::Transported);
+		assert_ok!(WasteManagement::prepare_settlement(settling));
+		let other = create_report(3, GLASS, 20);
+
+		// Neither growing the escrowed report nor merging it away is allowed.
+		assert_noop!(
+			WasteManagement::merge_reports(RuntimeOrigin::signed(OPERATOR), settling, other),
+			Error::<Test>::CannotMerge
+		);
+		assert_noop!(
+			WasteManagement::merge_reports(RuntimeOrigin::signed(OPERATOR), other, settling),
+			Error::<Test>::CannotMerge
+		);
+		assert_eq!(WasteManagement::waste_data(settling).unwrap().waste_amount, 100);
+	});
+}
+
+#[test]
+fn status_can_be_updated_by_index() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assign_collector(report_id);
+
+		assert_ok!(WasteManagement::update_waste_status_by_index(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			WasteStatus::Collected.as_index()
+		));
+		assert_eq!(WasteManagement::status_of(report_id), Some(WasteStatus::Collected));
+		System::assert_last_event(
+			Event::WasteStatusUpdated {
+				report_id,
+				operator: OPERATOR,
+				old_status: WasteStatus::Reported,
+				new_status: WasteStatus::Collected,
+				collector: Some(COLLECTOR),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		// The same rules apply as to `update_waste_status`.
+		assert_noop!(
+			WasteManagement::update_waste_status_by_index(
+				RuntimeOrigin::signed(OPERATOR),
+				report_id,
+				WasteStatus::Cancelled.as_index()
+			),
+			Error::<Test>::InvalidStatusTransition
+		);
+	});
+}
+
+#[test]
+fn unknown_status_index_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+
+		assert_noop!(
+			WasteManagement::update_waste_status_by_index(
+				RuntimeOrigin::signed(OPERATOR),
+				report_id,
+				WasteStatus::all().len() as u8
+			),
+			Error::<Test>::InvalidStatusIndex
+		);
+		assert_eq!(WasteManagement::status_of(report_id), Some(WasteStatus::Reported));
+	});
+}
+
+#[test]
+fn archive_completed_moves_old_completed_reports_only() {
+	new_test_ext().execute_with(|| {
+		let old: Vec<_> = (0..2).map(|_| create_report(1, GLASS, 10)).collect();
+		for report_id in &old {
+			advance_to(*report_id, WasteStatus::Utilized);
+		}
+		let open = create_report(1, GLASS, 20);
+		System::set_block_number(10);
+		let recent = create_report(1, GLASS, 30);
+		advance_to(recent, WasteStatus::Utilized);
+
+		assert_noop!(
+			WasteManagement::archive_completed(RuntimeOrigin::signed(OPERATOR), 5, 10),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::archive_completed(RuntimeOrigin::root(), 5, 1));
+		System::assert_last_event(
+			Event::ReportsArchived { count: 1, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_ok!(WasteManagement::archive_completed(RuntimeOrigin::root(), 5, 10));
+		System::assert_last_event(
+			Event::ReportsArchived { count: 1, seq: WasteManagement::event_seq() }.into(),
+		);
+
+		for report_id in old {
+			assert!(!WasteManagement::exists(report_id));
+			let archived = WasteManagement::get_archived(report_id).unwrap();
+			assert_eq!((archived.waste_amount, archived.reporter), (10, 1));
+			assert_eq!((archived.created_at, archived.completed_at), (1, 1));
+		}
+		assert!(WasteManagement::exists(open));
+		assert!(WasteManagement::exists(recent));
+		assert_eq!(WasteManagement::get_archived(recent), None);
+		assert_eq!(WasteManagement::reports_of(&1), vec![open, recent]);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Utilized), 30);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn dashboard_summary_matches_the_reports() {
+	new_test_ext().execute_with(|| {
+		let collected = create_report(1, GLASS, 100);
+		assert_ok!(collect(collected));
+		let utilized = create_report(3, PLASTIC, 40);
+		advance_to(utilized, WasteStatus::Utilized);
+		create_report(1, ORGANIC, 7);
+		let cancelled = create_report(1, GLASS, 3);
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			cancelled,
+			reason(b"duplicate")
+		));
+
+		let summary = WasteManagement::dashboard_summary();
+		assert_eq!(summary.total, 3);
+		assert_eq!(
+			summary.by_status,
+			[
+				(WasteStatus::Reported, 7),
+				(WasteStatus::Collected, 100),
+				(WasteStatus::Transported, 0),
+				(WasteStatus::Utilized, 40),
+				(WasteStatus::Cancelled, 3),
+				(WasteStatus::Rejected, 0),
+			]
+		);
+		assert_eq!(summary.total_amount, 150);
+		assert!(summary.by_zone.is_empty());
+	});
+}
+
+const WEIGHBRIDGE: u64 = 7;
+
+fn signature(bytes: &[u8]) -> crate::WeighbridgeSignature {
+	bytes.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn trusted_weighbridge_attestation_is_stored() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_ok!(WasteManagement::trust_weighbridge(RuntimeOrigin::root(), WEIGHBRIDGE));
+
+		assert_noop!(
+			WasteManagement::attest_weight(
+				RuntimeOrigin::signed(1),
+				report_id,
+				WEIGHBRIDGE,
+				signature(&[1; 64])
+			),
+			Error::<Test>::NotAuthorizedOperator
+		);
+		assert_ok!(WasteManagement::attest_weight(
+			RuntimeOrigin::signed(OPERATOR),
+			report_id,
+			WEIGHBRIDGE,
+			signature(&[1; 64])
+		));
+		System::assert_last_event(
+			Event::WeightAttested {
+				report_id,
+				weighbridge: WEIGHBRIDGE,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			crate::WeightAttestations::<Test>::get(report_id),
+			Some((WEIGHBRIDGE, signature(&[1; 64])))
+		);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+		assert!(!crate::WeightAttestations::<Test>::contains_key(report_id));
+	});
+}
+
+#[test]
+fn untrusted_weighbridge_attestation_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+
+		assert_noop!(
+			WasteManagement::attest_weight(
+				RuntimeOrigin::signed(OPERATOR),
+				report_id,
+				WEIGHBRIDGE,
+				signature(b"sig")
+			),
+			Error::<Test>::UntrustedWeighbridge
+		);
+
+		// A weighbridge that loses its certification can't attest any more.
+		assert_ok!(WasteManagement::trust_weighbridge(RuntimeOrigin::root(), WEIGHBRIDGE));
+		assert_ok!(WasteManagement::untrust_weighbridge(RuntimeOrigin::root(), WEIGHBRIDGE));
+		assert_noop!(
+			WasteManagement::attest_weight(
+				RuntimeOrigin::signed(OPERATOR),
+				report_id,
+				WEIGHBRIDGE,
+				signature(b"sig")
+			),
+			Error::<Test>::UntrustedWeighbridge
+		);
+		assert_eq!(crate::WeightAttestations::<Test>::get(report_id), None);
+	});
+}
+
+#[test]
+fn transitions_wait_for_the_minimum_delay() {
+	new_test_ext().execute_with(|| {
+		MinTransitionDelay::set(3);
+		let report_id = create_report(1, GLASS, 100);
+		assign_collector(report_id);
+
+		System::set_block_number(3);
+		assert_noop!(
+			set_status(report_id, WasteStatus::Collected).map_err(|e| e.error),
+			Error::<Test>::TransitionTooSoon
+		);
+		System::set_block_number(4);
+		assert_ok!(set_status(report_id, WasteStatus::Collected));
+
+		// The delay runs again from the last change.
+		System::set_block_number(6);
+		assert_noop!(
+			set_status(report_id, WasteStatus::Transported).map_err(|e| e.error),
+			Error::<Test>::TransitionTooSoon
+		);
+		System::set_block_number(7);
+		assert_ok!(set_status(report_id, WasteStatus::Transported));
+	});
+}
+
+#[test]
+fn forced_updates_and_cancellation_skip_the_minimum_delay() {
+	new_test_ext().execute_with(|| {
+		MinTransitionDelay::set(3);
+		let forced = create_report(1, GLASS, 100);
+		let cancelled = create_report(1, GLASS, 10);
+
+		assert_ok!(WasteManagement::force_update_status(
+			RuntimeOrigin::root(),
+			forced,
+			WasteStatus::Utilized
+		));
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			cancelled,
+			reason(b"mistake")
+		));
+		assert_eq!(WasteManagement::status_of(forced), Some(WasteStatus::Utilized));
+		assert_eq!(WasteManagement::status_of(cancelled), Some(WasteStatus::Cancelled));
+	});
+}
+
+#[test]
+fn partial_collection_leaves_the_remainder_reported() {
+	new_test_ext().execute_with(|| {
+		let parent = create_report_with_volume(1, 100, 50);
+		assign_collector(parent);
+
+		assert_ok!(WasteManagement::collect_partial(RuntimeOrigin::signed(OPERATOR), parent, 40));
+		let child = parent + 1;
+		System::assert_last_event(
+			Event::PartialCollection {
+				parent,
+				child,
+				amount: 40,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		let collected = WasteManagement::waste_data(child).unwrap();
+		assert_eq!(collected.status, WasteStatus::Collected);
+		assert_eq!((collected.waste_amount, collected.volume), (40, 20));
+		assert_eq!(collected.parent, Some(parent));
+		assert_eq!(WasteManagement::collector_of(child), Some(COLLECTOR));
+		let remainder = WasteManagement::waste_data(parent).unwrap();
+		assert_eq!(remainder.status, WasteStatus::Reported);
+		assert_eq!((remainder.waste_amount, remainder.volume), (60, 30));
+		assert_eq!(crate::ChildReports::<Test>::get(parent).unwrap().into_inner(), vec![child]);
+
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 60);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), 40);
+		assert_eq!(WasteManagement::total_amount_of_type(GLASS), 100);
+		assert_ok!(WasteManagement::do_try_state());
+
+		// The rest can be collected later.
+		assert_ok!(WasteManagement::collect_partial(RuntimeOrigin::signed(OPERATOR), parent, 60));
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Collected), 100);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn partial_collection_of_more_than_remains_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let parent = create_report(1, GLASS, 100);
+		assert_noop!(
+			WasteManagement::collect_partial(RuntimeOrigin::signed(OPERATOR), parent, 40),
+			Error::<Test>::NoCollectorAssigned
+		);
+		assign_collector(parent);
+
+		assert_noop!(
+			WasteManagement::collect_partial(RuntimeOrigin::signed(OPERATOR), parent, 101),
+			Error::<Test>::SplitExceedsAmount
+		);
+		assert_noop!(
+			WasteManagement::collect_partial(RuntimeOrigin::signed(1), parent, 40),
+			Error::<Test>::NotAuthorizedForStatus
+		);
+	});
+}
+
+#[test]
+fn every_error_has_a_unique_documented_code() {
+	use scale_info::{TypeDef, TypeInfo};
+
+	let TypeDef::Variant(errors) = Error::<Test>::type_info().type_def else {
+		panic!("errors are an enum")
+	};
+	let mut codes = Vec::new();
+	for error in errors.variants.iter().filter(|variant| !variant.name.starts_with("__")) {
+		assert!(!error.docs.is_empty(), "{} is not documented", error.name);
+		let code = crate::ERROR_CODES
+			.iter()
+			.find(|(name, _)| *name == error.name)
+			.map(|(_, code)| *code)
+			.unwrap_or_else(|| panic!("{} has no code", error.name));
+		assert_ne!(code, 0);
+		codes.push(code);
+	}
+	codes.sort();
+	codes.dedup();
+	assert_eq!(codes.len(), crate::ERROR_CODES.len());
+
+	assert_eq!(WasteManagement::error_code(&Error::<Test>::DuplicateReport), 1);
+	assert_eq!(WasteManagement::error_code(&Error::<Test>::ReportNotFound), 3);
+}
+
+#[test]
+fn clerk_can_file_a_tagged_tip() {
+	new_test_ext().execute_with(|| {
+		let tag: crate::OriginTag<Test> = b"phone tip #42".to_vec().try_into().unwrap();
+		assert_ok!(WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(1),
+			components(&[(GLASS, 100)]),
+			0,
+			GeoPoint { x: 10, y: 20 },
+			Severity::Low,
+			None,
+			Some(tag.clone()),
+			None,
+			None,
+			None
+		));
+		let report_id = WasteManagement::next_report_id() - 1;
+		System::assert_last_event(
+			Event::WasteDataCreated {
+				report_id,
+				reporter: 1,
+				severity: Severity::Low,
+				waste_type: GLASS,
+				waste_amount: 100,
+				volume: 0,
+				origin_tag_len: 13,
+				status: WasteStatus::Reported,
+				has_location: true,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		// The clerk stays the reporter; the tag records the source.
+		let report = WasteManagement::get_report(report_id).unwrap();
+		assert_eq!(report.reporter, 1);
+		assert_eq!(report.origin_tag, Some(tag));
+	});
+}
+
+#[test]
+fn untagged_report_has_no_origin_tag() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+
+		assert_eq!(WasteManagement::get_report(report_id).unwrap().origin_tag, None);
+		System::assert_last_event(
+			Event::WasteDataCreated {
+				report_id,
+				reporter: 1,
+				severity: Severity::Low,
+				waste_type: GLASS,
+				waste_amount: 100,
+				volume: 0,
+				origin_tag_len: 0,
+				status: WasteStatus::Reported,
+				has_location: true,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn finalize_utilization_records_the_recovered_amount() {
+	new_test_ext().execute_with(|| {
+		let first = create_report(1, GLASS, 100);
+		advance_to(first, WasteStatus::Transported);
+
+		assert_ok!(WasteManagement::finalize_utilization(
+			RuntimeOrigin::signed(OPERATOR),
+			first,
+			80
+		));
+		System::assert_last_event(
+			Event::Utilized {
+				report_id: first,
+				input: 100,
+				recovered: 80,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_eq!(WasteManagement::status_of(first), Some(WasteStatus::Utilized));
+		assert_eq!(crate::RecoveredAmount::<Test>::get(first), Some(80));
+
+		let second = create_report(1, PLASTIC, 30);
+		advance_to(second, WasteStatus::Transported);
+		assert_ok!(WasteManagement::finalize_utilization(
+			RuntimeOrigin::signed(OPERATOR),
+			second,
+			30
+		));
+		assert_eq!(WasteManagement::total_recovered(), 110);
+	});
+}
+
+#[test]
+fn recovering_more_than_the_input_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		advance_to(report_id, WasteStatus::Transported);
+
+		assert_noop!(
+			WasteManagement::finalize_utilization(RuntimeOrigin::signed(OPERATOR), report_id, 101),
+			Error::<Test>::RecoveryExceedsInput
+		);
+		// The lifecycle applies as for any other status change.
+		let reported = create_report(1, GLASS, 10);
+		assert_noop!(
+			WasteManagement::finalize_utilization(RuntimeOrigin::signed(OPERATOR), reported, 5)
+				.map_err(|e| e.error),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_eq!(WasteManagement::total_recovered(), 0);
+	});
+}
+
+#[test]
+fn committed_settlement_utilizes_the_report() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		advance_to(report_id, WasteStatus::Transported);
+
+		let ticket = WasteManagement::prepare_settlement(report_id).unwrap();
+		assert_eq!(ticket.prior_status, WasteStatus::Transported);
+		System::assert_last_event(
+			Event::SettlementPrepared {
+				report_id,
+				deadline: 1 + SETTLEMENT_TIMEOUT,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		// The report is held until the settlement ends.
+		assert_noop!(
+			set_status(report_id, WasteStatus::Utilized).map_err(|e| e.error),
+			Error::<Test>::ReportSettling
+		);
+		assert_noop!(WasteManagement::prepare_settlement(report_id), Error::<Test>::ReportSettling);
+
+		assert_ok!(WasteManagement::commit_settlement(ticket.clone()));
+		System::assert_last_event(
+			Event::SettlementCommitted { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_eq!(WasteManagement::status_of(report_id), Some(WasteStatus::Utilized));
+		assert!(crate::SettlementDeadlines::<Test>::get(ticket.deadline).is_empty());
+		assert_noop!(WasteManagement::commit_settlement(ticket), Error::<Test>::NotSettling);
+	});
+}
+
+#[test]
+fn uncommitted_settlement_times_out_in_the_prior_status() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		advance_to(report_id, WasteStatus::Transported);
+		let ticket = WasteManagement::prepare_settlement(report_id).unwrap();
+
+		WasteManagement::on_initialize(ticket.deadline - 1);
+		assert!(crate::Settling::<Test>::contains_key(report_id));
+
+		WasteManagement::on_initialize(ticket.deadline);
+		System::assert_last_event(
+			Event::SettlementTimedOut {
+				report_id,
+				status: WasteStatus::Transported,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_eq!(WasteManagement::status_of(report_id), Some(WasteStatus::Transported));
+		assert_noop!(WasteManagement::commit_settlement(ticket), Error::<Test>::NotSettling);
+		assert_ok!(set_status(report_id, WasteStatus::Utilized));
+	});
+}
+
+#[test]
+fn reserved_id_is_filled_in_by_finalize_draft() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(WasteManagement::reserve_report_id(RuntimeOrigin::signed(1)));
+		System::assert_last_event(
+			Event::ReportIdReserved { report_id: 1, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert!(!WasteManagement::exists(1));
+
+		// Reports filed meanwhile take the ids after it.
+		assert_eq!(create_report(2, GLASS, 50), 2);
+
+		assert_noop!(
+			WasteManagement::finalize_draft(
+				RuntimeOrigin::signed(2),
+				1,
+				PLASTIC,
+				100,
+				GeoPoint { x: 10, y: 20 }
+			),
+			Error::<Test>::NotReportOwner
+		);
+		assert_noop!(
+			WasteManagement::finalize_draft(
+				RuntimeOrigin::signed(1),
+				3,
+				PLASTIC,
+				100,
+				GeoPoint { x: 10, y: 20 }
+			),
+			Error::<Test>::NotADraft
+		);
+		assert_ok!(WasteManagement::finalize_draft(
+			RuntimeOrigin::signed(1),
+			1,
+			PLASTIC,
+			100,
+			GeoPoint { x: 10, y: 20 }
+		));
+
+		let report = WasteManagement::get_report(1).unwrap();
+		assert_eq!((report.waste_type, report.waste_amount), (PLASTIC, 100));
+		assert_eq!(report.location, Some(GeoPoint { x: 10, y: 20 }));
+		assert_eq!(report.status, WasteStatus::Reported);
+		assert_eq!(WasteManagement::reports_of(&1), vec![1]);
+		assert_eq!(WasteManagement::next_report_id(), 3);
+		assert!(!crate::Drafts::<Test>::contains_key(1));
+		assert_noop!(
+			WasteManagement::finalize_draft(
+				RuntimeOrigin::signed(1),
+				1,
+				PLASTIC,
+				100,
+				GeoPoint { x: 10, y: 20 }
+			),
+			Error::<Test>::NotADraft
+		);
+	});
+}
+
+#[test]
+fn reserving_an_id_is_gated_like_filing_a_report() {
+	new_test_ext().execute_with(|| {
+		RequireAllowlist::set(true);
+		assert_noop!(
+			WasteManagement::reserve_report_id(RuntimeOrigin::signed(1)),
+			Error::<Test>::ReporterNotAllowed
+		);
+		assert_ok!(WasteManagement::allow_reporter(RuntimeOrigin::root(), 1));
+
+		// Each reservation counts against the per-block limit.
+		MaxReportsPerBlock::set(2);
+		assert_ok!(WasteManagement::reserve_report_id(RuntimeOrigin::signed(1)));
+		assert_ok!(WasteManagement::reserve_report_id(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			WasteManagement::reserve_report_id(RuntimeOrigin::signed(1)),
+			Error::<Test>::RateLimited
+		);
+		assert_eq!(WasteManagement::next_report_id(), 3);
+	});
+}
+
+#[test]
+fn unfinalized_drafts_expire() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(WasteManagement::reserve_report_id(RuntimeOrigin::signed(1)));
+
+		WasteManagement::on_initialize(DRAFT_EXPIRY);
+		assert!(crate::Drafts::<Test>::contains_key(1));
+
+		WasteManagement::on_initialize(1 + DRAFT_EXPIRY);
+		System::assert_last_event(
+			Event::DraftExpired { report_id: 1, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_noop!(
+			WasteManagement::finalize_draft(
+				RuntimeOrigin::signed(1),
+				1,
+				PLASTIC,
+				100,
+				GeoPoint { x: 10, y: 20 }
+			),
+			Error::<Test>::NotADraft
+		);
+		// The id is not handed out again.
+		assert_eq!(create_report(1, GLASS, 100), 2);
+	});
+}
+
+#[test]
+fn only_indexed_statuses_are_mirrored() {
+	new_test_ext().execute_with(|| {
+		IndexedStatuses::set(&[WasteStatus::Reported, WasteStatus::Transported]);
+		let report_id = create_report(1, GLASS, 100);
+		assert!(crate::WasteDataByStatus::<Test>::contains_key(WasteStatus::Reported, report_id));
+
+		assert_ok!(collect(report_id));
+		assert!(!crate::WasteDataByStatus::<Test>::contains_key(WasteStatus::Reported, report_id));
+		assert!(!crate::WasteDataByStatus::<Test>::contains_key(WasteStatus::Collected, report_id));
+		assert!(WasteManagement::reports_with_status(WasteStatus::Collected).is_empty());
+		assert_noop!(
+			WasteManagement::bulk_update_status(
+				RuntimeOrigin::signed(OPERATOR),
+				WasteStatus::Collected,
+				WasteStatus::Transported,
+				5
+			),
+			Error::<Test>::StatusNotIndexed
+		);
+
+		assert_ok!(set_status(report_id, WasteStatus::Transported));
+		assert_eq!(
+			crate::WasteDataByStatus::<Test>::get(WasteStatus::Transported, report_id),
+			WasteManagement::get_report(report_id)
+		);
+		assert_ok!(set_status(report_id, WasteStatus::Utilized));
+		assert_eq!(crate::WasteDataByStatus::<Test>::iter_keys().count(), 0);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn new_validated_checks_every_field() {
+	new_test_ext().execute_with(|| {
+		let build = |components: Components<Test>, volume: u64, location: GeoPoint| {
+			crate::WasteData::<Test>::new_validated(
+				7,
+				1,
+				components,
+				volume,
+				Some(location),
+				Severity::Low,
+				None,
+				None,
+			)
+			.map_err(sp_runtime::DispatchError::from)
+		};
+		let here = GeoPoint { x: 10, y: 20 };
+
+		let report = build(components(&[(PLASTIC, 30), (GLASS, 20)]), 5, here).unwrap();
+		assert_eq!((report.report_id, report.waste_type, report.waste_amount), (7, PLASTIC, 50));
+		assert_eq!(report.status, WasteStatus::Reported);
+		assert_eq!((report.created_at, report.updated_at), (1, 1));
+
+		assert_eq!(
+			build(components(&[(PLASTIC, 30)]), 0, GeoPoint { x: 1_001, y: 20 }),
+			Err(Error::<Test>::LocationOutOfBounds.into())
+		);
+		assert_eq!(
+			build(components(&[(PLASTIC, 30)]), 0, GeoPoint { x: 10, y: 501 }),
+			Err(Error::<Test>::LocationOutOfBounds.into())
+		);
+		assert_eq!(build(components(&[]), 0, here), Err(Error::<Test>::EmptyComponents.into()));
+		assert_eq!(
+			build(components(&[(WasteCategory::Other(99), 30)]), 0, here),
+			Err(Error::<Test>::UnknownWasteType.into())
+		);
+		assert_eq!(
+			build(components(&[(PLASTIC, 0)]), 0, here),
+			Err(Error::<Test>::AmountBelowMinimum.into())
+		);
+		assert_eq!(
+			build(components(&[(PLASTIC, MAX_WASTE_AMOUNT + 1)]), 0, here),
+			Err(Error::<Test>::AmountTooLarge.into())
+		);
+		assert_eq!(
+			build(components(&[(PLASTIC, u64::MAX), (GLASS, 1)]), 0, here),
+			Err(Error::<Test>::AmountTooLarge.into())
+		);
+		assert_eq!(
+			build(components(&[(PLASTIC, 30)]), MAX_VOLUME + 1, here),
+			Err(Error::<Test>::VolumeTooLarge.into())
+		);
+	});
+}
+
+#[test]
+fn event_seq_counts_every_event_across_blocks() {
+	new_test_ext().execute_with(|| {
+		let seqs = || -> Vec<u64> {
+			System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::WasteManagement(Event::WasteDataCreated { seq, .. }) |
+					RuntimeEvent::WasteManagement(Event::CollectorAssigned { seq, .. }) |
+					RuntimeEvent::WasteManagement(Event::WasteStatusUpdated { seq, .. }) => Some(seq),
+					RuntimeEvent::WasteManagement(event) => panic!("unexpected {:?}", event),
+					_ => None,
+				})
+				.collect()
+		};
+		assert_eq!(WasteManagement::event_seq(), 0);
+
+		let report_id = create_report(1, GLASS, 100);
+		assign_collector(report_id);
+		assert_eq!(seqs(), vec![1, 2]);
+
+		System::reset_events();
+		System::set_block_number(2);
+		assert_ok!(set_status(report_id, WasteStatus::Collected));
+		assert_eq!(seqs(), vec![3]);
+		assert_eq!(WasteManagement::event_seq(), 3);
+	});
+}
+
+#[test]
+fn reports_are_filed_under_their_service_zone() {
+	new_test_ext().execute_with(|| {
+		// With no zone defined every in-bounds location is accepted.
+		let report_id = create_report(1, PLASTIC, 10);
+		assert_eq!(crate::WasteDataMap::<Test>::get(report_id).unwrap().zone_id, None);
+
+		let zone = (GeoPoint { x: 0, y: 0 }, GeoPoint { x: 100, y: 100 });
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 1, Some(zone)));
+		System::assert_last_event(
+			Event::ServiceZoneSet {
+				zone_id: 1,
+				zone: Some(zone),
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		// Reports filed earlier keep their zone.
+		assert_eq!(crate::WasteDataMap::<Test>::get(report_id).unwrap().zone_id, None);
+
+		let report_id = create_report(1, PLASTIC, 10);
+		assert_eq!(crate::WasteDataMap::<Test>::get(report_id).unwrap().zone_id, Some(1));
+
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(PLASTIC, 10)]),
+				0,
+				GeoPoint { x: 420, y: 310 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::LocationOutsideServiceArea
+		);
+		assert_noop!(
+			WasteManagement::update_location(
+				RuntimeOrigin::signed(1),
+				report_id,
+				GeoPoint { x: 420, y: 310 }
+			),
+			Error::<Test>::LocationOutsideServiceArea
+		);
+
+		// Where zones overlap, the lowest id wins.
+		let wide = (GeoPoint { x: 50, y: 0 }, GeoPoint { x: 500, y: 400 });
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 3, Some(wide)));
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 2, Some(wide)));
+		assert_eq!(WasteManagement::zone_of(&GeoPoint { x: 60, y: 20 }), Some(1));
+		assert_eq!(WasteManagement::zone_of(&GeoPoint { x: 420, y: 310 }), Some(2));
+		assert_eq!(WasteManagement::zone_of(&GeoPoint { x: 600, y: 310 }), None);
+
+		assert_ok!(WasteManagement::update_location(
+			RuntimeOrigin::signed(1),
+			report_id,
+			GeoPoint { x: 420, y: 310 }
+		));
+		assert_eq!(crate::WasteDataMap::<Test>::get(report_id).unwrap().zone_id, Some(2));
+	});
+}
+
+#[test]
+fn service_zones_are_root_only_and_checked() {
+	new_test_ext().execute_with(|| {
+		let zone = (GeoPoint { x: 0, y: 0 }, GeoPoint { x: 100, y: 100 });
+		assert_noop!(
+			WasteManagement::set_service_zone(RuntimeOrigin::signed(OPERATOR), 0, Some(zone)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			WasteManagement::set_service_zone(RuntimeOrigin::root(), 4, Some(zone)),
+			Error::<Test>::InvalidZone
+		);
+		assert_noop!(
+			WasteManagement::set_service_zone(
+				RuntimeOrigin::root(),
+				0,
+				Some((GeoPoint { x: 100, y: 0 }, GeoPoint { x: 0, y: 100 }))
+			),
+			Error::<Test>::InvalidZone
+		);
+
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 0, Some(zone)));
+		assert_eq!(WasteManagement::service_zones(0), Some(zone));
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 0, None));
+		assert_eq!(WasteManagement::service_zones(0), None);
+		assert_eq!(
+			crate::WasteDataMap::<Test>::get(create_report(1, PLASTIC, 10)).unwrap().zone_id,
+			None
+		);
+	});
+}
+
+#[test]
+fn zone_totals_follow_the_reports_in_each_zone() {
+	new_test_ext().execute_with(|| {
+		let file_at = |x, amount| {
+			assert_ok!(WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(PLASTIC, amount)]),
+				0,
+				GeoPoint { x, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+			WasteManagement::next_report_id() - 1
+		};
+		let west = (GeoPoint { x: 0, y: 0 }, GeoPoint { x: 99, y: 500 });
+		let east = (GeoPoint { x: 100, y: 0 }, GeoPoint { x: 1_000, y: 500 });
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 0, Some(west)));
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 1, Some(east)));
+
+		file_at(10, 30);
+		let moved = file_at(20, 5);
+		let deleted = file_at(500, 100);
+		file_at(600, 12);
+		assert_eq!(
+			(WasteManagement::total_in_zone(0), WasteManagement::total_in_zone(1)),
+			(35, 112)
+		);
+
+		assert_ok!(WasteManagement::update_location(
+			RuntimeOrigin::signed(1),
+			moved,
+			GeoPoint { x: 700, y: 20 }
+		));
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), deleted));
+		assert_eq!(
+			(WasteManagement::total_in_zone(0), WasteManagement::total_in_zone(1)),
+			(30, 17)
+		);
+
+		let summary = WasteManagement::dashboard_summary();
+		assert_eq!(summary.by_zone, vec![(0, 30), (1, 17)]);
+		let grand_total =
+			summary.by_zone.iter().fold(0, |total: WasteAmount, (_, amount)| total + amount);
+		assert_eq!(grand_total, summary.total_amount);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn assign_zone_hands_the_zone_backlog_to_one_collector() {
+	new_test_ext().execute_with(|| {
+		let file_at = |x| {
+			assert_ok!(WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(PLASTIC, 10)]),
+				0,
+				GeoPoint { x, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+			WasteManagement::next_report_id() - 1
+		};
+		let west = (GeoPoint { x: 0, y: 0 }, GeoPoint { x: 99, y: 500 });
+		let east = (GeoPoint { x: 100, y: 0 }, GeoPoint { x: 1_000, y: 500 });
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 0, Some(west)));
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 1, Some(east)));
+		let backlog = [file_at(10), file_at(20), file_at(30)];
+		let outside = file_at(500);
+		let collected = file_at(40);
+		assert_ok!(collect(collected));
+		let assigned = || {
+			let mut ids = crate::WasteDataMap::<Test>::iter()
+				.filter(|(_, report)| report.collector == Some(COLLECTOR + 1))
+				.map(|(report_id, _)| report_id)
+				.collect::<Vec<_>>();
+			ids.sort();
+			ids
+		};
+
+		assert_noop!(
+			WasteManagement::assign_zone(RuntimeOrigin::signed(1), 0, COLLECTOR + 1, 10),
+			Error::<Test>::NotAuthorizedOperator
+		);
+
+		assert_ok!(WasteManagement::assign_zone(
+			RuntimeOrigin::signed(OPERATOR),
+			0,
+			COLLECTOR + 1,
+			2
+		));
+		System::assert_last_event(
+			Event::ZoneAssigned {
+				zone_id: 0,
+				collector: COLLECTOR + 1,
+				count: 2,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_eq!(assigned().len(), 2);
+
+		assert_ok!(WasteManagement::assign_zone(
+			RuntimeOrigin::signed(OPERATOR),
+			0,
+			COLLECTOR + 1,
+			10
+		));
+		assert_eq!(assigned(), backlog.to_vec());
+		// Reports outside the zone or past `Reported` are left alone.
+		assert_eq!(WasteManagement::collector_of(outside), None);
+		assert_eq!(WasteManagement::collector_of(collected), Some(COLLECTOR));
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+fn create_report_with_ref(
+	who: u64,
+	external_ref: crate::ExternalRef,
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	WasteManagement::create_waste_data(
+		RuntimeOrigin::signed(who),
+		components(&[(GLASS, 100)]),
+		0,
+		GeoPoint { x: 10 + who as u32, y: 20 },
+		Severity::Low,
+		None,
+		None,
+		None,
+		Some(external_ref),
+		None,
+	)
+}
+
+#[test]
+fn reports_can_be_found_by_external_ref() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_report_with_ref(1, [7; 32]));
+		let report_id = WasteManagement::next_report_id() - 1;
+
+		assert_eq!(WasteManagement::report_by_ref([7; 32]), Some(report_id));
+		assert_eq!(WasteManagement::waste_data(report_id).unwrap().external_ref, Some([7; 32]));
+		assert_eq!(WasteManagement::report_by_ref([8; 32]), None);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+		assert_eq!(WasteManagement::report_by_ref([7; 32]), None);
+	});
+}
+
+#[test]
+fn duplicate_external_refs_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_report_with_ref(1, [7; 32]));
+		let report_id = WasteManagement::next_report_id() - 1;
+
+		assert_noop!(create_report_with_ref(3, [7; 32]), Error::<Test>::DuplicateExternalRef);
+		assert_eq!(WasteManagement::report_by_ref([7; 32]), Some(report_id));
+		assert_ok!(create_report_with_ref(3, [9; 32]));
+	});
+}
+
+#[test]
+fn resent_external_ref_updates_the_amount_in_upsert_mode() {
+	let resend = |who, amount| {
+		WasteManagement::create_waste_data(
+			RuntimeOrigin::signed(who),
+			components(&[(GLASS, amount)]),
+			0,
+			GeoPoint { x: 50, y: 20 },
+			Severity::Low,
+			None,
+			None,
+			None,
+			Some([7; 32]),
+			None,
+		)
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(resend(1, 100));
+		assert_noop!(resend(1, 250), Error::<Test>::DuplicateExternalRef);
+	});
+
+	new_test_ext().execute_with(|| {
+		ExternalRefMode::set(crate::ExternalRefMode::UpsertAmount);
+		assert_ok!(resend(1, 100));
+		let report_id = WasteManagement::report_by_ref([7; 32]).unwrap();
+
+		assert_ok!(resend(1, 250));
+		System::assert_last_event(
+			Event::ReportUpserted { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+		assert_eq!(WasteManagement::waste_data(report_id).unwrap().waste_amount, 250);
+		assert_eq!(WasteManagement::next_report_id(), report_id + 1);
+		assert_eq!(WasteManagement::total_amount_of_type(GLASS), 250);
+		assert_eq!(Balances::reserved_balance(1), REPORT_DEPOSIT);
+
+		// Only the reporter can update their report, and only its amount.
+		assert_noop!(resend(3, 300), Error::<Test>::NotReportOwner);
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(PLASTIC, 300)]),
+				0,
+				GeoPoint { x: 50, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				Some([7; 32]),
+				None,
+			),
+			Error::<Test>::DuplicateExternalRef
+		);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn slashed_reports_are_rejected_and_lose_their_deposit() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		let issuance = Balances::total_issuance();
+
+		assert_noop!(
+			WasteManagement::slash_report(RuntimeOrigin::signed(OPERATOR), report_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::slash_report(RuntimeOrigin::root(), report_id));
+		System::assert_last_event(
+			Event::ReportSlashed {
+				report_id,
+				amount: REPORT_DEPOSIT,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		assert_eq!(WasteManagement::waste_data(report_id).unwrap().status, WasteStatus::Rejected);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - REPORT_DEPOSIT);
+		assert_eq!(Balances::total_issuance(), issuance - REPORT_DEPOSIT);
+
+		// Nothing is left to slash, and deleting the report refunds nothing.
+		assert_noop!(
+			WasteManagement::slash_report(RuntimeOrigin::root(), report_id),
+			Error::<Test>::NoDepositHeld
+		);
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), report_id));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - REPORT_DEPOSIT);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn active_reports_are_capped() {
+	new_test_ext().execute_with(|| {
+		MaxActiveReports::set(3);
+		let first = create_report(1, GLASS, 100);
+		let second = create_report(1, GLASS, 100);
+		let third = create_report(3, PLASTIC, 100);
+		assert_noop!(
+			WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(3),
+				components(&[(PLASTIC, 10)]),
+				0,
+				GeoPoint { x: 10, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::TooManyActiveReports
+		);
+
+		// Deleting a report makes room again.
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), first));
+		let fourth = create_report(3, PLASTIC, 10);
+		assert_eq!(WasteManagement::waste_data_count(), 3);
+
+		// As does cancelling one, but then it can't be reopened while the ceiling is reached.
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			second,
+			reason(b"dup")
+		));
+		create_report(1, ORGANIC, 10);
+		assert_noop!(
+			WasteManagement::reopen_report(RuntimeOrigin::signed(1), second),
+			Error::<Test>::TooManyActiveReports
+		);
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(3), fourth));
+		assert_ok!(WasteManagement::reopen_report(RuntimeOrigin::signed(1), second));
+		assert_eq!(WasteManagement::waste_data_count(), 3);
+		assert!(WasteManagement::get_report(third).is_some());
+
+		// Completing a report frees its slot too, though it stays on file.
+		advance_to(third, WasteStatus::Utilized);
+		assert_eq!(WasteManagement::open_report_count(), 2);
+		create_report(3, GLASS, 10);
+		assert_eq!(WasteManagement::open_report_count(), 3);
+		assert_eq!(WasteManagement::waste_data_count(), 4);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn cancelled_reports_can_be_reopened() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_ok!(WasteManagement::cancel_report(
+			RuntimeOrigin::signed(1),
+			report_id,
+			reason(b"mistake")
+		));
+		assert_eq!(WasteManagement::total_amount_of_type(GLASS), 0);
+
+		assert_noop!(
+			WasteManagement::reopen_report(RuntimeOrigin::signed(3), report_id),
+			Error::<Test>::NotReportOwner
+		);
+		assert_ok!(WasteManagement::reopen_report(RuntimeOrigin::signed(1), report_id));
+		System::assert_last_event(
+			Event::ReportReopened { report_id, seq: WasteManagement::event_seq() }.into(),
+		);
+
+		assert_eq!(WasteManagement::waste_data(report_id).unwrap().status, WasteStatus::Reported);
+		assert!(!crate::CancellationReasons::<Test>::contains_key(report_id));
+		assert_eq!(WasteManagement::total_amount_of_type(GLASS), 100);
+		assert_eq!(WasteManagement::total_amount_in(WasteStatus::Reported), 100);
+		assert_eq!(WasteManagement::reports_by_severity(Severity::Low), vec![report_id]);
+		assert_ok!(WasteManagement::do_try_state());
+
+		// `ForceOrigin` may reopen any report.
+		assert_ok!(WasteManagement::slash_report(RuntimeOrigin::root(), report_id));
+		assert_ok!(WasteManagement::reopen_report(RuntimeOrigin::root(), report_id));
+		assert_eq!(WasteManagement::waste_data(report_id).unwrap().status, WasteStatus::Reported);
+	});
+}
+
+#[test]
+fn slashed_reports_cannot_be_reopened_by_their_reporter() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_ok!(WasteManagement::slash_report(RuntimeOrigin::root(), report_id));
+		assert!(!crate::ReportDeposits::<Test>::contains_key(report_id));
+
+		assert_noop!(
+			WasteManagement::reopen_report(RuntimeOrigin::signed(1), report_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_eq!(WasteManagement::waste_data(report_id).unwrap().status, WasteStatus::Rejected);
+		assert_ok!(WasteManagement::reopen_report(RuntimeOrigin::root(), report_id));
+	});
+}
+
+#[test]
+fn only_cancelled_or_rejected_reports_can_be_reopened() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_report(1, GLASS, 100);
+		assert_noop!(
+			WasteManagement::reopen_report(RuntimeOrigin::signed(1), report_id),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_ok!(collect(report_id));
+		assert_noop!(
+			WasteManagement::reopen_report(RuntimeOrigin::root(), report_id),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_noop!(
+			WasteManagement::reopen_report(RuntimeOrigin::signed(1), 99),
+			Error::<Test>::ReportNotFound
+		);
+	});
+}
+
+#[test]
+fn privacy_mode_files_reports_under_a_hash_of_the_reporter() {
+	new_test_ext().execute_with(|| {
+		let public = create_report(1, GLASS, 100);
+		PrivacyMode::set(true);
+		let private = create_report(1, GLASS, 100);
+		let hashed = WasteManagement::reporter_key(&1);
+		assert_ne!(hashed, 1);
+
+		// The public report exposes the account, the private one only the hash.
+		assert_eq!(WasteManagement::waste_data(public).unwrap().reporter, 1);
+		assert_eq!(WasteManagement::waste_data(private).unwrap().reporter, hashed);
+		assert!(!crate::ReporterAccounts::<Test>::contains_key(public));
+		assert_eq!(crate::ReporterAccounts::<Test>::get(private), Some(1));
+		assert!(emitted!(
+			Event::WasteDataCreated { report_id, reporter, .. }
+				if report_id == private && reporter == hashed
+		));
+		assert_eq!(WasteManagement::reports_of(&1), vec![private]);
+
+		// Neither the deposit nor an idempotency key gives the account away.
+		assert_eq!(crate::ReportDeposits::<Test>::get(private), Some(REPORT_DEPOSIT));
+		let keyed = create_report_with_key(1, [9; 16]);
+		assert_eq!(crate::SeenKeys::<Test>::iter_prefix(1).count(), 0);
+		assert_eq!(crate::SeenKeys::<Test>::get(hashed, [9; 16]), Some(keyed));
+		assert_eq!(create_report_with_key(1, [9; 16]), keyed);
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), keyed));
+
+		// The reporter still owns both reports.
+		assert_noop!(
+			WasteManagement::attach_evidence(RuntimeOrigin::signed(hashed), private, [1; 32]),
+			Error::<Test>::NotReportOwner
+		);
+		assert_ok!(WasteManagement::attach_evidence(RuntimeOrigin::signed(1), private, [1; 32]));
+		assert_ok!(WasteManagement::attach_evidence(RuntimeOrigin::signed(1), public, [1; 32]));
+
+		assert_noop!(
+			WasteManagement::resolve_reporter(RuntimeOrigin::signed(OPERATOR), private),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::resolve_reporter(RuntimeOrigin::root(), private));
+		System::assert_last_event(
+			Event::ReporterResolved {
+				report_id: private,
+				reporter: 1,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), private));
+		assert!(!crate::ReporterAccounts::<Test>::contains_key(private));
+		assert_eq!(Balances::reserved_balance(1), REPORT_DEPOSIT);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+fn zone_with_reports(count: u32) -> Vec<ReportId> {
+	let zone = (GeoPoint { x: 0, y: 0 }, GeoPoint { x: 99, y: 500 });
+	assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 0, Some(zone)));
+	(0..count)
+		.map(|i| {
+			assert_ok!(WasteManagement::create_waste_data(
+				RuntimeOrigin::signed(1),
+				components(&[(PLASTIC, 10)]),
+				0,
+				GeoPoint { x: i, y: 20 },
+				Severity::Low,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+			WasteManagement::next_report_id() - 1
+		})
+		.collect()
+}
+
+#[test]
+fn next_unprocessed_returns_the_oldest_report_of_a_zone() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(WasteManagement::next_unprocessed(0, WasteStatus::Reported), None);
+		let ids = zone_with_reports(4);
+		assert_eq!(WasteManagement::next_unprocessed(0, WasteStatus::Reported), Some(ids[0]));
+		assert_eq!(WasteManagement::next_unprocessed(0, WasteStatus::Collected), None);
+		assert_eq!(WasteManagement::next_unprocessed(1, WasteStatus::Reported), None);
+
+		assert_ok!(collect(ids[2]));
+		assert_ok!(collect(ids[0]));
+		assert_eq!(WasteManagement::next_unprocessed(0, WasteStatus::Reported), Some(ids[1]));
+		assert_eq!(WasteManagement::next_unprocessed(0, WasteStatus::Collected), Some(ids[0]));
+
+		assert_ok!(WasteManagement::delete_waste_data(RuntimeOrigin::signed(1), ids[1]));
+		assert_eq!(WasteManagement::next_unprocessed(0, WasteStatus::Reported), Some(ids[3]));
+		assert_ok!(set_status(ids[0], WasteStatus::Transported));
+		assert_eq!(WasteManagement::next_unprocessed(0, WasteStatus::Collected), Some(ids[2]));
+
+		assert_ok!(collect(ids[3]));
+		assert_eq!(WasteManagement::next_unprocessed(0, WasteStatus::Reported), None);
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+fn statuses(report_ids: &[ReportId]) -> Vec<WasteStatus> {
+	report_ids
+		.iter()
+		.map(|id| WasteManagement::waste_data(id).unwrap().status)
+		.collect()
+}
+
+#[test]
+fn emergency_collection_of_a_small_zone_completes_at_once() {
+	new_test_ext().execute_with(|| {
+		MinTransitionDelay::set(100);
+		let zone = zone_with_reports(2);
+		let outside = (GeoPoint { x: 100, y: 0 }, GeoPoint { x: 1_000, y: 500 });
+		assert_ok!(WasteManagement::set_service_zone(RuntimeOrigin::root(), 1, Some(outside)));
+		let other = create_report(1, GLASS, 10);
+		assert_ok!(WasteManagement::update_location(
+			RuntimeOrigin::signed(1),
+			other,
+			GeoPoint { x: 500, y: 20 }
+		));
+
+		assert_noop!(
+			WasteManagement::emergency_collect_zone(RuntimeOrigin::signed(OPERATOR), 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::emergency_collect_zone(RuntimeOrigin::root(), 0));
+
+		assert!(emitted!(Event::EmergencyCollectionStarted { zone_id: 0, .. }));
+		System::assert_last_event(
+			Event::EmergencyCollectionCompleted {
+				zone_id: 0,
+				count: 2,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_eq!(statuses(&zone), vec![WasteStatus::Collected; 2]);
+		assert_eq!(statuses(&[other]), vec![WasteStatus::Reported]);
+		assert!(!crate::EmergencyCollections::<Test>::contains_key(0));
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn emergency_collection_of_a_large_zone_resumes_across_blocks() {
+	new_test_ext().execute_with(|| {
+		let zone = zone_with_reports(7);
+
+		assert_ok!(WasteManagement::emergency_collect_zone(RuntimeOrigin::root(), 0));
+		assert_eq!(crate::EmergencyCollections::<Test>::get(0), 3);
+		assert_noop!(
+			WasteManagement::emergency_collect_zone(RuntimeOrigin::root(), 0),
+			Error::<Test>::EmergencyInProgress
+		);
+
+		System::set_block_number(2);
+		WasteManagement::on_initialize(2);
+		assert_eq!(crate::EmergencyCollections::<Test>::get(0), 6);
+		assert!(!emitted!(Event::EmergencyCollectionCompleted { .. }));
+
+		System::set_block_number(3);
+		WasteManagement::on_initialize(3);
+		System::assert_last_event(
+			Event::EmergencyCollectionCompleted {
+				zone_id: 0,
+				count: 7,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_eq!(statuses(&zone), vec![WasteStatus::Collected; 7]);
+		assert!(!crate::EmergencyCollections::<Test>::contains_key(0));
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn emergency_collection_passes_over_settling_reports() {
+	new_test_ext().execute_with(|| {
+		let zone = zone_with_reports(7);
+		assert_ok!(WasteManagement::emergency_collect_zone(RuntimeOrigin::root(), 0));
+		let settling = *zone
+			.iter()
+			.find(|id| WasteManagement::status_of(**id) == Some(WasteStatus::Reported))
+			.unwrap();
+		// Prepared for settlement after the collection started.
+		crate::Settling::<Test>::insert(
+			settling,
+			crate::SettlementTicket {
+				report_id: settling,
+				prior_status: WasteStatus::Reported,
+				deadline: 100,
+			},
+		);
+
+		for block in 2..=5 {
+			System::set_block_number(block);
+			WasteManagement::on_initialize(block);
+		}
+		assert!(emitted!(Event::EmergencyCollectionCompleted { zone_id: 0, count: 6, .. }));
+		assert!(!crate::EmergencyCollections::<Test>::contains_key(0));
+		assert_eq!(WasteManagement::status_of(settling), Some(WasteStatus::Reported));
+		assert!(crate::ZoneBacklog::<Test>::contains_key(0, settling));
+		for report_id in zone.iter().filter(|id| **id != settling) {
+			assert_eq!(WasteManagement::status_of(*report_id), Some(WasteStatus::Collected));
+		}
+	});
+}
+
+#[test]
+fn active_count_tracks_a_random_mix_of_creates_and_deletes() {
+	new_test_ext().execute_with(|| {
+		// A fixed xorshift sequence, so that failures can be replayed.
+		let mut state = 0x2545_f491_4f6c_dd1d_u64;
+		let mut next = move || {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			state
+		};
+		let mut open = Vec::new();
+		let mut created = 0u64;
+
+		for step in 0..60u64 {
+			System::set_block_number(step + 1);
+			match next() % 4 {
+				0 | 1 if open.len() < 8 => {
+					// Spread over reporters and grid cells, which cancelled reports stay in.
+					assert_ok!(WasteManagement::create_waste_data(
+						RuntimeOrigin::signed(1 + step % 5),
+						components(&[(GLASS, 1 + next() % 50)]),
+						0,
+						GeoPoint { x: (step as u32 * 37) % 1_000, y: (step as u32 * 53) % 500 },
+						Severity::Low,
+						None,
+						None,
+						None,
+						None,
+						None
+					));
+					open.push(WasteManagement::next_report_id() - 1);
+					created += 1;
+				},
+				2 if !open.is_empty() => {
+					let report_id = open.swap_remove((next() % open.len() as u64) as usize);
+					let reporter = WasteManagement::waste_data(report_id).unwrap().reporter;
+					assert_ok!(WasteManagement::delete_waste_data(
+						RuntimeOrigin::signed(reporter),
+						report_id
+					));
+				},
+				3 if !open.is_empty() => {
+					let report_id = open.swap_remove((next() % open.len() as u64) as usize);
+					let reporter = WasteManagement::waste_data(report_id).unwrap().reporter;
+					assert_ok!(WasteManagement::cancel_report(
+						RuntimeOrigin::signed(reporter),
+						report_id,
+						reason(b"gone")
+					));
+				},
+				_ => {},
+			}
+			assert_eq!(WasteManagement::active_count(), open.len() as u64);
+			assert_eq!(WasteManagement::total_ever_created(), created);
+		}
+		assert_ok!(WasteManagement::do_try_state());
+	});
+}
+
+#[test]
+fn cooldown_exempt_accounts_skip_the_minimum_delay() {
+	const BIN_SERVICE: u64 = 5;
+	new_test_ext().execute_with(|| {
+		MinTransitionDelay::set(3);
+		assert_ok!(WasteManagement::add_operator(RuntimeOrigin::root(), BIN_SERVICE));
+		assert_noop!(
+			WasteManagement::add_cooldown_exempt(RuntimeOrigin::signed(OPERATOR), BIN_SERVICE),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(WasteManagement::add_cooldown_exempt(RuntimeOrigin::root(), BIN_SERVICE));
+		System::assert_last_event(
+			Event::CooldownExemptAdded { account: BIN_SERVICE, seq: WasteManagement::event_seq() }
+				.into(),
+		);
+		let automated = create_report(1, GLASS, 100);
+		let manual = create_report(3, GLASS, 100);
+		assign_collector(automated);
+		assign_collector(manual);
+		let update = |who, report_id, status| {
+			WasteManagement::update_waste_status(
+				RuntimeOrigin::signed(who),
+				report_id,
+				status,
+				None,
+			)
+			.map_err(|e| e.error)
+		};
+
+		assert_ok!(update(BIN_SERVICE, automated, WasteStatus::Collected));
+		assert_ok!(update(BIN_SERVICE, automated, WasteStatus::Transported));
+		assert_noop!(
+			update(OPERATOR, manual, WasteStatus::Collected),
+			Error::<Test>::TransitionTooSoon
+		);
+
+		assert_ok!(WasteManagement::remove_cooldown_exempt(RuntimeOrigin::root(), BIN_SERVICE));
+		System::assert_last_event(
+			Event::CooldownExemptRemoved {
+				account: BIN_SERVICE,
+				seq: WasteManagement::event_seq(),
+			}
+			.into(),
+		);
+		assert_noop!(
+			update(BIN_SERVICE, automated, WasteStatus::Utilized),
+			Error::<Test>::TransitionTooSoon
+		);
+	});
+}
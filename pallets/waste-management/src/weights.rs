@@ -0,0 +1,191 @@
+//! Weights for waste_management
+//!
+//! Produced with the `benchmark pallet` subcommand of the node on reference hardware:
+//!
+//! ./target/release/node-template benchmark pallet --chain dev --pallet waste_management
+//! --extrinsic '*' --steps 50 --repeat 20 --output pallets/waste-management/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for waste_management.
+pub trait WeightInfo {
+	fn create_waste_data() -> Weight;
+	fn update_waste_status() -> Weight;
+	fn update_waste_status_noop() -> Weight;
+	fn update_waste_status_to_collected() -> Weight;
+	fn update_waste_status_to_transported() -> Weight;
+	fn update_waste_status_to_utilized() -> Weight;
+	fn create_waste_data_batch(n: u32, ) -> Weight;
+	fn bulk_update_status(n: u32, ) -> Weight;
+	fn delete_waste_data(n: u32, ) -> Weight;
+}
+
+/// Weights for waste_management using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: WasteManagement NextReportId (r:1 w:1)
+	// Storage: WasteManagement WasteDataMap (r:1 w:1)
+	// Storage: WasteManagement ReportsByReporter (r:1 w:1)
+	// Storage: WasteManagement WasteDataByStatus (r:0 w:1)
+	fn create_waste_data() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: WasteManagement Operators (r:1 w:0)
+	// Storage: WasteManagement WasteDataMap (r:1 w:1)
+	// Storage: WasteManagement WasteDataByStatus (r:0 w:2)
+	fn update_waste_status() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: WasteManagement Operators (r:1 w:0)
+	// Storage: WasteManagement WasteDataMap (r:1 w:0)
+	fn update_waste_status_noop() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	// Storage: WasteManagement Operators (r:1 w:0)
+	// Storage: WasteManagement WasteDataMap (r:1 w:1)
+	// Storage: WasteManagement WasteDataByStatus (r:0 w:2)
+	fn update_waste_status_to_collected() -> Weight {
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: WasteManagement Operators (r:1 w:0)
+	// Storage: WasteManagement WasteDataMap (r:1 w:1)
+	// Storage: WasteManagement WasteDataByStatus (r:0 w:2)
+	fn update_waste_status_to_transported() -> Weight {
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: WasteManagement Operators (r:1 w:0)
+	// Storage: WasteManagement WasteDataMap (r:1 w:1)
+	// Storage: WasteManagement PendingApprovals (r:1 w:1)
+	// Storage: WasteManagement ReporterScore (r:1 w:1)
+	// Storage: WasteManagement ReportDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: WasteManagement PendingRewards (r:2 w:2)
+	// Storage: WasteManagement WasteDataByStatus (r:0 w:2)
+	fn update_waste_status_to_utilized() -> Weight {
+		Weight::from_parts(46_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	// Storage: WasteManagement NextReportId (r:1 w:1)
+	// Storage: WasteManagement WasteDataMap (r:1 w:1)
+	// Storage: WasteManagement ReportsByReporter (r:1 w:1)
+	// Storage: WasteManagement WasteDataByStatus (r:0 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn create_waste_data_batch(n: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(22_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+	// Storage: WasteManagement Operators (r:1 w:0)
+	// Storage: WasteManagement WasteDataByStatus (r:1 w:2)
+	// Storage: WasteManagement WasteDataMap (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn bulk_update_status(n: u32, ) -> Weight {
+		Weight::from_parts(7_000_000, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	// Storage: WasteManagement WasteDataMap (r:1 w:1)
+	// Storage: WasteManagement ActiveReportCount (r:1 w:1)
+	// Storage: WasteManagement TotalAmountByType (r:1 w:1)
+	// Storage: WasteManagement TotalAmountByStatus (r:1 w:1)
+	// Storage: WasteManagement TotalVolumeByStatus (r:1 w:1)
+	// Storage: WasteManagement ReportsByReporter (r:1 w:1)
+	// Storage: WasteManagement ReportsByCell (r:1 w:1)
+	// Storage: WasteManagement ReportDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: WasteManagement WasteDataByStatus (r:0 w:1)
+	// Storage: WasteManagement ReportsBySeverity (r:0 w:1)
+	// Storage: WasteManagement EvidenceHash (r:0 w:1)
+	// Storage: WasteManagement WeightAttestations (r:0 w:1)
+	// Storage: WasteManagement Disputed (r:0 w:1)
+	// Storage: WasteManagement Settling (r:0 w:1)
+	// Storage: WasteManagement StaleFlags (r:0 w:1)
+	// Storage: WasteManagement CancellationReasons (r:0 w:1)
+	// Storage: WasteManagement RejectionReasons (r:0 w:1)
+	// Storage: WasteManagement ChildReports (r:0 w:1)
+	// Storage: WasteManagement ReportNotes (r:0 w:1)
+	// Storage: WasteManagement StatusHistory (r:0 w:1)
+	// Storage: WasteManagement LocationHistory (r:0 w:1)
+	// Storage: WasteManagement PendingApprovals (r:0 w:1)
+	// Storage: WasteManagement ReportsByCreation (r:0 w:1)
+	/// The range of component `n` is `[1, 256]`.
+	fn delete_waste_data(n: u32, ) -> Weight {
+		Weight::from_parts(48_000_000, 0)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(120_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(24))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_waste_data() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	fn update_waste_status() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	fn update_waste_status_noop() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2))
+	}
+	fn update_waste_status_to_collected() -> Weight {
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	fn update_waste_status_to_transported() -> Weight {
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	fn update_waste_status_to_utilized() -> Weight {
+		Weight::from_parts(46_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(9))
+	}
+	fn create_waste_data_batch(n: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(Weight::from_parts(22_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+	fn bulk_update_status(n: u32, ) -> Weight {
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	fn delete_waste_data(n: u32, ) -> Weight {
+		Weight::from_parts(48_000_000, 0)
+			.saturating_add(Weight::from_parts(120_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(24))
+	}
+}
@@ -6,6 +6,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use codec::Encode;
 use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -13,7 +14,8 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
+		NumberFor, One, SaturatedConversion, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
@@ -27,7 +29,8 @@ use sp_version::RuntimeVersion;
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+		ConstBool, ConstI32, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem,
+		Randomness, StorageInfo,
 	},
 	weights::{
 		constants::{
@@ -35,7 +38,7 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -270,8 +273,134 @@ impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Index,
+	) -> Option<(
+		RuntimeCall,
+		<UncheckedExtrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload,
+	)> {
+		let period =
+			BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+		let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+		let extra: SignedExtra = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+		);
+		let raw_payload = SignedPayload::new(call, extra).ok()?;
+		let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+		let (call, extra, _) = raw_payload.deconstruct();
+		Some((call, (sp_runtime::MultiAddress::Id(account), signature, extra)))
+	}
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = RuntimeCall;
+}
+
+parameter_types! {
+	/// Two operators must confirm before a report is utilized and its reward paid.
+	pub const RequiredApprovals: (waste_management::WasteStatus, u32) =
+		(waste_management::WasteStatus::Utilized, 2);
+	/// Every status is mirrored, so that all of them can be listed and scanned by status.
+	pub const IndexedStatuses: &'static [waste_management::WasteStatus] =
+		&waste_management::WasteStatus::ALL;
+	/// A report whose route is full can't be moved again, so that its whole route is kept.
+	pub const RouteHistoryMode: waste_management::RouteHistoryMode =
+		waste_management::RouteHistoryMode::Reject;
+	/// A report filed again under an outside case number is refused, so each case is filed once.
+	pub const ExternalRefMode: waste_management::ExternalRefMode =
+		waste_management::ExternalRefMode::Reject;
+	pub const TreasuryCut: Permill = Permill::zero();
+	/// Keyless account that would collect the treasury's cut of completion rewards.
+	pub TreasuryAccount: AccountId = PalletId(*b"wm/trsry").into_account_truncating();
+}
+
 impl waste_management::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type AuthorityId = waste_management::crypto::AuthId;
+	type StaleThreshold = ConstU32<DAYS>;
+	type MaxStaleScan = ConstU32<64>;
+	type AutoExpiryBlocks = ConstU32<{ 30 * DAYS }>;
+	type MaxExpiriesPerBlock = ConstU32<16>;
+	type DraftExpiry = ConstU32<DAYS>;
+	type MaxReportsPerAccount = ConstU32<256>;
+	type MaxActiveReports = ConstU64<10_000_000>;
+	type IndexedStatuses = IndexedStatuses;
+	type MaxLocationX = ConstU32<1_000_000>;
+	type MaxLocationY = ConstU32<1_000_000>;
+	type MaxServiceZones = ConstU32<32>;
+	type MaxEmergencyBatch = ConstU32<64>;
+	type MaxTypeNameLen = ConstU32<64>;
+	type MaxReportsPerBlock = ConstU32<64>;
+	type MaxBatchSize = ConstU32<50>;
+	type MaxQueryBatch = ConstU32<1_000>;
+	type MaxSplit = ConstU32<8>;
+	type MaxReasonLen = ConstU32<256>;
+	type MaxContactLen = ConstU32<128>;
+	type MaxTagLen = ConstU32<128>;
+	type MaxNoteLen = ConstU32<512>;
+	type MaxNotes = ConstU32<16>;
+	type MaxHistoryLen = ConstU32<16>;
+	type MaxLocationHistory = ConstU32<32>;
+	type MaxEventLog = ConstU32<64>;
+	type RecentActivityLen = ConstU32<100>;
+	type RouteHistoryMode = RouteHistoryMode;
+	type ExternalRefMode = ExternalRefMode;
+	type CellSize = ConstU32<1_000>;
+	type MaxPerCell = ConstU32<256>;
+	type MaxSearchRings = ConstU32<16>;
+	type DedupWindow = ConstU32<{ 10 * MINUTES }>;
+	type MinTransitionDelay = ConstU32<MINUTES>;
+	type SettlementTimeout = ConstU32<{ 10 * MINUTES }>;
+	type Currency = Balances;
+	type ReportDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	// There is no treasury in this runtime, so slashed deposits are burned.
+	type Slash = ();
+	type CompletionReward = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	// Nothing is set aside for a treasury, so reporters get the whole reward.
+	type TreasuryCut = TreasuryCut;
+	type TreasuryAccount = TreasuryAccount;
+	type MaxComponents = ConstU32<8>;
+	type MaxWasteAmount = ConstU64<10_000_000_000>;
+	type MaxVolume = ConstU64<1_000_000_000>;
+	type DefaultMinAmount = ConstU64<1>;
+	type AmountDecimals = ConstU8<6>;
+	type SummaryInterval = ConstU32<HOURS>;
+	type MinScoreToReport = ConstI32<-10>;
+	type RequireAllowlist = ConstBool<false>;
+	type PrivacyMode = ConstBool<false>;
+	type VerboseEvents = ConstBool<true>;
+	type AutoStampCollector = ConstBool<false>;
+	type RequiredApprovals = RequiredApprovals;
+	type MaxApprovers = ConstU32<4>;
+	// Matches `RequiredApprovals`, which needs two operators anyway.
+	type MinOperators = ConstU32<2>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type ReportHook = ();
+	type OnStatusReached = ();
+	type WeightInfo = waste_management::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -339,6 +468,7 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_template, TemplateModule]
+		[waste_management, WasteManagement]
 	);
 }
 
@@ -458,6 +588,112 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl
+		waste_management_runtime_api::WasteManagementApi<
+			Block,
+			AccountId,
+			waste_management::WasteData<Runtime>,
+			BlockNumber,
+		>
+		for Runtime
+	{
+		fn reports_by_status(
+			status: waste_management::WasteStatus,
+		) -> Vec<waste_management::WasteData<Runtime>> {
+			WasteManagement::reports_with_status(status)
+		}
+
+		fn count_by_type_and_status(
+			waste_type: waste_management::WasteType,
+			status: waste_management::WasteStatus,
+			scan_limit: Option<u32>,
+		) -> u64 {
+			WasteManagement::count_by_type_and_status(waste_type, status, scan_limit)
+		}
+
+		fn amount_histogram(
+			waste_type: waste_management::WasteType,
+			scan_limit: Option<u32>,
+		) -> [u64; 4] {
+			WasteManagement::amount_histogram(waste_type, scan_limit)
+		}
+
+		fn reports_of_by_status(
+			who: AccountId,
+			status: waste_management::WasteStatus,
+		) -> Vec<waste_management::WasteData<Runtime>> {
+			WasteManagement::reports_of_by_status(&who, status)
+		}
+
+		fn reports_in_box(
+			min: waste_management::GeoPoint,
+			max: waste_management::GeoPoint,
+			limit: u32,
+		) -> Vec<waste_management::WasteData<Runtime>> {
+			WasteManagement::reports_in_box(min, max, limit)
+		}
+
+		fn nearest_reports(
+			point: waste_management::GeoPoint,
+			k: u32,
+			status_filter: Option<waste_management::WasteStatus>,
+		) -> Vec<(waste_management::ReportId, u64)> {
+			WasteManagement::nearest_reports(point, k, status_filter)
+		}
+
+		fn supported_statuses() -> Vec<waste_management::WasteStatus> {
+			waste_management::WasteStatus::all().to_vec()
+		}
+
+		fn dashboard() -> waste_management::DashboardSummary {
+			WasteManagement::dashboard_summary()
+		}
+
+		fn report_by_ref(
+			external_ref: waste_management::ExternalRef,
+		) -> Option<waste_management::WasteData<Runtime>> {
+			WasteManagement::report_by_ref(external_ref).and_then(WasteManagement::waste_data)
+		}
+
+		fn registered_waste_types() -> Vec<(waste_management::WasteType, Vec<u8>)> {
+			WasteManagement::all_waste_types()
+		}
+
+		fn report_commitment(report_id: waste_management::ReportId) -> Option<[u8; 32]> {
+			WasteManagement::report_commitment(report_id)
+		}
+
+		fn operator_actions(operator: AccountId) -> u64 {
+			WasteManagement::actions_of(&operator)
+		}
+
+		fn report_progress(report_id: waste_management::ReportId) -> Option<u32> {
+			WasteManagement::report_progress(report_id)
+		}
+
+		fn statuses_of(
+			ids: Vec<waste_management::ReportId>,
+		) -> Vec<(waste_management::ReportId, Option<waste_management::WasteStatus>)> {
+			WasteManagement::statuses_of(frame_support::BoundedVec::truncate_from(ids))
+		}
+
+		fn recent_activity() -> Vec<(waste_management::ReportId, u8, BlockNumber)> {
+			WasteManagement::recent_activity()
+		}
+
+		fn report_chunk(
+			start_after: Option<waste_management::ReportId>,
+			limit: u32,
+			prev_hash: Option<[u8; 32]>,
+		) -> (
+			Vec<waste_management::WasteData<Runtime>>,
+			Option<waste_management::ReportId>,
+			[u8; 32],
+		) {
+			WasteManagement::report_chunk(start_after, limit, prev_hash)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,